@@ -1,25 +1,31 @@
 // frontend/src/components/EventMarketplace.tsx
 import React, { useState } from 'react';
 import { useEvents, usePurchaseTickets } from '../hooks/useTicketing';
-import { Event, EventType, EventPlatform } from '../types/ticketing';
+import { Event, EventType, EventPlatform, EventQuery, EventSort } from '../types/ticketing';
 import { Search, Filter, Calendar, MapPin, Users } from 'lucide-react';
 
 const EventMarketplace: React.FC = () => {
   const [filters, setFilters] = useState({
+    text: '',
     eventType: '' as EventType | '',
     platform: '' as EventPlatform | '',
     city: '',
     dateRange: '',
   });
 
-  const { data: events, isLoading } = useEvents();
+  // Filtering, ranking and paging happen server-side
+  const query: EventQuery = {
+    text: filters.text || undefined,
+    eventType: filters.eventType || undefined,
+    platform: filters.platform || undefined,
+    city: filters.city || undefined,
+    startDate: filters.dateRange ? `${filters.dateRange}T00:00:00Z` : undefined,
+    endDate: filters.dateRange ? `${filters.dateRange}T23:59:59Z` : undefined,
+    sort: filters.text ? EventSort.Relevance : EventSort.DateAscending,
+  };
 
-  const filteredEvents = events?.filter(event => {
-    if (filters.eventType && event.eventType !== filters.eventType) return false;
-    if (filters.platform && event.platform !== filters.platform) return false;
-    if (filters.city && !event.venue.city.toLowerCase().includes(filters.city.toLowerCase())) return false;
-    return true;
-  });
+  const { data: results, isLoading } = useEvents(query);
+  const filteredEvents = results?.events;
 
   if (isLoading) {
     return (
@@ -39,6 +45,16 @@ const EventMarketplace: React.FC = () => {
 
       {/* Filters */}
       <div className="bg-white rounded-lg shadow p-6 mb-8">
+        <div className="relative mb-4">
+          <Search className="h-4 w-4 absolute left-3 top-3 text-gray-400" />
+          <input
+            type="text"
+            placeholder="Search events, organizers..."
+            value={filters.text}
+            onChange={(e) => setFilters({ ...filters, text: e.target.value })}
+            className="w-full rounded-md border-gray-300 shadow-sm focus:border-blue-500 focus:ring-blue-500 border p-2 pl-9"
+          />
+        </div>
         <div className="grid grid-cols-1 md:grid-cols-4 gap-4">
           <div>
             <label className="block text-sm font-medium text-gray-700 mb-2">
@@ -52,7 +52,7 @@ const TicketPurchaseModal: React.FC<TicketPurchaseModalProps> = ({ event, onClos
         <div className="p-6 border-b">
           <h3 className="text-lg font-medium mb-2">{event.title}</h3>
           <p className="text-gray-600 text-sm">
-            {event.venue.name} • {new Date(event.eventDate).toLocaleDateString()}
+            {event.venue.name} • {new Date(event.eventDate).toLocaleDateString(undefined, { timeZone: event.localTimes?.timezone })}
           </p>
         </div>
 
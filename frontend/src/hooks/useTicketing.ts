@@ -2,6 +2,7 @@
 import { useQuery, useMutation, useQueryClient } from 'react-query';
 import { 
   Event, 
+  EventQuery,
   Ticket, 
   TicketPurchaseRequest, 
   TicketPurchaseResponse,
@@ -10,12 +11,13 @@ import {
 import { ticketingApi } from '../services/ticketingApi';
 import { toast } from 'react-hot-toast';
 
-export const useEvents = (filters?: any) => {
+export const useEvents = (query?: EventQuery) => {
   return useQuery(
-    ['events', filters],
-    () => ticketingApi.getEvents(filters),
+    ['events', query],
+    () => ticketingApi.getEvents(query),
     {
       refetchInterval: 30000, // Refresh every 30 seconds
+      keepPreviousData: true, // Avoid flashing the spinner while filters change
     }
   );
 };
@@ -2,10 +2,20 @@
 import axios from 'axios';
 import {
   Event,
+  EventQuery,
+  EventSearchResults,
+  EventEscrow,
+  EventRevision,
+  EventStatus,
+  EventUpdate,
+  PriceQuote,
   Ticket,
   TicketPurchaseRequest,
   TicketPurchaseResponse,
-  ResaleListing
+  ResaleListing,
+  SeatAssignment,
+  StatusTransition,
+  Venue
 } from '../types/ticketing';
 
 const API_BASE_URL = import.meta.env.VITE_API_URL || 'http://localhost:8080/api';
@@ -17,8 +27,8 @@ const api = axios.create({
 
 export const ticketingApi = {
   // Event operations
-  async getEvents(filters?: any): Promise<Event[]> {
-    const response = await api.get('/events', { params: filters });
+  async getEvents(query?: EventQuery): Promise<EventSearchResults> {
+    const response = await api.get('/events', { params: query });
     return response.data;
   },
 
@@ -32,7 +42,61 @@ export const ticketingApi = {
     return response.data;
   },
 
+  async getAvailableSeats(eventId: string, ticketTypeId: string): Promise<SeatAssignment[]> {
+    const response = await api.get(`/events/${eventId}/ticket-types/${ticketTypeId}/seats`);
+    return response.data;
+  },
+
+  // Sends the version being edited; a stale one is rejected with 409
+  async updateEvent(eventId: string, expectedVersion: number, update: EventUpdate): Promise<Event> {
+    const response = await api.patch(`/events/${eventId}`, update, {
+      headers: { 'If-Match': String(expectedVersion) },
+    });
+    return response.data;
+  },
+
+  async getEventRevisions(eventId: string): Promise<EventRevision[]> {
+    const response = await api.get(`/events/${eventId}/revisions`);
+    return response.data;
+  },
+
+  async getEventEscrow(eventId: string): Promise<EventEscrow> {
+    const response = await api.get(`/events/${eventId}/escrow`);
+    return response.data;
+  },
+
+  async transitionEvent(eventId: string, to: EventStatus, reason?: string): Promise<Event> {
+    const response = await api.post(`/events/${eventId}/status`, { to, reason });
+    return response.data;
+  },
+
+  async getStatusHistory(eventId: string): Promise<StatusTransition[]> {
+    const response = await api.get(`/events/${eventId}/status-history`);
+    return response.data;
+  },
+
+  // Venue operations
+  async getVenues(): Promise<Venue[]> {
+    const response = await api.get('/venues');
+    return response.data;
+  },
+
+  async createVenue(venue: Venue): Promise<Venue> {
+    const response = await api.post('/venues', venue);
+    return response.data;
+  },
+
+  async updateVenue(venueId: string, venue: Venue): Promise<Venue> {
+    const response = await api.put(`/venues/${venueId}`, venue);
+    return response.data;
+  },
+
   // Ticket operations
+  async quotePrice(eventId: string, ticketTypeId: string, currency: string): Promise<PriceQuote> {
+    const response = await api.post(`/events/${eventId}/ticket-types/${ticketTypeId}/quote`, { currency });
+    return response.data;
+  },
+
   async purchaseTickets(purchaseRequest: TicketPurchaseRequest): Promise<TicketPurchaseResponse> {
     const response = await api.post('/tickets/purchase', purchaseRequest);
     return response.data;
@@ -51,6 +115,11 @@ export const ticketingApi = {
     return response.data;
   },
 
+  async cancelResaleListing(listingId: string): Promise<ResaleListing> {
+    const response = await api.delete(`/tickets/resale/${listingId}`);
+    return response.data;
+  },
+
   async purchaseResaleTicket(listingId: string, buyerWallet: string): Promise<Ticket> {
     const response = await api.post(`/tickets/resale/${listingId}/purchase`, {
       buyerWallet,
@@ -58,6 +127,12 @@ export const ticketingApi = {
     return response.data;
   },
 
+  // Only while a material change's refund window is open
+  async requestRefund(ticketId: string): Promise<Ticket> {
+    const response = await api.post(`/tickets/${ticketId}/refund`);
+    return response.data;
+  },
+
   async transferTicket(ticketId: string, toWallet: string): Promise<Ticket> {
     const response = await api.post(`/tickets/${ticketId}/transfer`, {
       toWallet,
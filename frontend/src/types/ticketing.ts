@@ -7,6 +7,9 @@ export interface Event {
   venue: Venue;
   eventDate: string;
   doorTime: string;
+  endDate?: string;
+  timezone?: string;
+  localTimes?: LocalTimes;
   eventType: EventType;
   ticketTypes: TicketType[];
   externalEventId?: string;
@@ -14,17 +17,162 @@ export interface Event {
   status: EventStatus;
   createdAt: string;
   updatedAt: string;
+  soldSeats?: SeatAssignment[];
+  sessions?: Session[];
+  recurrence?: RecurrenceRule;
+  version?: number;
+  settlement?: SettlementSchedule;
+}
+
+export interface EventUpdate {
+  title?: string;
+  description?: string;
+  venue?: Venue;
+  eventDate?: string;
+  doorTime?: string;
+  endDate?: string;
+  timezone?: string;
+  eventType?: EventType;
+}
+
+export interface FieldChange {
+  field: string;
+  before: unknown;
+  after: unknown;
+}
+
+export interface EventRevision {
+  eventId: string;
+  version: number;
+  changedAt: string;
+  changes: FieldChange[];
+  refundWindowEnds?: string;
+}
+
+export type SettlementSchedule =
+  | 'AfterCompletion'
+  | { Tranches: SettlementTranche[] };
+
+export interface SettlementTranche {
+  releaseAt?: string;
+  share: number;
+}
+
+export interface EscrowRelease {
+  tranches: number[];
+  gross: number;
+  fee: number;
+  organizerTx?: string;
+  feeTx?: string;
+  releasedAt: string;
+}
+
+export interface EventEscrow {
+  eventId: string;
+  account: string;
+  collected: number;
+  refunded: number;
+  paidToOrganizer: number;
+  feesPaid: number;
+  feesOutstanding: number;
+  tranchesReleased: number[];
+  releases: EscrowRelease[];
+  refundsAdvanced: number; // Platform top-up for refunds, recovered from later sales
+}
+
+export interface StatusTransition {
+  eventId: string;
+  from: EventStatus;
+  to: EventStatus;
+  actor: string;
+  reason?: string;
+  at: string;
+}
+
+export interface Session {
+  id: string;
+  name: string;
+  startsAt: string;
+  endsAt: string;
+  capacity?: number;
+}
+
+export interface RecurrenceRule {
+  frequency: 'Daily' | 'Weekly';
+  interval?: number;
+  count?: number;
+  until?: string;
+  sessionCapacity?: number;
+}
+
+export type SessionAccess =
+  | 'FullPass'
+  | { Sessions: string[] };
+
+// Event times with the venue's UTC offset, e.g. 2030-06-14T20:00:00+08:00
+export interface LocalTimes {
+  timezone: string;
+  eventDate: string;
+  doorTime: string;
+  endDate: string;
 }
 
 export interface Venue {
+  id?: string;
   name: string;
   address: string;
   city: string;
   country: string;
   capacity: number;
   coordinates?: Coordinates;
+  timezone?: string;
+  seatMap?: SeatMap;
+  areas?: VenueArea[];
+}
+
+export interface VenueArea {
+  name: string;
+  capacity: number;
 }
 
+export interface SeatMap {
+  sections: Section[];
+}
+
+export interface Section {
+  name: string;
+  rows: SeatRow[];
+}
+
+export interface SeatRow {
+  label: string;
+  seats: Seat[];
+}
+
+export interface Seat {
+  number: string;
+  zone: string;
+  accessibility?: AccessibilityFeature[];
+}
+
+export enum AccessibilityFeature {
+  WheelchairSpace = 'WHEELCHAIR_SPACE',
+  CompanionSeat = 'COMPANION_SEAT',
+  StepFreeAccess = 'STEP_FREE_ACCESS',
+  HearingLoop = 'HEARING_LOOP',
+  RestrictedView = 'RESTRICTED_VIEW',
+}
+
+export interface SeatAssignment {
+  section: string;
+  row: string;
+  seat: string;
+}
+
+export type SeatSelection =
+  | { Specific: SeatAssignment[] }
+  | { BestAvailable: { accessibility?: AccessibilityFeature[] } };
+
 export interface Coordinates {
   latitude: number;
   longitude: number;
@@ -51,8 +199,17 @@ export interface TicketType {
   nftMetadata?: NFTMetadata;
   salesStart: string;
   salesEnd: string;
+  transferPolicy?: TransferPolicy;
+  priceZone?: string;
+  area?: string;
+  sessionAccess?: SessionAccess;
 }
 
+export type TransferPolicy =
+  | 'Transferable'
+  | { TransferableUntil: string }
+  | 'Soulbound';
+
 export interface Perk {
   name: string;
   description: string;
@@ -97,6 +254,39 @@ export enum EventStatus {
   Completed = 'COMPLETED',
 }
 
+export enum EventSort {
+  DateAscending = 'DATE_ASCENDING',
+  DateDescending = 'DATE_DESCENDING',
+  PriceAscending = 'PRICE_ASCENDING',
+  PriceDescending = 'PRICE_DESCENDING',
+  Relevance = 'RELEVANCE',
+  RecentlyAdded = 'RECENTLY_ADDED',
+}
+
+export interface EventQuery {
+  eventType?: EventType;
+  status?: EventStatus;
+  platform?: EventPlatform;
+  city?: string;
+  organizer?: string;
+  startDate?: string;
+  endDate?: string;
+  minPrice?: number;
+  maxPrice?: number;
+  text?: string;
+  availableOnly?: boolean;
+  sort?: EventSort;
+  page?: number;
+  pageSize?: number;
+}
+
+export interface EventSearchResults {
+  events: Event[];
+  total: number;
+  page: number;
+  pageSize: number;
+}
+
 export interface Ticket {
   id: string;
   eventId: string;
@@ -111,6 +301,24 @@ export interface Ticket {
   transferable: boolean;
   resaleAllowed: boolean;
   resalePrice?: number;
+  seat?: SeatAssignment;
+  checkedInSessions?: string[];
+  previousTicketId?: string;
+  refundPending?: boolean;
+  fxRate?: number;
+  payment?: PaymentReference;
+}
+
+export type PaymentMethod =
+  | 'HkdStablecoin'
+  | 'Card'
+  | 'Fps'
+  | { Stablecoin: string };
+
+export interface PaymentReference {
+  method: PaymentMethod;
+  paymentId: string;
+  payer: string;
 }
 
 export enum TicketStatus {
@@ -128,12 +336,29 @@ export interface TicketPurchaseRequest {
   quantity: number;
   buyerWallet: string;
   paymentCurrency: string;
+  seats?: SeatSelection;
+  quoteId?: string;
+  paymentMethod?: PaymentMethod; // Required unless paying in HKD
+}
+
+export interface PriceQuote {
+  id: string;
+  eventId: string;
+  ticketTypeId: string;
+  basePrice: number;
+  baseCurrency: string;
+  price: number;
+  currency: string;
+  rate: number;
+  quotedAt: string;
+  expiresAt: string;
 }
 
 export interface TicketPurchaseResponse {
   purchaseId: string;
   tickets: Ticket[];
   totalAmount: number;
+  currency: string;
   transactionHash?: string;
   nftMintTransactions: string[];
 }
@@ -0,0 +1,93 @@
+// src/engine.rs
+use crate::error::HKDError;
+use crate::models::TransactionMetadata;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// A settled movement of HKD stablecoin between two accounts.
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    pub id: String,
+    pub from: String,
+    pub to: String,
+    pub amount: Decimal,
+    pub metadata: Option<TransactionMetadata>,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Default)]
+struct Ledger {
+    balances: HashMap<String, Decimal>,
+    transactions: Vec<Transaction>,
+}
+
+/// The HKD stablecoin ledger: account balances and the transfers between them.
+#[derive(Default)]
+pub struct HKDEngine {
+    ledger: Mutex<Ledger>,
+}
+
+impl HKDEngine {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Issues `amount` of new stablecoin into `account`.
+    pub fn mint(&self, account: &str, amount: Decimal) -> Result<(), HKDError> {
+        if amount <= Decimal::ZERO {
+            return Err(HKDError::InvalidAmount(amount));
+        }
+        let mut ledger = self.ledger.lock().unwrap();
+        *ledger.balances.entry(account.to_string()).or_default() += amount;
+        Ok(())
+    }
+
+    pub fn balance_of(&self, account: &str) -> Decimal {
+        self.ledger.lock().unwrap().balances.get(account).copied().unwrap_or_default()
+    }
+
+    /// Moves `amount` from one account to another, returning the transaction id.
+    pub fn transfer(
+        &self,
+        from: &str,
+        to: &str,
+        amount: Decimal,
+        metadata: Option<TransactionMetadata>,
+    ) -> Result<String, HKDError> {
+        if amount <= Decimal::ZERO {
+            return Err(HKDError::InvalidAmount(amount));
+        }
+        let mut ledger = self.ledger.lock().unwrap();
+        let available = ledger.balances.get(from).copied().unwrap_or_default();
+        if available < amount {
+            return Err(HKDError::InsufficientBalance {
+                account: from.to_string(),
+                available,
+                required: amount,
+            });
+        }
+        ledger.balances.insert(from.to_string(), available - amount);
+        *ledger.balances.entry(to.to_string()).or_default() += amount;
+
+        let id = format!("hkd_tx_{}", Uuid::new_v4().simple());
+        ledger.transactions.push(Transaction {
+            id: id.clone(),
+            from: from.to_string(),
+            to: to.to_string(),
+            amount,
+            metadata,
+            timestamp: Utc::now(),
+        });
+        Ok(id)
+    }
+
+    pub fn transactions(&self, account: &str) -> Vec<Transaction> {
+        self.ledger.lock().unwrap().transactions.iter()
+            .filter(|tx| tx.from == account || tx.to == account)
+            .cloned()
+            .collect()
+    }
+}
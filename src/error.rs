@@ -0,0 +1,81 @@
+// src/error.rs
+use crate::models::ticketing::{EventPlatform, EventStatus, TicketStatus};
+use crate::services::event_validation::Violation;
+use rust_decimal::Decimal;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum HKDError {
+    #[error("Insufficient balance in {account}: {available} available, {required} required")]
+    InsufficientBalance { account: String, available: Decimal, required: Decimal },
+    #[error("Invalid amount: {0}")]
+    InvalidAmount(Decimal),
+
+    #[error("Event not found: {0}")]
+    EventNotFound(Uuid),
+    #[error("Ticket type not found: {0}")]
+    TicketTypeNotFound(Uuid),
+    #[error("Ticket not found: {0}")]
+    TicketNotFound(Uuid),
+    #[error("Resale listing not found: {0}")]
+    ListingNotFound(Uuid),
+    #[error("Not enough tickets available")]
+    InsufficientTickets,
+    #[error("Resale is not allowed for this ticket")]
+    ResaleNotAllowed,
+    #[error("Transfer is not allowed for this ticket")]
+    TransferNotAllowed,
+    #[error("Venue not found: {0}")]
+    VenueNotFound(Uuid),
+    #[error("Price quote not found: {0}")]
+    QuoteNotFound(Uuid),
+    #[error("Price quote {0} has expired")]
+    QuoteExpired(Uuid),
+    #[error("Event is not on sale ({0:?})")]
+    EventNotOnSale(EventStatus),
+    #[error("Validation failed: {}", join_violations(.0))]
+    ValidationFailed(Vec<Violation>),
+    #[error("Event was edited concurrently: expected version {expected}, found {actual}")]
+    VersionConflict { expected: u64, actual: u64 },
+    #[error("Can't move event from {from:?} to {to:?}: {reason}")]
+    InvalidTransition { from: EventStatus, to: EventStatus, reason: String },
+    #[error("Can't move ticket {ticket_id} from {from:?} to {to:?}")]
+    InvalidTicketTransition { ticket_id: Uuid, from: TicketStatus, to: TicketStatus },
+    #[error("Refund not allowed: {0}")]
+    RefundNotAllowed(String),
+    #[error("Invalid schedule: {0}")]
+    InvalidSchedule(String),
+    #[error("Check-in rejected: {0}")]
+    CheckInRejected(String),
+    #[error("Invalid seat selection: {0}")]
+    InvalidSeatSelection(String),
+    #[error("Seat unavailable: {0}")]
+    SeatUnavailable(String),
+
+    #[error("Unsupported currency: {0}")]
+    UnsupportedCurrency(String),
+    #[error("No FX rate from {from} to {to}")]
+    FxRateUnavailable { from: String, to: String },
+    #[error("Unsupported payment method: {0}")]
+    UnsupportedPaymentMethod(String),
+    #[error("Payment declined: {0}")]
+    PaymentDeclined(String),
+    #[error("Payment failed: {0}")]
+    PaymentFailed(String),
+
+    #[error("External API error: {0}")]
+    ExternalApiError(String),
+    #[error("Invalid webhook signature: {0}")]
+    InvalidWebhookSignature(String),
+    #[error("{platform:?} API returned {status}: {body}")]
+    PlatformApiError { platform: EventPlatform, status: u16, body: String },
+    #[error("{0:?} is unavailable")]
+    PlatformUnavailable(EventPlatform),
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+}
+
+fn join_violations(violations: &[Violation]) -> String {
+    violations.iter().map(|violation| violation.to_string()).collect::<Vec<_>>().join("; ")
+}
@@ -0,0 +1,5 @@
+// src/lib.rs
+pub mod engine;
+pub mod error;
+pub mod models;
+pub mod services;
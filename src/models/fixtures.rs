@@ -0,0 +1,103 @@
+// src/models/fixtures.rs
+//! Sample models shared by unit tests.
+use super::ticketing::*;
+use chrono::{Duration, TimeZone, Utc};
+use rust_decimal::Decimal;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// A published Hong Kong concert a few years out, with one ticket type.
+pub(crate) fn event() -> Event {
+    let event_date = Utc.with_ymd_and_hms(2030, 6, 14, 12, 0, 0).unwrap();
+    let created_at = Utc.with_ymd_and_hms(2025, 1, 10, 9, 0, 0).unwrap();
+    Event {
+        id: Uuid::new_v4(),
+        title: "Harbour Lights Festival".to_string(),
+        description: "Two stages on the waterfront.".to_string(),
+        organizer: "wallet:organizer".to_string(),
+        venue: Venue {
+            id: None,
+            name: "AsiaWorld-Arena".to_string(),
+            address: "1 Airport Expressway".to_string(),
+            city: "Hong Kong".to_string(),
+            country: "HK".to_string(),
+            capacity: 14_000,
+            coordinates: Some(Coordinates { latitude: 22.3203, longitude: 113.9431 }),
+            timezone: Some(chrono_tz::Asia::Hong_Kong),
+            seat_map: None,
+            areas: Vec::new(),
+        },
+        event_date,
+        door_time: event_date - Duration::hours(1),
+        end_date: None,
+        timezone: None,
+        local_times: None,
+        event_type: EventType::Concert,
+        ticket_types: vec![ticket_type("General Admission", Decimal::new(88000, 2), 1_000)],
+        external_event_id: None,
+        platform: EventPlatform::Internal,
+        status: EventStatus::Published,
+        created_at,
+        updated_at: created_at,
+        publications: HashMap::new(),
+        sold_seats: HashSet::new(),
+        sessions: Vec::new(),
+        recurrence: None,
+        version: 0,
+        settlement: SettlementSchedule::default(),
+    }
+}
+
+pub(crate) fn ticket_type(name: &str, price: Decimal, quantity: u32) -> TicketType {
+    TicketType {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        price,
+        currency: "HKD".to_string(),
+        quantity_available: quantity,
+        quantity_sold: 0,
+        perks: Vec::new(),
+        nft_metadata: None,
+        sales_start: Utc.with_ymd_and_hms(2030, 2, 1, 0, 0, 0).unwrap(),
+        sales_end: Utc.with_ymd_and_hms(2030, 6, 14, 10, 0, 0).unwrap(),
+        transfer_policy: TransferPolicy::default(),
+        inventory: InventoryAllocation::default(),
+        channel_sales: HashMap::new(),
+        external_ids: HashMap::new(),
+        price_zone: None,
+        area: None,
+        session_access: SessionAccess::default(),
+    }
+}
+
+/// An active ticket of the event's first ticket type.
+pub(crate) fn ticket(event: &Event) -> Ticket {
+    let ticket_type = &event.ticket_types[0];
+    let ticket_id = Uuid::new_v4();
+    Ticket {
+        id: ticket_id,
+        event_id: event.id,
+        ticket_type_id: ticket_type.id,
+        owner_wallet: "wallet:buyer".to_string(),
+        purchase_price: ticket_type.price,
+        purchase_currency: ticket_type.currency.clone(),
+        purchase_date: Utc.with_ymd_and_hms(2030, 2, 1, 0, 0, 0).unwrap(),
+        status: TicketStatus::Active,
+        nft_token_id: None,
+        qr_code: format!("TICKET_{}_{}", event.id, ticket_id),
+        transferable: true,
+        resale_allowed: true,
+        resale_price: None,
+        external_attendee_id: None,
+        seat: None,
+        checked_in_sessions: Vec::new(),
+        previous_ticket_id: None,
+        refund_pending: false,
+        fx_rate: None,
+        payment: Some(PaymentReference {
+            method: PaymentMethod::HkdStablecoin,
+            payment_id: format!("tx:{}", ticket_id),
+            payer: "wallet:buyer".to_string(),
+        }),
+    }
+}
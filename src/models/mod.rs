@@ -0,0 +1,15 @@
+// src/models/mod.rs
+use serde::{Deserialize, Serialize};
+
+pub mod ticketing;
+
+#[cfg(test)]
+pub(crate) mod fixtures;
+
+/// Context recorded with a stablecoin transfer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransactionMetadata {
+    pub reference: Option<String>,
+    pub purpose: Option<String>,
+    pub regulatory_approval_id: Option<String>,
+}
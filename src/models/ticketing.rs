@@ -1,10 +1,13 @@
 // src/models/ticketing.rs
 use serde::{Deserialize, Serialize};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use chrono_tz::Tz;
 use rust_decimal::Decimal;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Event {
     pub id: Uuid,
     pub title: String,
@@ -13,32 +16,293 @@ pub struct Event {
     pub venue: Venue,
     pub event_date: DateTime<Utc>,
     pub door_time: DateTime<Utc>,
+    #[serde(default)]
+    pub end_date: Option<DateTime<Utc>>, // Required for multi-day events
+    #[serde(default)]
+    pub timezone: Option<Tz>, // Overrides the venue's zone
+    #[serde(default)]
+    pub local_times: Option<LocalTimes>, // Filled in when stored, for display
     pub event_type: EventType,
     pub ticket_types: Vec<TicketType>,
-    pub external_event_id: Option<String>, // ID from external platform
-    pub platform: EventPlatform,
+    pub external_event_id: Option<String>, // ID on the platform the event originated from
+    pub platform: EventPlatform, // Origin; Internal for events created here
     pub status: EventStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub publications: HashMap<EventPlatform, PlatformSync>, // External platforms the event is mirrored to
+    #[serde(default)]
+    pub sold_seats: HashSet<SeatAssignment>, // Reserved seats already assigned to tickets
+    #[serde(default)]
+    pub sessions: Vec<Session>, // Festival days, workshop dates; empty for a single sitting
+    #[serde(default)]
+    pub recurrence: Option<RecurrenceRule>, // Generates `sessions` when none are given
+    #[serde(default)]
+    pub version: u64, // Bumped on every change; edits must name the version they start from
+    #[serde(default)]
+    pub settlement: SettlementSchedule, // When escrowed sales are paid out to the organizer
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// When stablecoin sales held in the event's escrow are released to the organizer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum SettlementSchedule {
+    #[default]
+    AfterCompletion,
+    Tranches(Vec<SettlementTranche>),
+}
+
+impl SettlementSchedule {
+    pub fn tranches(&self) -> Vec<SettlementTranche> {
+        match self {
+            SettlementSchedule::AfterCompletion => vec![SettlementTranche { release_at: None, share: Decimal::ONE }],
+            SettlementSchedule::Tranches(tranches) => tranches.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SettlementTranche {
+    #[serde(default)]
+    pub release_at: Option<DateTime<Utc>>, // None releases once the event has completed
+    pub share: Decimal, // Fraction of net sales, e.g. 0.3
+}
+
+/// One sitting of a multi-day or recurring event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    pub id: Uuid,
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    #[serde(default)]
+    pub capacity: Option<u32>, // Admissions across every ticket type valid for it
+}
+
+/// Repeats the event's first sitting at the same local time of day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecurrenceRule {
+    pub frequency: RecurrenceFrequency,
+    #[serde(default = "every_period")]
+    pub interval: u32, // Every n days or weeks
+    #[serde(default)]
+    pub count: Option<u32>,
+    #[serde(default)]
+    pub until: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub session_capacity: Option<u32>,
+}
+
+fn every_period() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RecurrenceFrequency {
+    Daily,
+    Weekly,
+}
+
+/// An event's schedule in its own time zone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocalTimes {
+    pub timezone: Tz,
+    pub event_date: DateTime<FixedOffset>,
+    pub door_time: DateTime<FixedOffset>,
+    pub end_date: DateTime<FixedOffset>,
+}
+
+// Assumed length of events that don't say when they end
+pub const DEFAULT_EVENT_DURATION_HOURS: i64 = 3;
+
+impl Event {
+    pub fn timezone(&self) -> Tz {
+        self.timezone.or(self.venue.timezone).unwrap_or(Tz::UTC)
+    }
+
+    pub fn end_time(&self) -> DateTime<Utc> {
+        self.end_date.unwrap_or(self.event_date + Duration::hours(DEFAULT_EVENT_DURATION_HOURS))
+    }
+
+    /// The event's id on `platform`, whether it was published there or imported from it.
+    pub fn external_id_on(&self, platform: &EventPlatform) -> Option<&str> {
+        self.publications.get(platform)
+            .and_then(|publication| publication.external_id.as_deref())
+            .or_else(|| {
+                if &self.platform == platform {
+                    self.external_event_id.as_deref()
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+/// Where an event's mirror on one external platform stands. Ticket type
+/// mappings live in `TicketType::external_ids`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlatformSync {
+    pub platform: EventPlatform,
+    pub status: SyncStatus,
+    pub external_id: Option<String>,
+    pub event_url: Option<String>,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+}
+
+impl PlatformSync {
+    pub fn pending(platform: EventPlatform) -> Self {
+        Self {
+            platform,
+            status: SyncStatus::Pending,
+            external_id: None,
+            event_url: None,
+            attempts: 0,
+            last_error: None,
+            last_attempt_at: None,
+        }
+    }
+
+    pub fn synced(platform: EventPlatform, external_id: String, event_url: String) -> Self {
+        Self {
+            platform,
+            status: SyncStatus::Synced,
+            external_id: Some(external_id),
+            event_url: Some(event_url),
+            attempts: 0,
+            last_error: None,
+            last_attempt_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SyncStatus {
+    Synced,
+    Pending,
+    Failed,
+}
+
+/// Where an event takes place. Events hold a copy; venues registered with the
+/// service carry an `id` and are refreshed on their events when edited.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Venue {
+    #[serde(default)]
+    pub id: Option<Uuid>, // Set once registered
     pub name: String,
     pub address: String,
     pub city: String,
     pub country: String,
     pub capacity: u32,
     pub coordinates: Option<Coordinates>,
+    #[serde(default)]
+    pub timezone: Option<Tz>, // IANA zone; inferred from city and country when missing
+    #[serde(default)]
+    pub seat_map: Option<SeatMap>, // None for general admission venues
+    #[serde(default)]
+    pub areas: Vec<VenueArea>, // Separately capped parts of the venue, e.g. standing floor and stands
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl Venue {
+    pub fn area(&self, name: &str) -> Option<&VenueArea> {
+        self.areas.iter().find(|area| area.name == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VenueArea {
+    pub name: String,
+    pub capacity: u32,
+}
+
+/// Reserved seating layout. Sections and rows are listed best first, and seats
+/// within a row in physical order, so neighbours in `seats` sit side by side.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeatMap {
+    pub sections: Vec<Section>,
+}
+
+impl SeatMap {
+    pub fn seat(&self, assignment: &SeatAssignment) -> Option<&Seat> {
+        self.sections.iter()
+            .find(|section| section.name == assignment.section)?
+            .rows.iter()
+            .find(|row| row.label == assignment.row)?
+            .seats.iter()
+            .find(|seat| seat.number == assignment.seat)
+    }
+
+    pub fn zone_capacity(&self, zone: &str) -> u32 {
+        self.sections.iter()
+            .flat_map(|section| &section.rows)
+            .flat_map(|row| &row.seats)
+            .filter(|seat| seat.zone == zone)
+            .count() as u32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Section {
+    pub name: String,
+    pub rows: Vec<SeatRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SeatRow {
+    pub label: String,
+    pub seats: Vec<Seat>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Seat {
+    pub number: String,
+    pub zone: String, // Price zone; ticket types sell the seats of one zone
+    #[serde(default)]
+    pub accessibility: Vec<AccessibilityFeature>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AccessibilityFeature {
+    WheelchairSpace,
+    CompanionSeat,
+    StepFreeAccess,
+    HearingLoop,
+    RestrictedView,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SeatAssignment {
+    pub section: String,
+    pub row: String,
+    pub seat: String,
+}
+
+impl fmt::Display for SeatAssignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Section {}, Row {}, Seat {}", self.section, self.row, self.seat)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Coordinates {
     pub latitude: f64,
     pub longitude: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl Coordinates {
+    /// Great-circle (haversine) distance in kilometres.
+    pub fn distance_km(&self, other: &Coordinates) -> f64 {
+        const EARTH_RADIUS_KM: f64 = 6371.0;
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let d_lat = lat2 - lat1;
+        let d_lon = (other.longitude - self.longitude).to_radians();
+
+        let h = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * h.sqrt().asin()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EventType {
     Concert,
     Conference,
@@ -49,7 +313,7 @@ pub enum EventType {
     Exhibition,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TicketType {
     pub id: Uuid,
     pub name: String,
@@ -61,16 +325,91 @@ pub struct TicketType {
     pub nft_metadata: Option<NFTMetadata>,
     pub sales_start: DateTime<Utc>,
     pub sales_end: DateTime<Utc>,
+    #[serde(default)]
+    pub transfer_policy: TransferPolicy,
+    #[serde(default)]
+    pub inventory: InventoryAllocation,
+    #[serde(default)]
+    pub channel_sales: HashMap<EventPlatform, u32>, // Sold per channel, summing to quantity_sold
+    #[serde(default)]
+    pub external_ids: HashMap<EventPlatform, String>, // Ticket class / offer / admission item ids
+    #[serde(default)]
+    pub price_zone: Option<String>, // Reserved seating zone; None for general admission
+    #[serde(default)]
+    pub area: Option<String>, // Required when the venue is split into areas
+    #[serde(default)]
+    pub session_access: SessionAccess,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Which of an event's sessions a ticket type admits to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Default)]
+pub enum SessionAccess {
+    #[default]
+    FullPass,
+    Sessions(Vec<Uuid>), // A day ticket, or a bundle of several sessions
+}
+
+
+impl SessionAccess {
+    pub fn covers(&self, session_id: Uuid) -> bool {
+        match self {
+            SessionAccess::FullPass => true,
+            SessionAccess::Sessions(sessions) => sessions.contains(&session_id),
+        }
+    }
+}
+
+/// How a ticket type's `quantity_available` is shared between our checkout
+/// and the external platforms the event is mirrored to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Default)]
+pub enum InventoryAllocation {
+    /// Every channel sells from the same remaining stock.
+    #[default]
+    SharedPool,
+    /// Each channel gets a fixed percentage; `Internal` also keeps the rounding remainder.
+    Split(Vec<ChannelAllocation>),
+}
+
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChannelAllocation {
+    pub platform: EventPlatform,
+    pub percent: u8,
+}
+
+/// Controls whether tickets of a given type may change hands after purchase.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum TransferPolicy {
+    #[default]
+    Transferable,
+    TransferableUntil(DateTime<Utc>), // Locked from this instant onwards
+    Soulbound,
+}
+
+impl TransferPolicy {
+    pub fn allows_transfer_at(&self, at: DateTime<Utc>) -> bool {
+        match self {
+            TransferPolicy::Transferable => true,
+            TransferPolicy::TransferableUntil(lock_date) => at < *lock_date,
+            TransferPolicy::Soulbound => false,
+        }
+    }
+
+    pub fn is_soulbound(&self) -> bool {
+        matches!(self, TransferPolicy::Soulbound)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Perk {
     pub name: String,
     pub description: String,
     pub category: PerkCategory,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PerkCategory {
     EarlyEntry,
     VIP,
@@ -79,7 +418,7 @@ pub enum PerkCategory {
     DigitalContent,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NFTMetadata {
     pub name: String,
     pub description: String,
@@ -89,13 +428,13 @@ pub struct NFTMetadata {
     pub external_url: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NFTAttribute {
     pub trait_type: String,
     pub value: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EventPlatform {
     Eventbrite,
     Ticketmaster,
@@ -103,7 +442,7 @@ pub enum EventPlatform {
     Internal,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum EventStatus {
     Draft,
     Published,
@@ -119,7 +458,7 @@ pub struct Ticket {
     pub event_id: Uuid,
     pub ticket_type_id: Uuid,
     pub owner_wallet: String,
-    pub purchase_price: Decimal,
+    pub purchase_price: Decimal, // What was charged, in `purchase_currency`
     pub purchase_currency: String,
     pub purchase_date: DateTime<Utc>,
     pub status: TicketStatus,
@@ -128,9 +467,23 @@ pub struct Ticket {
     pub transferable: bool,
     pub resale_allowed: bool,
     pub resale_price: Option<Decimal>,
+    #[serde(default)]
+    pub external_attendee_id: Option<String>, // Set for tickets sold on an external platform
+    #[serde(default)]
+    pub seat: Option<SeatAssignment>,
+    #[serde(default)]
+    pub checked_in_sessions: Vec<Uuid>,
+    #[serde(default)]
+    pub previous_ticket_id: Option<Uuid>, // The ticket this one was reissued from on transfer
+    #[serde(default)]
+    pub refund_pending: bool, // A refund is owed but hasn't gone through yet
+    #[serde(default)]
+    pub fx_rate: Option<Decimal>, // Ticket type currency to `purchase_currency`, for tickets sold here
+    #[serde(default)]
+    pub payment: Option<PaymentReference>, // How it was paid for; None for tickets sold on other platforms
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TicketStatus {
     Active,
     Used,
@@ -147,6 +500,45 @@ pub struct TicketPurchaseRequest {
     pub quantity: u32,
     pub buyer_wallet: String,
     pub payment_currency: String, // HKD, USD, etc.
+    #[serde(default)]
+    pub seats: SeatSelection, // Ignored for general admission ticket types
+    #[serde(default)]
+    pub quote_id: Option<Uuid>, // Locks the price from `quote_price`; otherwise converted at today's rate
+    #[serde(default)]
+    pub payment_method: Option<PaymentMethod>, // Required unless paying in HKD, which defaults to the HKD stablecoin
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PaymentMethod {
+    HkdStablecoin, // Settled through the stablecoin engine
+    Card,
+    Fps,
+    Stablecoin(String), // Another issuer's token, e.g. "USDC"
+}
+
+/// Where a ticket's money came from, so refunds go back the same way.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaymentReference {
+    pub method: PaymentMethod,
+    pub payment_id: String, // Stablecoin transaction hash or the provider's payment id
+    pub payer: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SeatSelection {
+    /// Exactly these seats, one per ticket.
+    Specific(Vec<SeatAssignment>),
+    /// The best seats in the ticket type's zone, side by side where possible.
+    BestAvailable {
+        #[serde(default)]
+        accessibility: Vec<AccessibilityFeature>, // Every seat must offer these
+    },
+}
+
+impl Default for SeatSelection {
+    fn default() -> Self {
+        SeatSelection::BestAvailable { accessibility: Vec::new() }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -154,6 +546,8 @@ pub struct TicketPurchaseResponse {
     pub purchase_id: Uuid,
     pub tickets: Vec<Ticket>,
     pub total_amount: Decimal,
+    #[serde(default)]
+    pub currency: String, // The currency `total_amount` was charged in
     pub transaction_hash: Option<String>,
     pub nft_mint_transactions: Vec<String>,
 }
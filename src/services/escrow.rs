@@ -0,0 +1,309 @@
+// src/services/escrow.rs
+use crate::models::ticketing::*;
+use crate::services::event_validation::Violation;
+use crate::error::HKDError;
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Platform fee taken out of every release to an organizer, and the platform
+/// account that covers refunds an escrow can no longer pay.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettlementConfig {
+    pub fee_rate: Decimal, // e.g. 0.05 for 5%
+    pub fee_account: String,
+    #[serde(default = "default_refund_account")]
+    pub refund_account: String,
+}
+
+impl Default for SettlementConfig {
+    fn default() -> Self {
+        Self {
+            fee_rate: Decimal::ZERO,
+            fee_account: "platform:fees".to_string(),
+            refund_account: default_refund_account(),
+        }
+    }
+}
+
+impl SettlementConfig {
+    pub fn validate(&self) -> Result<(), HKDError> {
+        let mut violations = Vec::new();
+        if self.fee_rate < Decimal::ZERO || self.fee_rate > Decimal::ONE {
+            violations.push(Violation::new("fee_rate", "must be between 0 and 1"));
+        }
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(HKDError::ValidationFailed(violations))
+        }
+    }
+}
+
+fn default_refund_account() -> String {
+    "platform:refunds".to_string()
+}
+
+/// Ledger of the engine account holding an event's stablecoin sales until
+/// they are settled to the organizer or refunded to buyers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventEscrow {
+    pub event_id: Uuid,
+    pub account: String,
+    pub collected: Decimal,
+    pub refunded: Decimal,
+    pub paid_to_organizer: Decimal,
+    pub fees_paid: Decimal,
+    pub fees_outstanding: Decimal, // Taken from a release but not yet moved to the fee account
+    pub tranches_released: Vec<usize>,
+    pub releases: Vec<EscrowRelease>,
+    // Topped up by the platform for refunds after sales were released; paid back
+    // out of the event's later sales before the organizer receives more
+    #[serde(default)]
+    pub refunds_advanced: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EscrowRelease {
+    pub tranches: Vec<usize>,
+    pub gross: Decimal,
+    pub fee: Decimal,
+    pub organizer_tx: Option<String>,
+    pub fee_tx: Option<String>,
+    pub released_at: DateTime<Utc>,
+}
+
+impl EventEscrow {
+    pub fn new(event_id: Uuid) -> Self {
+        Self {
+            event_id,
+            account: escrow_account(event_id),
+            collected: Decimal::ZERO,
+            refunded: Decimal::ZERO,
+            paid_to_organizer: Decimal::ZERO,
+            fees_paid: Decimal::ZERO,
+            fees_outstanding: Decimal::ZERO,
+            tranches_released: Vec::new(),
+            releases: Vec::new(),
+            refunds_advanced: Decimal::ZERO,
+        }
+    }
+
+    /// What the account should still hold.
+    pub fn balance(&self) -> Decimal {
+        self.collected + self.refunds_advanced - self.refunded - self.paid_to_organizer - self.fees_paid
+    }
+
+    /// What refunds and releases can still draw on; fees already taken stay put.
+    pub fn available(&self) -> Decimal {
+        (self.balance() - self.fees_outstanding).max(Decimal::ZERO)
+    }
+
+    /// How much the platform must top up before the account can refund `amount`.
+    pub fn refund_shortfall(&self, amount: Decimal) -> Decimal {
+        (amount - self.available()).max(Decimal::ZERO)
+    }
+
+    // Sales already allocated to the organizer or the platform
+    fn released(&self) -> Decimal {
+        self.paid_to_organizer + self.fees_paid + self.fees_outstanding
+    }
+}
+
+pub fn escrow_account(event_id: Uuid) -> String {
+    format!("escrow:event:{}", event_id)
+}
+
+/// Tranches not yet released whose time has come. Undated tranches wait for
+/// the event to complete, and a completed event releases everything.
+pub fn due_tranches(schedule: &SettlementSchedule, escrow: &EventEscrow, completed: bool, now: DateTime<Utc>) -> Vec<usize> {
+    schedule.tranches().iter()
+        .enumerate()
+        .filter(|(index, _)| !escrow.tranches_released.contains(index))
+        .filter(|(_, tranche)| completed || tranche.release_at.is_some_and(|release_at| release_at <= now))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Gross amount to release so the organizer has received the combined share
+/// of every tranche released so far, and the platform fee out of it.
+pub fn release_amounts(
+    schedule: &SettlementSchedule,
+    escrow: &EventEscrow,
+    due: &[usize],
+    fee_rate: Decimal,
+) -> (Decimal, Decimal) {
+    let share: Decimal = schedule.tranches().iter()
+        .enumerate()
+        .filter(|(index, _)| escrow.tranches_released.contains(index) || due.contains(index))
+        .map(|(_, tranche)| tranche.share)
+        .sum();
+    let target = to_cents((escrow.collected - escrow.refunded) * share.min(Decimal::ONE));
+    let gross = (target - escrow.released()).max(Decimal::ZERO).min(escrow.available());
+    (gross, to_cents(gross * fee_rate))
+}
+
+/// Tranche shares must be fractions that add up to the whole of the sales.
+pub fn settlement_violations(event: &Event) -> Vec<Violation> {
+    let tranches = match &event.settlement {
+        SettlementSchedule::Tranches(tranches) => tranches,
+        SettlementSchedule::AfterCompletion => return Vec::new(),
+    };
+    let mut violations = Vec::new();
+    if tranches.is_empty() {
+        violations.push(Violation::new("settlement", "must have at least one tranche"));
+    }
+    for (index, tranche) in tranches.iter().enumerate() {
+        if tranche.share <= Decimal::ZERO || tranche.share > Decimal::ONE {
+            violations.push(Violation::new(format!("settlement[{}].share", index), "must be above 0 and at most 1"));
+        }
+    }
+    let total: Decimal = tranches.iter().map(|tranche| tranche.share).sum();
+    if !tranches.is_empty() && total != Decimal::ONE {
+        violations.push(Violation::new("settlement", format!("tranche shares add up to {} instead of 1", total)));
+    }
+    violations
+}
+
+// Rounded down so releases never exceed what the account holds
+fn to_cents(amount: Decimal) -> Decimal {
+    amount.round_dp_with_strategy(2, RoundingStrategy::ToZero)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use chrono::{Duration, TimeZone};
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn fee_rate() -> Decimal {
+        dec("0.05")
+    }
+
+    /// 30% a month before the show, the rest once it has happened.
+    fn schedule() -> SettlementSchedule {
+        SettlementSchedule::Tranches(vec![
+            SettlementTranche { release_at: Some(Utc.with_ymd_and_hms(2030, 5, 14, 0, 0, 0).unwrap()), share: dec("0.3") },
+            SettlementTranche { release_at: None, share: dec("0.7") },
+        ])
+    }
+
+    // Books a release the way the service does once its transfers have gone through
+    fn release(escrow: &mut EventEscrow, due: Vec<usize>, gross: Decimal, fee: Decimal) {
+        escrow.paid_to_organizer += gross - fee;
+        escrow.fees_paid += fee;
+        escrow.tranches_released.extend(due);
+    }
+
+    #[test]
+    fn escrow_accounts_are_per_event() {
+        let event = fixtures::event();
+        let escrow = EventEscrow::new(event.id);
+        assert_eq!(escrow.account, format!("escrow:event:{}", event.id));
+        assert_eq!(escrow.balance(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn dated_tranches_come_due_and_undated_ones_wait_for_completion() {
+        let escrow = EventEscrow::new(fixtures::event().id);
+        let before = Utc.with_ymd_and_hms(2030, 5, 1, 0, 0, 0).unwrap();
+        let after = before + Duration::days(20);
+
+        assert!(due_tranches(&schedule(), &escrow, false, before).is_empty());
+        assert_eq!(due_tranches(&schedule(), &escrow, false, after), vec![0]);
+        assert_eq!(due_tranches(&schedule(), &escrow, true, before), vec![0, 1]);
+        assert_eq!(due_tranches(&SettlementSchedule::AfterCompletion, &escrow, true, before), vec![0]);
+    }
+
+    #[test]
+    fn releases_pay_each_share_of_net_sales_less_the_fee() {
+        let mut escrow = EventEscrow::new(fixtures::event().id);
+        escrow.collected = dec("10000.00");
+        escrow.refunded = dec("1000.00");
+
+        let (gross, fee) = release_amounts(&schedule(), &escrow, &[0], fee_rate());
+        assert_eq!((gross, fee), (dec("2700.00"), dec("135.00")));
+        release(&mut escrow, vec![0], gross, fee);
+        assert_eq!(escrow.balance(), dec("6300.00"));
+
+        // Released tranches aren't paid twice, and later sales top up the share
+        escrow.collected += dec("1000.00");
+        let (gross, _) = release_amounts(&schedule(), &escrow, &[], fee_rate());
+        assert_eq!(gross, dec("300.00"));
+
+        let (gross, fee) = release_amounts(&schedule(), &escrow, &[1], fee_rate());
+        assert_eq!((gross, fee), (dec("7300.00"), dec("365.00")));
+        release(&mut escrow, vec![1], gross, fee);
+        assert_eq!(escrow.balance(), Decimal::ZERO);
+    }
+
+    #[test]
+    fn releases_never_exceed_what_the_account_holds() {
+        let mut escrow = EventEscrow::new(fixtures::event().id);
+        escrow.collected = dec("1000.00");
+        escrow.paid_to_organizer = dec("200.00");
+        // A fee taken but not yet moved stays in the account, spoken for
+        escrow.fees_outstanding = dec("700.00");
+
+        let (gross, fee) = release_amounts(&schedule(), &escrow, &[0, 1], fee_rate());
+        assert_eq!(gross, dec("100.00"));
+        assert_eq!(fee, dec("5.00"));
+    }
+
+    #[test]
+    fn fees_round_down_to_the_cent() {
+        let mut escrow = EventEscrow::new(fixtures::event().id);
+        escrow.collected = dec("333.33");
+        let (gross, fee) = release_amounts(&SettlementSchedule::AfterCompletion, &escrow, &[0], dec("0.025"));
+        assert_eq!((gross, fee), (dec("333.33"), dec("8.33")));
+    }
+
+    #[test]
+    fn refunds_past_the_balance_are_topped_up_and_recovered_from_later_sales() {
+        let mut escrow = EventEscrow::new(fixtures::event().id);
+        escrow.collected = dec("1000.00");
+        let (gross, fee) = release_amounts(&schedule(), &escrow, &[0], fee_rate());
+        release(&mut escrow, vec![0], gross, fee);
+        assert_eq!(escrow.available(), dec("700.00"));
+
+        // Refunding every ticket needs more than the escrow still holds
+        assert_eq!(escrow.refund_shortfall(dec("600.00")), Decimal::ZERO);
+        escrow.refunded += dec("600.00");
+        let shortfall = escrow.refund_shortfall(dec("400.00"));
+        assert_eq!(shortfall, dec("300.00"));
+        escrow.refunds_advanced += shortfall;
+        escrow.refunded += dec("400.00");
+        assert_eq!(escrow.balance(), Decimal::ZERO);
+
+        // New sales repay the platform before the organizer sees more
+        escrow.collected += dec("2000.00");
+        let recovered = escrow.refunds_advanced.min(escrow.available());
+        assert_eq!(recovered, dec("300.00"));
+        escrow.refunds_advanced -= recovered;
+        assert_eq!(escrow.available(), dec("1700.00"));
+
+        let (gross, _) = release_amounts(&schedule(), &escrow, &[1], fee_rate());
+        assert_eq!(gross, dec("1700.00"));
+    }
+
+    #[test]
+    fn fee_rates_must_be_a_fraction() {
+        let valid = SettlementConfig { fee_rate: dec("0.05"), ..SettlementConfig::default() };
+        assert!(valid.validate().is_ok());
+        assert_eq!(SettlementConfig::default().refund_account, "platform:refunds");
+
+        for fee_rate in [dec("-0.01"), dec("1.5")] {
+            let config = SettlementConfig { fee_rate, ..SettlementConfig::default() };
+            match config.validate() {
+                Err(HKDError::ValidationFailed(violations)) => assert_eq!(violations[0].field, "fee_rate"),
+                other => panic!("expected {} to be rejected, got {:?}", fee_rate, other),
+            }
+        }
+    }
+}
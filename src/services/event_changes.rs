@@ -0,0 +1,253 @@
+// src/services/event_changes.rs
+use crate::models::ticketing::*;
+use crate::error::HKDError;
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+// How long holders have to ask for a refund after a material change
+const MATERIAL_CHANGE_REFUND_DAYS: i64 = 14;
+
+// Changes to these let holders ask for their money back
+const MATERIAL_FIELDS: &[&str] = &["event_date", "door_time", "venue"];
+
+/// Edits to an event; fields left `None` stay as they are. Ticket types,
+/// sessions and sync state have their own operations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EventUpdate {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub venue: Option<Venue>,
+    #[serde(default)]
+    pub event_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub door_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub end_date: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub timezone: Option<Tz>,
+    #[serde(default)]
+    pub event_type: Option<EventType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+/// One accepted edit, kept so the event's history can be shown and audited.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventRevision {
+    pub event_id: Uuid,
+    pub version: u64, // The version this edit produced
+    pub changed_at: DateTime<Utc>,
+    pub changes: Vec<FieldChange>,
+    pub refund_window_ends: Option<DateTime<Utc>>, // Set for material changes
+}
+
+impl EventRevision {
+    pub fn is_material(&self) -> bool {
+        self.refund_window_ends.is_some()
+    }
+}
+
+/// What ticket holders are told after a material change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventChangeNotice {
+    pub event_id: Uuid,
+    pub event_title: String,
+    pub version: u64,
+    pub changes: Vec<FieldChange>, // Material changes only
+    pub holder_wallets: Vec<String>,
+    pub refund_window_ends: DateTime<Utc>,
+}
+
+#[async_trait::async_trait]
+pub trait HolderNotifier {
+    async fn notify_event_changed(&self, notice: &EventChangeNotice) -> Result<(), HKDError>;
+}
+
+/// Default notifier until a mail or push provider is wired in.
+pub struct LogHolderNotifier;
+
+#[async_trait::async_trait]
+impl HolderNotifier for LogHolderNotifier {
+    async fn notify_event_changed(&self, notice: &EventChangeNotice) -> Result<(), HKDError> {
+        log::info!(
+            "Event {} changed ({}); notifying {} holders, refunds open until {}",
+            notice.event_id,
+            notice.changes.iter().map(|change| change.field.as_str()).collect::<Vec<_>>().join(", "),
+            notice.holder_wallets.len(),
+            notice.refund_window_ends
+        );
+        Ok(())
+    }
+}
+
+/// Applies `update` to `event`, returning the fields that actually changed.
+pub fn apply_update(event: &mut Event, update: EventUpdate) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    track(&mut changes, "title", &mut event.title, update.title);
+    track(&mut changes, "description", &mut event.description, update.description);
+    track(&mut changes, "venue", &mut event.venue, update.venue);
+    track(&mut changes, "event_date", &mut event.event_date, update.event_date);
+    track(&mut changes, "door_time", &mut event.door_time, update.door_time);
+    track(&mut changes, "end_date", &mut event.end_date, update.end_date.map(Some));
+    track(&mut changes, "timezone", &mut event.timezone, update.timezone.map(Some));
+    track(&mut changes, "event_type", &mut event.event_type, update.event_type);
+    changes
+}
+
+fn track<T: PartialEq + Serialize>(changes: &mut Vec<FieldChange>, field: &str, current: &mut T, new: Option<T>) {
+    let new = match new {
+        Some(new) if new != *current => new,
+        _ => return,
+    };
+    changes.push(FieldChange {
+        field: field.to_string(),
+        before: serde_json::to_value(&*current).unwrap_or(Value::Null),
+        after: serde_json::to_value(&new).unwrap_or(Value::Null),
+    });
+    *current = new;
+}
+
+pub fn material_changes(changes: &[FieldChange]) -> Vec<FieldChange> {
+    changes.iter()
+        .filter(|change| MATERIAL_FIELDS.contains(&change.field.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// Refunds stay open for a fortnight, but never past the (new) start time.
+pub fn refund_window_ends(event: &Event, now: DateTime<Utc>) -> DateTime<Utc> {
+    (now + Duration::days(MATERIAL_CHANGE_REFUND_DAYS)).min(event.event_date)
+}
+
+/// Completed and cancelled events are final.
+pub fn is_editable(status: &EventStatus) -> bool {
+    !matches!(status, EventStatus::Completed | EventStatus::Cancelled)
+}
+
+/// The latest material change a ticket bought at `purchased_at` can still be
+/// refunded for at `at`. Tickets bought after a change were bought knowing it.
+pub fn open_refund_window(
+    revisions: &[EventRevision],
+    purchased_at: DateTime<Utc>,
+    at: DateTime<Utc>,
+) -> Option<&EventRevision> {
+    revisions.iter()
+        .rev()
+        .filter(|revision| purchased_at < revision.changed_at)
+        .find(|revision| revision.refund_window_ends.is_some_and(|ends| at <= ends))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    fn at(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2030, 3, day, 9, 0, 0).unwrap()
+    }
+
+    fn revision(version: u64, changed_at: DateTime<Utc>, refund_window_ends: Option<DateTime<Utc>>) -> EventRevision {
+        EventRevision {
+            event_id: Uuid::nil(),
+            version,
+            changed_at,
+            changes: Vec::new(),
+            refund_window_ends,
+        }
+    }
+
+    #[test]
+    fn only_fields_that_change_are_recorded() {
+        let mut event = fixtures::event();
+        let new_date = event.event_date + Duration::days(1);
+        let update = EventUpdate {
+            title: Some(event.title.clone()),
+            description: Some("Now with fireworks".to_string()),
+            event_date: Some(new_date),
+            ..EventUpdate::default()
+        };
+
+        let changes = apply_update(&mut event, update);
+        let fields: Vec<&str> = changes.iter().map(|change| change.field.as_str()).collect();
+        assert_eq!(fields, vec!["description", "event_date"]);
+        assert_eq!(changes[0].after, json!("Now with fireworks"));
+        assert_eq!(event.event_date, new_date);
+    }
+
+    #[test]
+    fn an_empty_update_changes_nothing() {
+        let mut event = fixtures::event();
+        let before = event.clone();
+        assert!(apply_update(&mut event, EventUpdate::default()).is_empty());
+        assert_eq!(event.title, before.title);
+        assert_eq!(event.venue, before.venue);
+    }
+
+    #[test]
+    fn date_door_and_venue_changes_are_material() {
+        let mut event = fixtures::event();
+        let mut moved = event.venue.clone();
+        moved.name = "Hong Kong Coliseum".to_string();
+        let door_time = event.door_time - Duration::minutes(30);
+        let changes = apply_update(&mut event, EventUpdate {
+            title: Some("Harbour Lights".to_string()),
+            venue: Some(moved),
+            door_time: Some(door_time),
+            ..EventUpdate::default()
+        });
+
+        let material: Vec<String> = material_changes(&changes).into_iter().map(|change| change.field).collect();
+        assert_eq!(material, vec!["venue", "door_time"]);
+    }
+
+    #[test]
+    fn refund_windows_last_a_fortnight_but_close_at_the_start() {
+        let mut event = fixtures::event();
+        let now = event.event_date - Duration::days(30);
+        assert_eq!(refund_window_ends(&event, now), now + Duration::days(14));
+
+        event.event_date = now + Duration::days(3);
+        assert_eq!(refund_window_ends(&event, now), event.event_date);
+    }
+
+    #[test]
+    fn tickets_bought_before_an_open_material_change_can_be_refunded() {
+        let revisions = vec![
+            revision(1, at(1), Some(at(15))),
+            revision(2, at(5), None),
+            revision(3, at(10), Some(at(24))),
+        ];
+
+        // The latest applicable change wins
+        let open = open_refund_window(&revisions, at(2), at(12)).unwrap();
+        assert_eq!(open.version, 3);
+        // Nothing is left to refund against once the window closes
+        assert!(open_refund_window(&revisions, at(2), at(25)).is_none());
+        // Bought after the latest change, so it was bought knowing about it
+        assert!(open_refund_window(&revisions, at(11), at(12)).is_none());
+        // Between the two material changes: only the later one applies
+        assert_eq!(open_refund_window(&revisions, at(6), at(12)).map(|revision| revision.version), Some(3));
+        assert!(!revisions[1].is_material());
+    }
+
+    #[test]
+    fn completed_and_cancelled_events_are_final() {
+        assert!(is_editable(&EventStatus::Draft));
+        assert!(is_editable(&EventStatus::OnSale));
+        assert!(!is_editable(&EventStatus::Completed));
+        assert!(!is_editable(&EventStatus::Cancelled));
+    }
+}
@@ -0,0 +1,308 @@
+// src/services/event_import.rs
+use crate::models::ticketing::*;
+use crate::services::event_validation::Violation;
+use crate::services::external_apis::EventFilters;
+use crate::services::inventory_sync::record_channel_sales;
+use crate::services::ticketing_service::TicketingService;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub platform: EventPlatform,
+    pub created: u32,
+    pub updated: u32,
+    pub unchanged: u32,
+    pub rejected: Vec<ImportRejection>, // Failed validation and were left as they were
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportRejection {
+    pub external_event_id: Option<String>,
+    pub violations: Vec<Violation>,
+}
+
+impl ImportReport {
+    pub fn new(platform: EventPlatform) -> Self {
+        Self {
+            platform,
+            created: 0,
+            updated: 0,
+            unchanged: 0,
+            rejected: Vec::new(),
+        }
+    }
+}
+
+pub enum ImportOutcome {
+    Created(Event),
+    Updated(Event),
+    Unchanged(Uuid),
+}
+
+/// Reconciles a freshly fetched external event with the copy we already hold.
+///
+/// An event we published to the platform stays ours: only the platform's sales
+/// of its ticket types are taken, see `reconcile_publication`. For events
+/// imported from the platform, internal ids, timestamps, sync state and fields
+/// the external platforms don't know about (organizer wallet, perks, NFT
+/// metadata, transfer policy, inventory, seating, sessions, settlement) are kept
+/// from the existing event; ticket types are matched by external id, then name.
+pub fn reconcile_imported_event(existing: Option<&Event>, mut imported: Event) -> ImportOutcome {
+    let platform = imported.platform.clone();
+    let existing = match existing {
+        Some(existing) => existing,
+        None => return ImportOutcome::Created(imported),
+    };
+    if existing.publications.contains_key(&platform) {
+        return reconcile_publication(existing, &imported);
+    }
+
+    imported.id = existing.id;
+    imported.organizer = existing.organizer.clone();
+    imported.created_at = existing.created_at;
+    imported.updated_at = existing.updated_at;
+    imported.version = existing.version;
+    // Derived on store; carried over so only real changes count as updates
+    imported.local_times = existing.local_times.clone();
+    imported.external_event_id = existing.external_event_id.clone();
+    let mut publications = existing.publications.clone();
+    for (published_on, publication) in imported.publications.drain() {
+        publications.entry(published_on).or_insert(publication);
+    }
+    imported.publications = publications;
+    if existing.venue.id.is_some() {
+        // Registered venues are maintained here, not by the platform
+        imported.venue = existing.venue.clone();
+    } else {
+        imported.venue.seat_map = existing.venue.seat_map.clone();
+        imported.venue.areas = existing.venue.areas.clone();
+    }
+    imported.sold_seats = existing.sold_seats.clone();
+    imported.sessions = existing.sessions.clone();
+    imported.recurrence = existing.recurrence.clone();
+    imported.settlement = existing.settlement.clone();
+
+    for ticket_type in imported.ticket_types.iter_mut() {
+        let external_id = ticket_type.external_ids.get(&platform).cloned();
+        let known = existing.ticket_types.iter()
+            .find(|tt| external_id.is_some() && tt.external_ids.get(&platform) == external_id.as_ref())
+            .or_else(|| existing.ticket_types.iter().find(|tt| tt.name == ticket_type.name));
+
+        if let Some(known) = known {
+            ticket_type.id = known.id;
+            ticket_type.perks = known.perks.clone();
+            ticket_type.nft_metadata = known.nft_metadata.clone();
+            ticket_type.transfer_policy = known.transfer_policy.clone();
+            ticket_type.price_zone = known.price_zone.clone();
+            ticket_type.area = known.area.clone();
+            ticket_type.session_access = known.session_access.clone();
+
+            // Stock is ours once known: the platform only sees its own allocation
+            ticket_type.quantity_available = known.quantity_available;
+            ticket_type.inventory = known.inventory.clone();
+
+            let mut channel_sales = known.channel_sales.clone();
+            channel_sales.extend(ticket_type.channel_sales.drain());
+            ticket_type.quantity_sold = channel_sales.values().fold(0u32, |total, &sold| total.saturating_add(sold));
+            ticket_type.channel_sales = channel_sales;
+
+            let mut external_ids = known.external_ids.clone();
+            external_ids.extend(ticket_type.external_ids.drain());
+            ticket_type.external_ids = external_ids;
+        }
+    }
+
+    if &imported == existing {
+        ImportOutcome::Unchanged(existing.id)
+    } else {
+        imported.updated_at = chrono::Utc::now();
+        ImportOutcome::Updated(imported)
+    }
+}
+
+/// Takes the platform's sales of an event we published to it. Title, dates,
+/// status and ticket types are ours, so everything else the platform reports
+/// is ignored, including ticket types it doesn't have our external id for.
+pub fn reconcile_publication(existing: &Event, imported: &Event) -> ImportOutcome {
+    let platform = &imported.platform;
+    let mut event = existing.clone();
+
+    for ticket_type in event.ticket_types.iter_mut() {
+        let external_id = match ticket_type.external_ids.get(platform) {
+            Some(external_id) => external_id,
+            None => continue,
+        };
+        let sold = imported.ticket_types.iter()
+            .find(|tt| tt.external_ids.get(platform) == Some(external_id))
+            .and_then(|tt| tt.channel_sales.get(platform).copied());
+        if let Some(sold) = sold {
+            record_channel_sales(ticket_type, platform.clone(), sold);
+        }
+    }
+
+    if &event == existing {
+        ImportOutcome::Unchanged(existing.id)
+    } else {
+        event.updated_at = chrono::Utc::now();
+        ImportOutcome::Updated(event)
+    }
+}
+
+/// Periodically imports events from every registered platform.
+pub async fn run_import_job(
+    service: Arc<Mutex<TicketingService>>,
+    filters: EventFilters,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        let clients = service.lock().await.platform_clients();
+        for (platform, client) in clients {
+            // Listing pages through rate limits and retries; other requests go on meanwhile
+            match client.list_events(filters.clone()).await {
+                Ok(external_events) => {
+                    let report = service.lock().await.apply_imported_events(platform.clone(), external_events).await;
+                    log::info!(
+                        "Imported events from {:?}: {} created, {} updated, {} unchanged, {} rejected",
+                        report.platform, report.created, report.updated, report.unchanged, report.rejected.len()
+                    );
+                }
+                Err(e) => log::warn!("Event import from {:?} failed: {}", platform, e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    // What a platform hands back for an event we hold: its own ids and
+    // organizer, and none of the fields it has no notion of
+    fn as_listed(existing: &Event) -> Event {
+        let mut listed = existing.clone();
+        listed.id = Uuid::new_v4();
+        listed.organizer = "Harbour Lights Ltd".to_string();
+        listed.created_at = Utc::now();
+        listed.updated_at = Utc::now();
+        for ticket_type in listed.ticket_types.iter_mut() {
+            ticket_type.id = Uuid::new_v4();
+            ticket_type.perks.clear();
+            ticket_type.nft_metadata = None;
+            ticket_type.transfer_policy = TransferPolicy::Transferable;
+        }
+        listed
+    }
+
+    fn held_event() -> Event {
+        let mut event = fixtures::event();
+        event.platform = EventPlatform::Eventbrite;
+        event.external_event_id = Some("eb-1001".to_string());
+        let ticket_type = &mut event.ticket_types[0];
+        ticket_type.transfer_policy = TransferPolicy::Soulbound;
+        ticket_type.perks.push(Perk {
+            name: "Early entry".to_string(),
+            description: "Doors 30 minutes early".to_string(),
+            category: PerkCategory::EarlyEntry,
+        });
+        event
+    }
+
+    #[test]
+    fn events_we_dont_hold_are_created_as_listed() {
+        let listed = as_listed(&held_event());
+        match reconcile_imported_event(None, listed.clone()) {
+            ImportOutcome::Created(event) => assert_eq!(event, listed),
+            _ => panic!("expected a new event"),
+        }
+    }
+
+    #[test]
+    fn an_unchanged_listing_changes_nothing() {
+        let held = held_event();
+        assert!(matches!(reconcile_imported_event(Some(&held), as_listed(&held)), ImportOutcome::Unchanged(id) if id == held.id));
+    }
+
+    #[test]
+    fn updates_keep_our_ids_and_local_only_fields() {
+        let held = held_event();
+        let mut listed = as_listed(&held);
+        listed.title = "Harbour Lights Festival 2030".to_string();
+        listed.ticket_types[0].price = Decimal::new(98000, 2);
+
+        let event = match reconcile_imported_event(Some(&held), listed) {
+            ImportOutcome::Updated(event) => event,
+            _ => panic!("expected an update"),
+        };
+        assert_eq!(event.title, "Harbour Lights Festival 2030");
+        assert_eq!(event.id, held.id);
+        assert_eq!(event.organizer, held.organizer);
+        assert_eq!(event.created_at, held.created_at);
+        assert!(event.updated_at > held.updated_at);
+
+        let ticket_type = &event.ticket_types[0];
+        assert_eq!(ticket_type.id, held.ticket_types[0].id);
+        assert_eq!(ticket_type.price, Decimal::new(98000, 2));
+        assert_eq!(ticket_type.perks, held.ticket_types[0].perks);
+        assert_eq!(ticket_type.transfer_policy, TransferPolicy::Soulbound);
+    }
+
+    #[test]
+    fn ticket_types_are_matched_by_name() {
+        let held = held_event();
+        let mut listed = as_listed(&held);
+        listed.ticket_types[0].name = "Standing".to_string();
+
+        let event = match reconcile_imported_event(Some(&held), listed) {
+            ImportOutcome::Updated(event) => event,
+            _ => panic!("expected an update"),
+        };
+        // A renamed ticket type is a new one to us
+        assert_ne!(event.ticket_types[0].id, held.ticket_types[0].id);
+        assert_eq!(event.ticket_types[0].transfer_policy, TransferPolicy::Transferable);
+    }
+
+    #[test]
+    fn events_we_published_only_take_the_platforms_sales() {
+        let mut ours = fixtures::event();
+        ours.publications.insert(EventPlatform::Eventbrite, PlatformSync::pending(EventPlatform::Eventbrite));
+        ours.ticket_types[0].external_ids.insert(EventPlatform::Eventbrite, "eb-tt-1".to_string());
+
+        let mut listed = as_listed(&ours);
+        listed.platform = EventPlatform::Eventbrite;
+        listed.title = "Harbour Lights".to_string();
+        listed.ticket_types[0].channel_sales.insert(EventPlatform::Eventbrite, 40);
+        let mut theirs_only = fixtures::ticket_type("Platform Special", Decimal::new(10000, 2), 10);
+        theirs_only.channel_sales.insert(EventPlatform::Eventbrite, 5);
+        listed.ticket_types.push(theirs_only);
+
+        let event = match reconcile_imported_event(Some(&ours), listed) {
+            ImportOutcome::Updated(event) => event,
+            _ => panic!("expected the platform's sales to be taken"),
+        };
+        assert_eq!(event.title, ours.title);
+        assert_eq!(event.platform, EventPlatform::Internal);
+        assert_eq!(event.ticket_types.len(), 1);
+        assert_eq!(event.ticket_types[0].channel_sales.get(&EventPlatform::Eventbrite), Some(&40));
+        assert_eq!(event.ticket_types[0].quantity_sold, 40);
+    }
+
+    #[test]
+    fn a_publication_without_new_sales_is_unchanged() {
+        let mut ours = fixtures::event();
+        ours.ticket_types[0].external_ids.insert(EventPlatform::Eventbrite, "eb-tt-1".to_string());
+        let mut listed = as_listed(&ours);
+        listed.platform = EventPlatform::Eventbrite;
+
+        assert!(matches!(reconcile_publication(&ours, &listed), ImportOutcome::Unchanged(id) if id == ours.id));
+    }
+}
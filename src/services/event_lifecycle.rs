@@ -0,0 +1,198 @@
+// src/services/event_lifecycle.rs
+use crate::models::ticketing::*;
+use crate::error::HKDError;
+use crate::services::inventory_sync::venue_remaining;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Actor recorded for transitions the service makes on its own
+pub const SYSTEM_ACTOR: &str = "system";
+
+/// Audit record of one status change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusTransition {
+    pub event_id: Uuid,
+    pub from: EventStatus,
+    pub to: EventStatus,
+    pub actor: String, // User, wallet, `system` or the importing platform
+    pub reason: Option<String>,
+    pub at: DateTime<Utc>,
+}
+
+/// Draft → Published → OnSale ↔ SoldOut → Completed, and Cancelled from any
+/// state that hasn't finished.
+pub fn is_allowed(from: &EventStatus, to: &EventStatus) -> bool {
+    use EventStatus::*;
+    matches!(
+        (from, to),
+        (Draft, Published)
+            | (Published, OnSale)
+            | (OnSale, SoldOut)
+            | (SoldOut, OnSale)
+            | (OnSale, Completed)
+            | (SoldOut, Completed)
+            | (Draft | Published | OnSale | SoldOut, Cancelled)
+    )
+}
+
+/// Checks a transition against the table and the event's current state.
+pub fn check_transition(event: &Event, to: &EventStatus, now: DateTime<Utc>) -> Result<(), HKDError> {
+    let refuse = |reason: &str| HKDError::InvalidTransition {
+        from: event.status.clone(),
+        to: to.clone(),
+        reason: reason.to_string(),
+    };
+    if !is_allowed(&event.status, to) {
+        return Err(refuse("not a permitted step"));
+    }
+
+    match to {
+        EventStatus::Published | EventStatus::OnSale if event.ticket_types.is_empty() => {
+            Err(refuse("the event has no ticket types"))
+        }
+        EventStatus::OnSale if event.event_date <= now => Err(refuse("the event has already started")),
+        EventStatus::OnSale if event.status == EventStatus::SoldOut && remaining(event) == 0 => {
+            Err(refuse("no tickets have come back into stock"))
+        }
+        EventStatus::SoldOut if remaining(event) > 0 => Err(refuse("tickets are still available")),
+        EventStatus::Completed if event.event_date > now => Err(refuse("the event hasn't started yet")),
+        _ => Ok(()),
+    }
+}
+
+/// Tickets still unsold on any channel, across ticket types. An event is only
+/// sold out once every platform's share is gone, not just our own.
+pub fn remaining(event: &Event) -> u32 {
+    let unsold: u64 = event.ticket_types.iter()
+        .map(|tt| u64::from(tt.quantity_available.saturating_sub(tt.quantity_sold)))
+        .sum();
+    u32::try_from(unsold).unwrap_or(u32::MAX).min(venue_remaining(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use chrono::Duration;
+
+    fn event_in(status: EventStatus) -> Event {
+        let mut event = fixtures::event();
+        event.status = status;
+        event.event_date = Utc::now() + Duration::days(30);
+        event
+    }
+
+    fn refusal(event: &Event, to: EventStatus) -> String {
+        match check_transition(event, &to, Utc::now()) {
+            Err(HKDError::InvalidTransition { reason, .. }) => reason,
+            other => panic!("expected {:?} → {:?} to be refused, got {:?}", event.status, to, other),
+        }
+    }
+
+    #[test]
+    fn the_table_only_allows_forward_steps_and_cancelling() {
+        use EventStatus::*;
+        let allowed = [
+            (Draft, Published),
+            (Published, OnSale),
+            (OnSale, SoldOut),
+            (SoldOut, OnSale),
+            (OnSale, Completed),
+            (SoldOut, Completed),
+            (Draft, Cancelled),
+            (Published, Cancelled),
+            (OnSale, Cancelled),
+            (SoldOut, Cancelled),
+        ];
+        let statuses = [Draft, Published, OnSale, SoldOut, Completed, Cancelled];
+        for from in &statuses {
+            for to in &statuses {
+                let expected = allowed.iter().any(|(a, b)| a == from && b == to);
+                assert_eq!(is_allowed(from, to), expected, "{:?} → {:?}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn events_need_ticket_types_to_be_published_or_sold() {
+        let mut event = event_in(EventStatus::Draft);
+        event.ticket_types.clear();
+        assert_eq!(refusal(&event, EventStatus::Published), "the event has no ticket types");
+
+        event.status = EventStatus::Published;
+        assert_eq!(refusal(&event, EventStatus::OnSale), "the event has no ticket types");
+    }
+
+    #[test]
+    fn sales_cannot_open_once_the_event_has_started() {
+        let mut event = event_in(EventStatus::Published);
+        assert!(check_transition(&event, &EventStatus::OnSale, Utc::now()).is_ok());
+
+        event.event_date = Utc::now() - Duration::hours(1);
+        assert_eq!(refusal(&event, EventStatus::OnSale), "the event has already started");
+    }
+
+    #[test]
+    fn sold_out_follows_the_stock() {
+        let mut event = event_in(EventStatus::OnSale);
+        assert_eq!(refusal(&event, EventStatus::SoldOut), "tickets are still available");
+
+        let ticket_type = &mut event.ticket_types[0];
+        ticket_type.quantity_sold = ticket_type.quantity_available;
+        ticket_type.channel_sales.insert(EventPlatform::Internal, ticket_type.quantity_available);
+        assert_eq!(remaining(&event), 0);
+        assert!(check_transition(&event, &EventStatus::SoldOut, Utc::now()).is_ok());
+
+        event.status = EventStatus::SoldOut;
+        assert_eq!(refusal(&event, EventStatus::OnSale), "no tickets have come back into stock");
+
+        // A refund puts a ticket back on sale
+        let ticket_type = &mut event.ticket_types[0];
+        ticket_type.quantity_sold -= 1;
+        ticket_type.channel_sales.insert(EventPlatform::Internal, ticket_type.quantity_sold);
+        assert!(check_transition(&event, &EventStatus::OnSale, Utc::now()).is_ok());
+    }
+
+    #[test]
+    fn only_started_events_complete() {
+        let mut event = event_in(EventStatus::OnSale);
+        assert_eq!(refusal(&event, EventStatus::Completed), "the event hasn't started yet");
+
+        event.event_date = Utc::now() - Duration::hours(2);
+        assert!(check_transition(&event, &EventStatus::Completed, Utc::now()).is_ok());
+    }
+
+    #[test]
+    fn finished_events_stay_finished() {
+        for status in [EventStatus::Completed, EventStatus::Cancelled] {
+            let event = event_in(status);
+            assert_eq!(refusal(&event, EventStatus::OnSale), "not a permitted step");
+            assert_eq!(refusal(&event, EventStatus::Cancelled), "not a permitted step");
+        }
+    }
+
+    #[test]
+    fn stock_left_on_another_channel_keeps_the_event_on_sale() {
+        let mut event = event_in(EventStatus::OnSale);
+        let ticket_type = &mut event.ticket_types[0];
+        ticket_type.quantity_available = 100;
+        ticket_type.inventory = InventoryAllocation::Split(vec![
+            ChannelAllocation { platform: EventPlatform::Eventbrite, percent: 40 },
+        ]);
+        // Our 60 are gone, Eventbrite's 40 aren't
+        ticket_type.quantity_sold = 60;
+        ticket_type.channel_sales.insert(EventPlatform::Internal, 60);
+
+        assert_eq!(remaining(&event), 40);
+        assert_eq!(refusal(&event, EventStatus::SoldOut), "tickets are still available");
+    }
+
+    #[test]
+    fn remaining_is_capped_by_the_venue() {
+        let mut event = event_in(EventStatus::OnSale);
+        event.venue.capacity = 120;
+        event.ticket_types[0].quantity_available = 500;
+        assert_eq!(remaining(&event), 120);
+    }
+}
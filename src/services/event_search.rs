@@ -0,0 +1,324 @@
+// src/services/event_search.rs
+use crate::models::ticketing::*;
+use crate::services::external_apis::EventFilters;
+use crate::services::inventory_sync::{channel_available, venue_remaining};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// A search over local events. `filters` narrow the set; `text` ranks it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventQuery {
+    #[serde(default)]
+    pub filters: EventFilters,
+    // Every term must appear in the title, description or organizer
+    #[serde(default)]
+    pub text: Option<String>,
+    // Only events with tickets on sale right now
+    #[serde(default)]
+    pub available_only: bool,
+    #[serde(default)]
+    pub sort: EventSort,
+    #[serde(default = "first_page")]
+    pub page: u32, // 1-based
+    #[serde(default = "default_page_size")]
+    pub page_size: u32,
+}
+
+impl Default for EventQuery {
+    fn default() -> Self {
+        Self {
+            filters: EventFilters::default(),
+            text: None,
+            available_only: false,
+            sort: EventSort::default(),
+            page: first_page(),
+            page_size: default_page_size(),
+        }
+    }
+}
+
+fn first_page() -> u32 {
+    1
+}
+
+fn default_page_size() -> u32 {
+    DEFAULT_PAGE_SIZE
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum EventSort {
+    #[default]
+    DateAscending,
+    DateDescending,
+    PriceAscending, // By the cheapest ticket type
+    PriceDescending,
+    Relevance, // Falls back to date order without a text query
+    RecentlyAdded,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventSearchResults<'a> {
+    pub events: Vec<&'a Event>,
+    pub total: usize, // Matches across all pages
+    pub page: u32,
+    pub page_size: u32,
+}
+
+pub fn search_events<'a>(
+    events: impl Iterator<Item = &'a Event>,
+    query: &EventQuery,
+    now: DateTime<Utc>,
+) -> EventSearchResults<'a> {
+    let terms = query.text.as_deref().map(search_terms).unwrap_or_default();
+
+    let mut matches: Vec<(&Event, u32)> = events
+        // Asking for a status by name finds it, drafts and past events included
+        .filter(|event| (query.filters.status.is_some() || is_listed(event)) && query.filters.matches(event))
+        .filter(|event| !query.available_only || is_on_sale(event, now))
+        .filter_map(|event| {
+            if terms.is_empty() {
+                return Some((event, 0));
+            }
+            relevance(event, &terms).map(|score| (event, score))
+        })
+        .collect();
+
+    matches.sort_by(|(a, a_score), (b, b_score)| {
+        let by_date = a.event_date.cmp(&b.event_date);
+        let order = match query.sort {
+            EventSort::DateAscending => by_date,
+            EventSort::DateDescending => by_date.reverse(),
+            EventSort::PriceAscending => compare_prices(a, b, false),
+            EventSort::PriceDescending => compare_prices(a, b, true),
+            EventSort::Relevance => b_score.cmp(a_score).then(by_date),
+            EventSort::RecentlyAdded => b.created_at.cmp(&a.created_at),
+        };
+        // Ties broken by id so pages are stable between requests
+        order.then_with(|| a.id.cmp(&b.id))
+    });
+
+    let page = query.page.max(1);
+    let page_size = query.page_size.clamp(1, MAX_PAGE_SIZE);
+    let total = matches.len();
+    let events = matches.into_iter()
+        .map(|(event, _)| event)
+        .skip((page.saturating_sub(1) as usize).saturating_mul(page_size as usize))
+        .take(page_size as usize)
+        .collect();
+
+    EventSearchResults { events, total, page, page_size }
+}
+
+/// Drafts and events that are over or called off aren't discoverable
+/// unless a search asks for their status.
+pub fn is_listed(event: &Event) -> bool {
+    !matches!(event.status, EventStatus::Draft | EventStatus::Cancelled | EventStatus::Completed)
+}
+
+/// Whether any ticket type can be bought directly right now.
+pub fn is_on_sale(event: &Event, now: DateTime<Utc>) -> bool {
+    if event.status != EventStatus::OnSale {
+        return false;
+    }
+    let remaining = venue_remaining(event);
+    event.ticket_types.iter().any(|tt| {
+        tt.sales_start <= now
+            && now <= tt.sales_end
+            && channel_available(tt, &EventPlatform::Internal, remaining) > 0
+    })
+}
+
+fn search_terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Title hits weigh most, then organizer, then description. `None` unless
+/// every term matches somewhere.
+fn relevance(event: &Event, terms: &[String]) -> Option<u32> {
+    let title = event.title.to_lowercase();
+    let organizer = event.organizer.to_lowercase();
+    let description = event.description.to_lowercase();
+
+    let mut score = 0;
+    for term in terms {
+        let term_score = 3 * u32::from(title.contains(term.as_str()))
+            + 2 * u32::from(organizer.contains(term.as_str()))
+            + u32::from(description.contains(term.as_str()));
+        if term_score == 0 {
+            return None;
+        }
+        score += term_score;
+    }
+    Some(score)
+}
+
+fn lowest_price(event: &Event) -> Option<Decimal> {
+    event.ticket_types.iter().map(|tt| tt.price).min()
+}
+
+// Events without ticket types sort last either way
+fn compare_prices(a: &Event, b: &Event, descending: bool) -> Ordering {
+    match (lowest_price(a), lowest_price(b)) {
+        (Some(a), Some(b)) if descending => b.cmp(&a),
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use chrono::{Duration, TimeZone};
+    use uuid::Uuid;
+
+    fn event_on(day: u32, title: &str) -> Event {
+        let mut event = fixtures::event();
+        event.title = title.to_string();
+        event.event_date = Utc.with_ymd_and_hms(2030, 6, day, 12, 0, 0).unwrap();
+        event
+    }
+
+    fn titles(results: &EventSearchResults) -> Vec<String> {
+        results.events.iter().map(|event| event.title.clone()).collect()
+    }
+
+    #[test]
+    fn pages_cover_every_match_once_in_date_order() {
+        let events: Vec<Event> = [5, 1, 4, 2, 3].iter()
+            .map(|day| event_on(*day, &format!("Night {}", day)))
+            .collect();
+        let now = Utc::now();
+
+        let mut seen = Vec::new();
+        for page in 1..=3 {
+            let query = EventQuery { page, page_size: 2, ..EventQuery::default() };
+            let results = search_events(events.iter(), &query, now);
+            assert_eq!(results.total, 5);
+            seen.extend(titles(&results));
+        }
+        assert_eq!(seen, vec!["Night 1", "Night 2", "Night 3", "Night 4", "Night 5"]);
+    }
+
+    #[test]
+    fn page_size_is_clamped() {
+        let events: Vec<Event> = (1..=3).map(|day| event_on(day, "Night")).collect();
+        let query = EventQuery { page: 0, page_size: 0, ..EventQuery::default() };
+
+        let results = search_events(events.iter(), &query, Utc::now());
+        assert_eq!((results.page, results.page_size), (1, 1));
+        assert_eq!(results.events.len(), 1);
+    }
+
+    #[test]
+    fn pages_past_the_end_are_empty() {
+        let events: Vec<Event> = (1..=3).map(|day| event_on(day, "Night")).collect();
+        let query = EventQuery { page: u32::MAX, page_size: 2, ..EventQuery::default() };
+
+        let results = search_events(events.iter(), &query, Utc::now());
+        assert_eq!(results.total, 3);
+        assert!(results.events.is_empty());
+    }
+
+    #[test]
+    fn drafts_and_finished_events_are_not_listed() {
+        let mut draft = event_on(1, "Draft");
+        draft.status = EventStatus::Draft;
+        let mut cancelled = event_on(2, "Cancelled");
+        cancelled.status = EventStatus::Cancelled;
+        let events = [draft, cancelled, event_on(3, "Published")];
+
+        let results = search_events(events.iter(), &EventQuery::default(), Utc::now());
+        assert_eq!(titles(&results), vec!["Published"]);
+    }
+
+    #[test]
+    fn a_status_filter_finds_unlisted_events() {
+        let mut draft = event_on(1, "Draft");
+        draft.status = EventStatus::Draft;
+        let mut completed = event_on(2, "Completed");
+        completed.status = EventStatus::Completed;
+        let events = [draft, completed, event_on(3, "Published")];
+
+        for (status, expected) in [(EventStatus::Draft, "Draft"), (EventStatus::Completed, "Completed")] {
+            let query = EventQuery {
+                filters: EventFilters { status: Some(status), ..EventFilters::default() },
+                ..EventQuery::default()
+            };
+            assert_eq!(titles(&search_events(events.iter(), &query, Utc::now())), vec![expected]);
+        }
+    }
+
+    #[test]
+    fn every_term_must_match_and_title_hits_rank_first() {
+        let mut in_title = event_on(2, "Harbour Jazz Night");
+        in_title.description = "Live music".to_string();
+        let mut in_description = event_on(1, "Late Session");
+        in_description.description = "Jazz by the harbour".to_string();
+        let mut partial = event_on(3, "Jazz Brunch");
+        partial.description = "Pancakes".to_string();
+        let events = [in_title, in_description, partial];
+
+        let query = EventQuery {
+            text: Some("harbour jazz".to_string()),
+            sort: EventSort::Relevance,
+            ..EventQuery::default()
+        };
+        let results = search_events(events.iter(), &query, Utc::now());
+        assert_eq!(titles(&results), vec!["Harbour Jazz Night", "Late Session"]);
+    }
+
+    #[test]
+    fn available_only_keeps_events_selling_right_now() {
+        let now = Utc::now();
+        let mut on_sale = event_on(1, "On sale");
+        on_sale.status = EventStatus::OnSale;
+        let mut ticket_type = fixtures::ticket_type("GA", Decimal::new(30000, 2), 100);
+        ticket_type.sales_start = now - Duration::days(1);
+        ticket_type.sales_end = now + Duration::days(1);
+        on_sale.ticket_types = vec![ticket_type.clone()];
+
+        let mut sold_out = on_sale.clone();
+        sold_out.id = Uuid::new_v4();
+        sold_out.title = "Sold out".to_string();
+        sold_out.ticket_types[0].quantity_sold = 100;
+
+        // Sales haven't opened yet
+        let mut announced = event_on(2, "Announced");
+        ticket_type.sales_start = now + Duration::days(1);
+        ticket_type.sales_end = now + Duration::days(2);
+        announced.ticket_types = vec![ticket_type];
+
+        let events = [on_sale, sold_out, announced];
+        let query = EventQuery { available_only: true, ..EventQuery::default() };
+        let results = search_events(events.iter(), &query, now);
+        assert_eq!(titles(&results), vec!["On sale"]);
+    }
+
+    #[test]
+    fn price_sort_puts_events_without_tickets_last() {
+        let mut cheap = event_on(3, "Cheap");
+        cheap.ticket_types = vec![fixtures::ticket_type("GA", Decimal::new(10000, 2), 10)];
+        let mut dear = event_on(1, "Dear");
+        dear.ticket_types = vec![fixtures::ticket_type("GA", Decimal::new(90000, 2), 10)];
+        let mut free_entry = event_on(2, "No tickets");
+        free_entry.ticket_types.clear();
+        let events = [free_entry, dear, cheap];
+
+        let ascending = EventQuery { sort: EventSort::PriceAscending, ..EventQuery::default() };
+        assert_eq!(titles(&search_events(events.iter(), &ascending, Utc::now())), vec!["Cheap", "Dear", "No tickets"]);
+
+        let descending = EventQuery { sort: EventSort::PriceDescending, ..EventQuery::default() };
+        assert_eq!(titles(&search_events(events.iter(), &descending, Utc::now())), vec!["Dear", "Cheap", "No tickets"]);
+    }
+}
@@ -0,0 +1,206 @@
+// src/services/event_validation.rs
+use crate::models::ticketing::*;
+use crate::services::escrow::settlement_violations;
+use crate::services::schedule::schedule_violations;
+use crate::services::sessions::session_violations;
+use crate::services::venue_registry::capacity_violations;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+// Currencies we can price tickets in and settle through the stablecoin engine
+pub const SUPPORTED_CURRENCIES: &[&str] = &[
+    "HKD", "USD", "CNY", "EUR", "GBP", "JPY", "SGD", "TWD", "MOP", "KRW", "AUD", "THB",
+];
+
+// Schemes NFT marketplaces resolve for images and links
+const NFT_URL_SCHEMES: &[&str] = &["https", "http", "ipfs", "ar"];
+
+/// One problem with an event, located by a path such as `ticket_types[2].price`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Violation {
+    pub field: String,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Every rule an event must satisfy before it is stored, whether created here
+/// or imported. Returns all violations rather than stopping at the first.
+pub fn validate_event(event: &Event) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    if event.title.trim().is_empty() {
+        violations.push(Violation::new("title", "must not be empty"));
+    }
+
+    let mut ticket_type_ids = HashSet::new();
+    for (index, ticket_type) in event.ticket_types.iter().enumerate() {
+        let field = |name: &str| format!("ticket_types[{}].{}", index, name);
+
+        if !ticket_type_ids.insert(ticket_type.id) {
+            violations.push(Violation::new(field("id"), format!("{} is used by another ticket type", ticket_type.id)));
+        }
+        if ticket_type.name.trim().is_empty() {
+            violations.push(Violation::new(field("name"), "must not be empty"));
+        }
+        if ticket_type.price < Decimal::ZERO {
+            violations.push(Violation::new(field("price"), "must not be negative"));
+        }
+        if !SUPPORTED_CURRENCIES.contains(&ticket_type.currency.as_str()) {
+            violations.push(Violation::new(field("currency"), format!("{} isn't supported", ticket_type.currency)));
+        }
+        if let Some(metadata) = &ticket_type.nft_metadata {
+            let urls = [
+                ("image", Some(&metadata.image)),
+                ("animation_url", metadata.animation_url.as_ref()),
+                ("external_url", metadata.external_url.as_ref()),
+            ];
+            for (name, url) in urls {
+                if let Some(url) = url.filter(|url| !is_well_formed_url(url)) {
+                    violations.push(Violation::new(
+                        field(&format!("nft_metadata.{}", name)),
+                        format!("{} isn't a valid URL", url),
+                    ));
+                }
+            }
+        }
+    }
+
+    violations.extend(schedule_violations(event));
+    violations.extend(session_violations(event));
+    violations.extend(settlement_violations(event));
+    violations.extend(capacity_violations(&event.venue, &event.ticket_types));
+    violations
+}
+
+// scheme://rest with a known scheme, something after it and no whitespace
+fn is_well_formed_url(url: &str) -> bool {
+    match url.split_once("://") {
+        Some((scheme, rest)) => {
+            NFT_URL_SCHEMES.contains(&scheme.to_ascii_lowercase().as_str())
+                && !rest.is_empty()
+                && !rest.starts_with('/')
+                && !url.chars().any(char::is_whitespace)
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use chrono::Duration;
+
+    fn fields(event: &Event) -> Vec<String> {
+        validate_event(event).into_iter().map(|violation| violation.field).collect()
+    }
+
+    fn nft_metadata(image: &str) -> NFTMetadata {
+        NFTMetadata {
+            name: "Harbour Lights VIP".to_string(),
+            description: "VIP pass".to_string(),
+            image: image.to_string(),
+            animation_url: None,
+            attributes: Vec::new(),
+            external_url: None,
+        }
+    }
+
+    #[test]
+    fn the_fixture_event_is_valid() {
+        assert!(validate_event(&fixtures::event()).is_empty());
+    }
+
+    #[test]
+    fn every_violation_is_reported_at_once() {
+        let mut event = fixtures::event();
+        event.title = "  ".to_string();
+        event.ticket_types[0].name = String::new();
+        event.ticket_types[0].price = Decimal::new(-1, 0);
+        event.ticket_types[0].currency = "XYZ".to_string();
+        event.door_time = event.event_date + Duration::hours(1);
+
+        assert_eq!(
+            fields(&event),
+            vec![
+                "title",
+                "ticket_types[0].name",
+                "ticket_types[0].price",
+                "ticket_types[0].currency",
+                "door_time",
+            ]
+        );
+    }
+
+    #[test]
+    fn ticket_type_ids_must_be_unique() {
+        let mut event = fixtures::event();
+        let duplicate = event.ticket_types[0].clone();
+        event.ticket_types.push(duplicate);
+        assert_eq!(fields(&event), vec!["ticket_types[1].id"]);
+    }
+
+    #[test]
+    fn nft_urls_need_a_known_scheme_and_no_whitespace() {
+        let mut event = fixtures::event();
+        let mut metadata = nft_metadata("ipfs://bafy-image");
+        metadata.animation_url = Some("ftp://example.com/clip.mp4".to_string());
+        metadata.external_url = Some("https://example.com/a b".to_string());
+        event.ticket_types[0].nft_metadata = Some(metadata);
+
+        assert_eq!(
+            fields(&event),
+            vec!["ticket_types[0].nft_metadata.animation_url", "ticket_types[0].nft_metadata.external_url"]
+        );
+
+        event.ticket_types[0].nft_metadata = Some(nft_metadata("https:///missing-host"));
+        assert_eq!(fields(&event), vec!["ticket_types[0].nft_metadata.image"]);
+    }
+
+    #[test]
+    fn settlement_tranches_must_add_up_to_the_whole() {
+        let mut event = fixtures::event();
+        event.settlement = SettlementSchedule::Tranches(vec![
+            SettlementTranche { release_at: None, share: Decimal::new(5, 1) },
+            SettlementTranche { release_at: None, share: Decimal::new(3, 1) },
+        ]);
+        assert_eq!(fields(&event), vec!["settlement"]);
+
+        event.settlement = SettlementSchedule::Tranches(vec![
+            SettlementTranche { release_at: None, share: Decimal::new(15, 1) },
+            SettlementTranche { release_at: None, share: Decimal::new(-5, 1) },
+        ]);
+        assert_eq!(fields(&event), vec!["settlement[0].share", "settlement[1].share"]);
+    }
+
+    #[test]
+    fn sessions_and_capacity_feed_into_the_same_report() {
+        let mut event = fixtures::event();
+        event.ticket_types.push(fixtures::ticket_type("GA", Decimal::new(38000, 2), 13_001));
+        event.ticket_types[0].session_access = SessionAccess::Sessions(Vec::new());
+
+        assert_eq!(fields(&event), vec!["ticket_types[0].session_access", "ticket_types"]);
+    }
+
+    #[test]
+    fn violations_display_as_field_and_message() {
+        let violation = Violation::new("title", "must not be empty");
+        assert_eq!(violation.to_string(), "title: must not be empty");
+    }
+}
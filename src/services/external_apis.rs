@@ -1,38 +1,95 @@
 // src/services/external_apis.rs
 use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use crate::models::ticketing::*;
 use crate::error::HKDError;
-use reqwest::{Client, header};
-use std::collections::HashMap;
+use crate::services::platform_http::{PlatformHttp, ResilienceConfig};
+use crate::services::schedule::{parse_timezone, VENUE_TIMEZONES};
+use reqwest::header;
+use std::collections::{HashMap, HashSet};
 use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use chrono::Utc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
 
 #[async_trait]
-pub trait TicketingPlatform {
+pub trait TicketingPlatform: Send + Sync {
+    fn platform(&self) -> EventPlatform;
     async fn create_event(&self, event: &Event) -> Result<ExternalEventResponse, HKDError>;
     async fn update_event(&self, event_id: &str, event: &Event) -> Result<ExternalEventResponse, HKDError>;
     async fn get_event(&self, event_id: &str) -> Result<Event, HKDError>;
-    async fn list_events(&self, filters: EventFilters) -> Result<Vec<Event>, HKDError>;
+    async fn list_events_page(&self, filters: &EventFilters, cursor: Option<String>) -> Result<EventPage, HKDError>;
     async fn create_ticket_types(&self, event_id: &str, ticket_types: &[TicketType]) -> Result<Vec<ExternalTicketTypeResponse>, HKDError>;
+    async fn get_quantity_sold(&self, event_id: &str, ticket_type_id: &str) -> Result<u32, HKDError>;
+    async fn set_quantity_total(&self, event_id: &str, ticket_type_id: &str, quantity_total: u32) -> Result<(), HKDError>;
+
+    /// Every page of `list_events_page`; use `event_stream` to avoid holding them all at once.
+    async fn list_events(&self, filters: EventFilters) -> Result<Vec<Event>, HKDError> {
+        let mut events = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self.list_events_page(&filters, cursor).await?;
+            events.extend(page.events);
+            match page.next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => return Ok(events),
+            }
+        }
+    }
+
+    // Only needed by platforms whose webhooks carry a resource URL instead of the payload
+    async fn get_order(&self, _order_id: &str) -> Result<ExternalOrder, HKDError> {
+        Err(HKDError::ExternalApiError(format!("{:?} does not support order lookup", self.platform())))
+    }
+
+    async fn get_attendee(&self, _event_id: &str, _attendee_id: &str) -> Result<ExternalAttendee, HKDError> {
+        Err(HKDError::ExternalApiError(format!("{:?} does not support attendee lookup", self.platform())))
+    }
+}
+
+/// Lazily walks a platform's events, fetching the next page only once the
+/// current one has been consumed.
+pub fn event_stream<'a>(
+    platform: &'a dyn TicketingPlatform,
+    filters: EventFilters,
+) -> BoxStream<'a, Result<Event, HKDError>> {
+    // `Some(cursor)` while there is a page left to fetch; the first page has no cursor
+    stream::try_unfold((filters, Some(None)), move |(filters, cursor)| async move {
+        let cursor: Option<String> = match cursor {
+            Some(cursor) => cursor,
+            None => return Ok::<_, HKDError>(None),
+        };
+        let page = platform.list_events_page(&filters, cursor).await?;
+        let next = page.next_cursor.map(Some);
+        Ok(Some((stream::iter(page.events.into_iter().map(Ok)), (filters, next))))
+    })
+    .try_flatten()
+    .boxed()
 }
 
 pub struct EventbriteClient {
-    client: Client,
+    http: PlatformHttp,
     base_url: String,
     api_key: String,
+    organization_id: Option<String>,
+    organizer_id: Option<String>,
 }
 
 pub struct TicketmasterClient {
-    client: Client,
+    http: PlatformHttp,
     base_url: String,
     api_key: String,
 }
 
 pub struct CventClient {
-    client: Client,
+    http: PlatformHttp,
     base_url: String,
-    api_key: String,
+    client_id: String,
+    client_secret: String,
     account_id: String,
+    cached_token: Mutex<Option<CventAccessToken>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,125 +107,763 @@ pub struct ExternalTicketTypeResponse {
     pub platform: EventPlatform,
 }
 
+/// An order placed directly on an external platform; one attendee per ticket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalOrder {
+    pub external_order_id: String,
+    pub external_event_id: String,
+    pub attendees: Vec<ExternalAttendee>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum SyncStatus {
-    Synced,
-    Pending,
-    Failed,
+pub struct ExternalAttendee {
+    pub external_attendee_id: String,
+    pub external_event_id: String,
+    pub external_ticket_type_id: String,
+    pub buyer_reference: String, // Email or platform customer id
+    pub status: AttendeeStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AttendeeStatus {
+    Active,
+    CheckedIn,
+    Cancelled,
+    Refunded,
 }
 
+/// One page of a platform's events; `next_cursor` is opaque and platform specific.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventPage {
+    pub events: Vec<Event>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EventFilters {
     pub start_date: Option<chrono::DateTime<Utc>>,
     pub end_date: Option<chrono::DateTime<Utc>>,
     pub event_type: Option<EventType>,
     pub venue_city: Option<String>,
     pub organizer: Option<String>,
+    #[serde(default)]
+    pub status: Option<EventStatus>,
+    // Matches events with at least one ticket type priced within the range
+    #[serde(default)]
+    pub min_price: Option<Decimal>,
+    #[serde(default)]
+    pub max_price: Option<Decimal>,
+    // Case-insensitive match on title or description
+    #[serde(default)]
+    pub keyword: Option<String>,
+    #[serde(default)]
+    pub near: Option<GeoRadius>,
+    #[serde(default)]
+    pub platform: Option<EventPlatform>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeoRadius {
+    pub center: Coordinates,
+    pub radius_km: f64,
+}
+
+impl EventFilters {
+    /// Client-side check for filters a platform's list endpoint can't express.
+    pub fn matches(&self, event: &Event) -> bool {
+        if let Some(start) = self.start_date {
+            if event.event_date < start {
+                return false;
+            }
+        }
+        if let Some(end) = self.end_date {
+            if event.event_date > end {
+                return false;
+            }
+        }
+        if let Some(event_type) = &self.event_type {
+            if event_type != &event.event_type {
+                return false;
+            }
+        }
+        if let Some(city) = &self.venue_city {
+            if !event.venue.city.eq_ignore_ascii_case(city) {
+                return false;
+            }
+        }
+        if let Some(organizer) = &self.organizer {
+            if &event.organizer != organizer {
+                return false;
+            }
+        }
+        if let Some(status) = &self.status {
+            if status != &event.status {
+                return false;
+            }
+        }
+        if self.min_price.is_some() || self.max_price.is_some() {
+            let in_range = event.ticket_types.iter().any(|tt| {
+                self.min_price.is_none_or(|min| tt.price >= min)
+                    && self.max_price.is_none_or(|max| tt.price <= max)
+            });
+            if !in_range {
+                return false;
+            }
+        }
+        if let Some(keyword) = &self.keyword {
+            let keyword = keyword.to_lowercase();
+            if !event.title.to_lowercase().contains(&keyword)
+                && !event.description.to_lowercase().contains(&keyword)
+            {
+                return false;
+            }
+        }
+        if let Some(near) = &self.near {
+            // Events without coordinates can't be placed, so they never match
+            let within = event.venue.coordinates.as_ref()
+                .is_some_and(|coordinates| coordinates.distance_km(&near.center) <= near.radius_km);
+            if !within {
+                return false;
+            }
+        }
+        if let Some(platform) = &self.platform {
+            if &event.platform != platform && !event.publications.contains_key(platform) {
+                return false;
+            }
+        }
+        true
+    }
 }
 
 impl EventbriteClient {
     pub fn new(api_key: String) -> Self {
-        let client = Client::new();
         Self {
-            client,
+            http: PlatformHttp::new(EventPlatform::Eventbrite),
             base_url: "https://www.eventbriteapi.com/v3".to_string(),
             api_key,
+            organization_id: None,
+            organizer_id: None,
         }
     }
 
-    pub async fn create_eventbrite_event(&self, event: &Event) -> Result<ExternalEventResponse, HKDError> {
-        let eventbrite_event = EventbriteEvent::from_event(event);
-        
-        let response = self.client
-            .post(&format!("{}/events/", self.base_url))
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    pub fn with_resilience(mut self, config: ResilienceConfig) -> Self {
+        self.http = PlatformHttp::with_config(EventPlatform::Eventbrite, config);
+        self
+    }
+
+    pub fn with_organization_id(mut self, organization_id: String) -> Self {
+        self.organization_id = Some(organization_id);
+        self
+    }
+
+    pub fn with_organizer_id(mut self, organizer_id: String) -> Self {
+        self.organizer_id = Some(organizer_id);
+        self
+    }
+
+    /// Uses the configured organization, or the first one the API key belongs to.
+    async fn resolve_organization_id(&self) -> Result<String, HKDError> {
+        if let Some(organization_id) = &self.organization_id {
+            return Ok(organization_id.clone());
+        }
+
+        let request = self.http
+            .get(format!("{}/users/me/organizations/", self.base_url))
+            .header("Authorization", format!("Bearer {}", self.api_key));
+        let response = self.http.send(request).await?;
+
+        let organizations: EventbriteOrganizationList = response.json().await?;
+        organizations.organizations
+            .into_iter()
+            .next()
+            .map(|organization| organization.id)
+            .ok_or_else(|| HKDError::ExternalApiError("Eventbrite account has no organizations".to_string()))
+    }
+
+    /// Uses the configured organizer profile, otherwise the one named like our
+    /// `Event::organizer`, falling back to the organization's first organizer.
+    async fn resolve_organizer_id(&self, organization_id: &str, organizer_name: &str) -> Result<String, HKDError> {
+        if let Some(organizer_id) = &self.organizer_id {
+            return Ok(organizer_id.clone());
+        }
+
+        let request = self.http
+            .get(format!("{}/organizations/{}/organizers/", self.base_url, organization_id))
+            .header("Authorization", format!("Bearer {}", self.api_key));
+        let response = self.http.send(request).await?;
+
+        let organizers: EventbriteOrganizerList = response.json().await?;
+        let matching = organizers.organizers.iter()
+            .find(|organizer| organizer.name.as_deref() == Some(organizer_name))
+            .or_else(|| organizers.organizers.first());
+        matching
+            .map(|organizer| organizer.id.clone())
+            .ok_or_else(|| HKDError::ExternalApiError("Eventbrite organization has no organizers".to_string()))
+    }
+
+    /// Reuses an organization venue with the same name and address, so a retried
+    /// publish doesn't leave a duplicate behind for every attempt.
+    pub async fn find_or_create_eventbrite_venue(&self, organization_id: &str, venue: &Venue) -> Result<String, HKDError> {
+        match self.find_eventbrite_venue(organization_id, venue).await? {
+            Some(venue_id) => Ok(venue_id),
+            None => self.create_eventbrite_venue(organization_id, venue).await,
+        }
+    }
+
+    async fn find_eventbrite_venue(&self, organization_id: &str, venue: &Venue) -> Result<Option<String>, HKDError> {
+        let mut continuation: Option<String> = None;
+        loop {
+            let mut request = self.http
+                .get(format!("{}/organizations/{}/venues/", self.base_url, organization_id))
+                .header("Authorization", format!("Bearer {}", self.api_key));
+            if let Some(continuation) = &continuation {
+                request = request.query(&[("continuation", continuation)]);
+            }
+            let response = self.http.send(request).await?;
+
+            let list_response: EventbriteVenueList = response.json().await?;
+            if let Some(existing) = list_response.venues.iter().find(|existing| existing.is_same_place(venue)) {
+                return Ok(Some(existing.id.clone()));
+            }
+            continuation = list_response.pagination
+                .filter(|pagination| pagination.has_more_items)
+                .and_then(|pagination| pagination.continuation);
+            if continuation.is_none() {
+                return Ok(None);
+            }
+        }
+    }
+
+    pub async fn create_eventbrite_venue(&self, organization_id: &str, venue: &Venue) -> Result<String, HKDError> {
+        let eventbrite_venue = EventbriteVenue::from_venue(venue);
+
+        let request = self.http
+            .post(format!("{}/organizations/{}/venues/", self.base_url, organization_id))
             .header("Authorization", format!("Bearer {}", self.api_key))
             .header(header::CONTENT_TYPE, "application/json")
-            .json(&eventbrite_event)
-            .send()
-            .await?;
-
-        if response.status().is_success() {
-            let event_response: EventbriteEventResponse = response.json().await?;
-            Ok(ExternalEventResponse {
-                external_id: event_response.id,
-                event_url: event_response.url,
-                platform: EventPlatform::Eventbrite,
-                sync_status: SyncStatus::Synced,
-            })
-        } else {
-            Err(HKDError::ExternalApiError(format!("Eventbrite API error: {}", response.status())))
-        }
+            .json(&eventbrite_venue);
+        let response = self.http.send(request).await?;
+
+        let venue_response: EventbriteVenueResponse = response.json().await?;
+        Ok(venue_response.id)
     }
 
     pub async fn create_eventbrite_ticket_class(&self, event_id: &str, ticket_type: &TicketType) -> Result<ExternalTicketTypeResponse, HKDError> {
         let ticket_class = EventbriteTicketClass::from_ticket_type(ticket_type);
         
-        let response = self.client
-            .post(&format!("{}/events/{}/ticket_classes/", self.base_url, event_id))
+        let request = self.http
+            .post(format!("{}/events/{}/ticket_classes/", self.base_url, event_id))
             .header("Authorization", format!("Bearer {}", self.api_key))
-            .json(&ticket_class)
-            .send()
-            .await?;
-
-        if response.status().is_success() {
-            let ticket_response: EventbriteTicketClassResponse = response.json().await?;
-            Ok(ExternalTicketTypeResponse {
-                external_id: ticket_response.id,
-                ticket_type_id: ticket_type.id,
-                platform: EventPlatform::Eventbrite,
-            })
-        } else {
-            Err(HKDError::ExternalApiError(format!("Eventbrite API error: {}", response.status())))
+            .json(&ticket_class);
+        let response = self.http.send(request).await?;
+
+        let ticket_response: EventbriteTicketClassResponse = response.json().await?;
+        Ok(ExternalTicketTypeResponse {
+            external_id: ticket_response.id,
+            ticket_type_id: ticket_type.id,
+            platform: EventPlatform::Eventbrite,
+        })
+    }
+}
+
+#[async_trait]
+impl TicketingPlatform for EventbriteClient {
+    fn platform(&self) -> EventPlatform {
+        EventPlatform::Eventbrite
+    }
+
+    async fn create_event(&self, event: &Event) -> Result<ExternalEventResponse, HKDError> {
+        let organization_id = self.resolve_organization_id().await?;
+        let organizer_id = self.resolve_organizer_id(&organization_id, &event.organizer).await?;
+        let venue_id = self.find_or_create_eventbrite_venue(&organization_id, &event.venue).await?;
+        let eventbrite_event = EventbriteEvent::from_event(event, &organizer_id, Some(venue_id));
+        
+        let request = self.http
+            .post(format!("{}/organizations/{}/events/", self.base_url, organization_id))
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&eventbrite_event);
+        let response = self.http.send(request).await?;
+
+        let event_response: EventbriteEventResponse = response.json().await?;
+        Ok(ExternalEventResponse {
+            external_id: event_response.id,
+            event_url: event_response.url,
+            platform: EventPlatform::Eventbrite,
+            sync_status: SyncStatus::Synced,
+        })
+    }
+
+    async fn update_event(&self, event_id: &str, event: &Event) -> Result<ExternalEventResponse, HKDError> {
+        let organization_id = self.resolve_organization_id().await?;
+        let organizer_id = self.resolve_organizer_id(&organization_id, &event.organizer).await?;
+        // Venue is left untouched; Eventbrite venues are immutable once attached to a live event
+        let eventbrite_event = EventbriteEvent::from_event(event, &organizer_id, None);
+
+        let request = self.http
+            .post(format!("{}/events/{}/", self.base_url, event_id))
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&eventbrite_event);
+        let response = self.http.send(request).await?;
+
+        let event_response: EventbriteEventResponse = response.json().await?;
+        Ok(ExternalEventResponse {
+            external_id: event_response.id,
+            event_url: event_response.url,
+            platform: EventPlatform::Eventbrite,
+            sync_status: SyncStatus::Synced,
+        })
+    }
+
+    async fn get_event(&self, event_id: &str) -> Result<Event, HKDError> {
+        let request = self.http
+            .get(format!("{}/events/{}/", self.base_url, event_id))
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .query(&[("expand", EVENTBRITE_EVENT_EXPANSIONS)]);
+        let response = self.http.send(request).await?;
+
+        let event_response: EventbriteEventResponse = response.json().await?;
+        Ok(event_response.into_event())
+    }
+
+    async fn list_events_page(&self, filters: &EventFilters, cursor: Option<String>) -> Result<EventPage, HKDError> {
+        let organization_id = self.resolve_organization_id().await?;
+
+        let request = self.http
+            .get(format!("{}/organizations/{}/events/", self.base_url, organization_id))
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .query(&[("expand", EVENTBRITE_EVENT_EXPANSIONS)])
+            .query(&EventbriteEventQuery::from_filters(filters, cursor));
+        let response = self.http.send(request).await?;
+
+        let list_response: EventbriteEventListResponse = response.json().await?;
+        let next_cursor = list_response.pagination
+            .filter(|pagination| pagination.has_more_items)
+            .and_then(|pagination| pagination.continuation);
+        Ok(EventPage {
+            events: list_response.events
+                .into_iter()
+                .map(EventbriteEventResponse::into_event)
+                .filter(|event| filters.matches(event))
+                .collect(),
+            next_cursor,
+        })
+    }
+
+    async fn create_ticket_types(&self, event_id: &str, ticket_types: &[TicketType]) -> Result<Vec<ExternalTicketTypeResponse>, HKDError> {
+        let mut responses = Vec::with_capacity(ticket_types.len());
+        for ticket_type in ticket_types {
+            responses.push(self.create_eventbrite_ticket_class(event_id, ticket_type).await?);
         }
+        Ok(responses)
+    }
+
+    async fn get_quantity_sold(&self, event_id: &str, ticket_type_id: &str) -> Result<u32, HKDError> {
+        let request = self.http
+            .get(format!("{}/events/{}/ticket_classes/{}/", self.base_url, event_id, ticket_type_id))
+            .header("Authorization", format!("Bearer {}", self.api_key));
+        let response = self.http.send(request).await?;
+
+        let ticket_response: EventbriteTicketClassResponse = response.json().await?;
+        Ok(ticket_response.quantity_sold.unwrap_or(0))
+    }
+
+    async fn set_quantity_total(&self, event_id: &str, ticket_type_id: &str, quantity_total: u32) -> Result<(), HKDError> {
+        let update = EventbriteTicketClassQuantity {
+            ticket_class: EventbriteTicketClassQuantityData { quantity_total },
+        };
+
+        let request = self.http
+            .post(format!("{}/events/{}/ticket_classes/{}/", self.base_url, event_id, ticket_type_id))
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .json(&update);
+        self.http.send(request).await?;
+        Ok(())
+    }
+
+    async fn get_order(&self, order_id: &str) -> Result<ExternalOrder, HKDError> {
+        let request = self.http
+            .get(format!("{}/orders/{}/", self.base_url, order_id))
+            .header("Authorization", format!("Bearer {}", self.api_key))
+            .query(&[("expand", "attendees")]);
+        let response = self.http.send(request).await?;
+
+        let order_response: EventbriteOrderResponse = response.json().await?;
+        Ok(ExternalOrder {
+            external_order_id: order_response.id,
+            external_event_id: order_response.event_id,
+            attendees: order_response.attendees
+                .unwrap_or_default()
+                .into_iter()
+                .map(EventbriteAttendeeResponse::into_attendee)
+                .collect(),
+        })
+    }
+
+    async fn get_attendee(&self, event_id: &str, attendee_id: &str) -> Result<ExternalAttendee, HKDError> {
+        let request = self.http
+            .get(format!("{}/events/{}/attendees/{}/", self.base_url, event_id, attendee_id))
+            .header("Authorization", format!("Bearer {}", self.api_key));
+        let response = self.http.send(request).await?;
+
+        let attendee_response: EventbriteAttendeeResponse = response.json().await?;
+        Ok(attendee_response.into_attendee())
     }
 }
 
 impl TicketmasterClient {
     pub fn new(api_key: String) -> Self {
-        let client = Client::new();
         Self {
-            client,
-            base_url: "https://app.ticketmaster.com/commerce/v2".to_string(),
+            http: PlatformHttp::new(EventPlatform::Ticketmaster),
+            base_url: "https://app.ticketmaster.com".to_string(),
             api_key,
         }
     }
 
-    pub async fn create_ticketmaster_event(&self, event: &Event) -> Result<ExternalEventResponse, HKDError> {
-        // Ticketmaster integration for event creation
-        // Implementation would follow Ticketmaster's API spec
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    pub fn with_resilience(mut self, config: ResilienceConfig) -> Self {
+        self.http = PlatformHttp::with_config(EventPlatform::Ticketmaster, config);
+        self
+    }
+
+    pub async fn create_ticketmaster_offer(&self, event_id: &str, ticket_type: &TicketType) -> Result<ExternalTicketTypeResponse, HKDError> {
+        let offer = TicketmasterOffer::from_ticket_type(ticket_type);
+
+        let request = self.http
+            .post(format!("{}/publish/v2/events/{}/offers", self.base_url, event_id))
+            .query(&[("apikey", &self.api_key)])
+            .json(&offer);
+        let response = self.http.send(request).await?;
+
+        let offer_response: TicketmasterIdResponse = response.json().await?;
+        Ok(ExternalTicketTypeResponse {
+            external_id: offer_response.id,
+            ticket_type_id: ticket_type.id,
+            platform: EventPlatform::Ticketmaster,
+        })
+    }
+}
+
+#[async_trait]
+impl TicketingPlatform for TicketmasterClient {
+    fn platform(&self) -> EventPlatform {
+        EventPlatform::Ticketmaster
+    }
+
+    async fn create_event(&self, event: &Event) -> Result<ExternalEventResponse, HKDError> {
+        let ticketmaster_event = TicketmasterPublishEvent::from_event(event);
+
+        let request = self.http
+            .post(format!("{}/publish/v2/events", self.base_url))
+            .query(&[("apikey", &self.api_key)])
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&ticketmaster_event);
+        let response = self.http.send(request).await?;
+
+        let event_response: TicketmasterEventResponse = response.json().await?;
+        Ok(ExternalEventResponse {
+            external_id: event_response.id,
+            event_url: event_response.url.unwrap_or_default(),
+            platform: EventPlatform::Ticketmaster,
+            sync_status: SyncStatus::Synced,
+        })
+    }
+
+    async fn update_event(&self, event_id: &str, event: &Event) -> Result<ExternalEventResponse, HKDError> {
+        let ticketmaster_event = TicketmasterPublishEvent::from_event(event);
+
+        let request = self.http
+            .put(format!("{}/publish/v2/events/{}", self.base_url, event_id))
+            .query(&[("apikey", &self.api_key)])
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&ticketmaster_event);
+        let response = self.http.send(request).await?;
+
+        let event_response: TicketmasterEventResponse = response.json().await?;
         Ok(ExternalEventResponse {
-            external_id: "tm_".to_string() + &event.id.to_string(),
-            event_url: format!("https://ticketmaster.com/event/{}", event.id),
+            external_id: event_response.id,
+            event_url: event_response.url.unwrap_or_default(),
             platform: EventPlatform::Ticketmaster,
             sync_status: SyncStatus::Synced,
         })
     }
+
+    async fn get_event(&self, event_id: &str) -> Result<Event, HKDError> {
+        let request = self.http
+            .get(format!("{}/discovery/v2/events/{}.json", self.base_url, event_id))
+            .query(&[("apikey", &self.api_key)]);
+        let response = self.http.send(request).await?;
+
+        let event_response: TicketmasterEventResponse = response.json().await?;
+        Ok(event_response.into_event())
+    }
+
+    async fn list_events_page(&self, filters: &EventFilters, cursor: Option<String>) -> Result<EventPage, HKDError> {
+        let page = match cursor {
+            Some(cursor) => cursor.parse::<u32>()
+                .map_err(|_| HKDError::ExternalApiError(format!("Invalid Ticketmaster page cursor: {}", cursor)))?,
+            None => 0,
+        };
+
+        let request = self.http
+            .get(format!("{}/discovery/v2/events.json", self.base_url))
+            .query(&[("apikey", &self.api_key)])
+            .query(&TicketmasterSearchQuery::from_filters(filters, page));
+        let response = self.http.send(request).await?;
+
+        let list_response: TicketmasterEventListResponse = response.json().await?;
+        let next_cursor = list_response.page
+            .as_ref()
+            .and_then(TicketmasterPage::next_page)
+            .map(|page| page.to_string());
+        Ok(EventPage {
+            events: list_response.embedded
+                .map(|embedded| embedded.events)
+                .unwrap_or_default()
+                .into_iter()
+                .map(TicketmasterEventResponse::into_event)
+                .filter(|event| filters.matches(event))
+                .collect(),
+            next_cursor,
+        })
+    }
+
+    async fn create_ticket_types(&self, event_id: &str, ticket_types: &[TicketType]) -> Result<Vec<ExternalTicketTypeResponse>, HKDError> {
+        let mut responses = Vec::with_capacity(ticket_types.len());
+        for ticket_type in ticket_types {
+            responses.push(self.create_ticketmaster_offer(event_id, ticket_type).await?);
+        }
+        Ok(responses)
+    }
+
+    async fn get_quantity_sold(&self, event_id: &str, ticket_type_id: &str) -> Result<u32, HKDError> {
+        let request = self.http
+            .get(format!("{}/publish/v2/events/{}/offers/{}", self.base_url, event_id, ticket_type_id))
+            .query(&[("apikey", &self.api_key)]);
+        let response = self.http.send(request).await?;
+
+        let offer_response: TicketmasterOfferResponse = response.json().await?;
+        Ok(offer_response.quantity_sold)
+    }
+
+    async fn set_quantity_total(&self, event_id: &str, ticket_type_id: &str, quantity_total: u32) -> Result<(), HKDError> {
+        let request = self.http
+            .patch(format!("{}/publish/v2/events/{}/offers/{}", self.base_url, event_id, ticket_type_id))
+            .query(&[("apikey", &self.api_key)])
+            .json(&TicketmasterOfferQuantity { quantity: quantity_total });
+        self.http.send(request).await?;
+        Ok(())
+    }
 }
 
 impl CventClient {
-    pub fn new(api_key: String, account_id: String) -> Self {
-        let client = Client::new();
+    pub fn new(client_id: String, client_secret: String, account_id: String) -> Self {
         Self {
-            client,
-            base_url: "https://api.cvent.com/ea".to_string(),
-            api_key,
+            http: PlatformHttp::new(EventPlatform::Cvent),
+            base_url: "https://api-platform.cvent.com/ea".to_string(),
+            client_id,
+            client_secret,
             account_id,
+            cached_token: Mutex::new(None),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = base_url;
+        self
+    }
+
+    pub fn with_resilience(mut self, config: ResilienceConfig) -> Self {
+        self.http = PlatformHttp::with_config(EventPlatform::Cvent, config);
+        self
+    }
+
+    /// Returns a cached OAuth token, requesting a new one via the client
+    /// credentials grant once the current one is about to expire.
+    async fn access_token(&self) -> Result<String, HKDError> {
+        let mut cached = self.cached_token.lock().await;
+        if let Some(token) = cached.as_ref() {
+            if token.expires_at > Utc::now() + chrono::Duration::seconds(CVENT_TOKEN_REFRESH_MARGIN_SECS) {
+                return Ok(token.token.clone());
+            }
         }
+
+        let request = self.http
+            .post(format!("{}/oauth2/token", self.base_url))
+            .basic_auth(&self.client_id, Some(&self.client_secret))
+            .form(&[("grant_type", "client_credentials"), ("client_id", self.client_id.as_str())]);
+        let response = self.http.send(request).await?;
+
+        let token_response: CventTokenResponse = response.json().await?;
+        let token = token_response.access_token.clone();
+        *cached = Some(CventAccessToken {
+            token: token_response.access_token,
+            expires_at: Utc::now() + chrono::Duration::seconds(token_response.expires_in),
+        });
+        Ok(token)
+    }
+
+    fn account_url(&self, path: &str) -> String {
+        format!("{}/accounts/{}/{}", self.base_url, self.account_id, path)
+    }
+
+    pub async fn create_cvent_admission_item(&self, event_id: &str, ticket_type: &TicketType) -> Result<ExternalTicketTypeResponse, HKDError> {
+        let token = self.access_token().await?;
+        let admission_item = CventAdmissionItem::from_ticket_type(ticket_type);
+
+        let request = self.http
+            .post(self.account_url(&format!("events/{}/admission-items", event_id)))
+            .bearer_auth(token)
+            .json(&admission_item);
+        let response = self.http.send(request).await?;
+
+        let item_response: CventIdResponse = response.json().await?;
+        Ok(ExternalTicketTypeResponse {
+            external_id: item_response.id,
+            ticket_type_id: ticket_type.id,
+            platform: EventPlatform::Cvent,
+        })
     }
+}
+
+#[async_trait]
+impl TicketingPlatform for CventClient {
+    fn platform(&self) -> EventPlatform {
+        EventPlatform::Cvent
+    }
+
+    async fn create_event(&self, event: &Event) -> Result<ExternalEventResponse, HKDError> {
+        let token = self.access_token().await?;
+        let cvent_event = CventEvent::from_event(event);
+
+        let request = self.http
+            .post(self.account_url("events"))
+            .bearer_auth(token)
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&cvent_event);
+        let response = self.http.send(request).await?;
+
+        let event_response: CventEventResponse = response.json().await?;
+        Ok(ExternalEventResponse {
+            event_url: event_response.event_url(),
+            external_id: event_response.id,
+            platform: EventPlatform::Cvent,
+            sync_status: SyncStatus::Synced,
+        })
+    }
+
+    async fn update_event(&self, event_id: &str, event: &Event) -> Result<ExternalEventResponse, HKDError> {
+        let token = self.access_token().await?;
+        let cvent_event = CventEvent::from_event(event);
+
+        let request = self.http
+            .put(self.account_url(&format!("events/{}", event_id)))
+            .bearer_auth(token)
+            .header(header::CONTENT_TYPE, "application/json")
+            .json(&cvent_event);
+        let response = self.http.send(request).await?;
 
-    pub async fn create_cvent_event(&self, event: &Event) -> Result<ExternalEventResponse, HKDError> {
-        // Cvent integration for event creation
-        // Implementation would follow Cvent's API spec
+        let event_response: CventEventResponse = response.json().await?;
         Ok(ExternalEventResponse {
-            external_id: "cv_".to_string() + &event.id.to_string(),
-            event_url: format!("https://cvent.com/event/{}", event.id),
+            event_url: event_response.event_url(),
+            external_id: event_response.id,
             platform: EventPlatform::Cvent,
             sync_status: SyncStatus::Synced,
         })
     }
+
+    async fn get_event(&self, event_id: &str) -> Result<Event, HKDError> {
+        let token = self.access_token().await?;
+
+        let request = self.http
+            .get(self.account_url(&format!("events/{}", event_id)))
+            .bearer_auth(token);
+        let response = self.http.send(request).await?;
+
+        let event_response: CventEventResponse = response.json().await?;
+        Ok(event_response.into_event())
+    }
+
+    async fn list_events_page(&self, filters: &EventFilters, cursor: Option<String>) -> Result<EventPage, HKDError> {
+        let token = self.access_token().await?;
+
+        let request = self.http
+            .get(self.account_url("events"))
+            .bearer_auth(token)
+            .query(&CventEventQuery::from_filters(filters, cursor));
+        let response = self.http.send(request).await?;
+
+        let list_response: CventEventListResponse = response.json().await?;
+        Ok(EventPage {
+            events: list_response.data
+                .into_iter()
+                .map(CventEventResponse::into_event)
+                .filter(|event| filters.matches(event))
+                .collect(),
+            next_cursor: list_response.paging.and_then(|paging| paging.next_token),
+        })
+    }
+
+    async fn create_ticket_types(&self, event_id: &str, ticket_types: &[TicketType]) -> Result<Vec<ExternalTicketTypeResponse>, HKDError> {
+        let mut responses = Vec::with_capacity(ticket_types.len());
+        for ticket_type in ticket_types {
+            responses.push(self.create_cvent_admission_item(event_id, ticket_type).await?);
+        }
+        Ok(responses)
+    }
+
+    async fn get_quantity_sold(&self, event_id: &str, ticket_type_id: &str) -> Result<u32, HKDError> {
+        let token = self.access_token().await?;
+
+        let request = self.http
+            .get(self.account_url(&format!("events/{}/admission-items/{}", event_id, ticket_type_id)))
+            .bearer_auth(token);
+        let response = self.http.send(request).await?;
+
+        let item_response: CventAdmissionItemResponse = response.json().await?;
+        Ok(item_response.registered.unwrap_or(0))
+    }
+
+    async fn set_quantity_total(&self, event_id: &str, ticket_type_id: &str, quantity_total: u32) -> Result<(), HKDError> {
+        let token = self.access_token().await?;
+
+        let request = self.http
+            .patch(self.account_url(&format!("events/{}/admission-items/{}", event_id, ticket_type_id)))
+            .bearer_auth(token)
+            .json(&CventAdmissionItemCapacity { capacity: quantity_total });
+        self.http.send(request).await?;
+        Ok(())
+    }
 }
 
 // Eventbrite-specific data structures
+const EVENTBRITE_EVENT_EXPANSIONS: &str = "venue,ticket_classes";
+
+const EVENTBRITE_MAX_QUANTITY_PER_ORDER: u32 = 10;
+
+const EVENTBRITE_PAGE_SIZE: u32 = 200;
+
+// Eventbrite format ids (GET /formats/) and the performing arts category
+const EVENTBRITE_FORMAT_CONFERENCE: &str = "1";
+const EVENTBRITE_FORMAT_EXPO: &str = "3";
+const EVENTBRITE_FORMAT_FESTIVAL: &str = "5";
+const EVENTBRITE_FORMAT_PERFORMANCE: &str = "6";
+const EVENTBRITE_FORMAT_WORKSHOP: &str = "9";
+const EVENTBRITE_FORMAT_TOURNAMENT: &str = "13";
+const EVENTBRITE_CATEGORY_MUSIC: &str = "103";
+const EVENTBRITE_CATEGORY_PERFORMING_ARTS: &str = "105";
+const EVENTBRITE_CATEGORY_SPORTS: &str = "108";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct EventbriteEvent {
     event: EventbriteEventData,
@@ -183,15 +878,71 @@ struct EventbriteEventData {
     currency: String,
     online_event: bool,
     organizer_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     venue_id: Option<String>,
+    capacity: u32,
+    format_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    category_id: Option<String>,
     // ... other Eventbrite specific fields
 }
 
+impl EventbriteEvent {
+    fn from_event(event: &Event, organizer_id: &str, venue_id: Option<String>) -> Self {
+        let timezone = event.timezone().name().to_string();
+        let (format_id, category_id) = eventbrite_format(&event.event_type);
+        let currency = event.ticket_types.first()
+            .map(|ticket_type| ticket_type.currency.clone())
+            .unwrap_or_else(|| "HKD".to_string());
+
+        Self {
+            event: EventbriteEventData {
+                name: EventbriteText::from_plain(&event.title),
+                description: EventbriteText {
+                    text: None,
+                    html: description_to_html(&event.description),
+                },
+                start: EventbriteDateTime {
+                    timezone: timezone.clone(),
+                    utc: event.event_date,
+                },
+                end: EventbriteDateTime {
+                    timezone,
+                    utc: event.end_time(),
+                },
+                currency,
+                online_event: false,
+                organizer_id: organizer_id.to_string(),
+                venue_id,
+                capacity: event.venue.capacity,
+                format_id: format_id.to_string(),
+                category_id: category_id.map(str::to_string),
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct EventbriteText {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
     html: String,
 }
 
+impl EventbriteText {
+    fn from_plain(text: &str) -> Self {
+        Self {
+            text: None,
+            html: escape_html(text),
+        }
+    }
+
+    /// Eventbrite returns both renderings; fall back to the HTML when `text` is absent.
+    fn into_plain(self) -> String {
+        self.text.unwrap_or_else(|| html_to_text(&self.html))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct EventbriteDateTime {
     timezone: String,
@@ -202,27 +953,1411 @@ struct EventbriteDateTime {
 struct EventbriteEventResponse {
     id: String,
     url: String,
+    name: EventbriteText,
+    description: Option<EventbriteText>,
+    start: EventbriteDateTime,
+    end: EventbriteDateTime,
+    status: String,
+    organizer_id: Option<String>,
+    capacity: Option<u32>,
+    format_id: Option<String>,
+    category_id: Option<String>,
+    venue: Option<EventbriteVenueResponse>,
+    ticket_classes: Option<Vec<EventbriteTicketClassResponse>>,
     // ... other response fields
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct EventbriteTicketClass {
-    ticket_class: EventbriteTicketClassData,
+struct EventbriteEventListResponse {
+    events: Vec<EventbriteEventResponse>,
+    pagination: Option<EventbritePagination>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct EventbriteTicketClassData {
-    name: String,
-    free: bool,
-    minimum_quantity: u32,
-    maximum_quantity: u32,
-    delivery_methods: Vec<String>,
-    cost: String,
-    // ... other ticket class fields
+struct EventbritePagination {
+    continuation: Option<String>,
+    #[serde(default)]
+    has_more_items: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct EventbriteTicketClassResponse {
-    id: String,
-    // ... other response fields
+#[derive(Debug, Clone, Serialize)]
+struct EventbriteEventQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    continuation: Option<String>,
+    page_size: u32,
+}
+
+impl EventbriteEventQuery {
+    fn from_filters(filters: &EventFilters, continuation: Option<String>) -> Self {
+        Self {
+            status: filters.status.as_ref().map(eventbrite_status_filter),
+            continuation,
+            page_size: EVENTBRITE_PAGE_SIZE,
+        }
+    }
+}
+
+impl EventbriteEventResponse {
+    fn into_event(self) -> Event {
+        let now = Utc::now();
+        let capacity = self.capacity;
+        let venue = match self.venue {
+            Some(venue) => venue.into_venue(capacity),
+            None => empty_venue(capacity.unwrap_or(0)),
+        };
+        let event_date = self.start.utc;
+        let ticket_types = self.ticket_classes
+            .unwrap_or_default()
+            .into_iter()
+            .map(|ticket_class| ticket_class.into_ticket_type(event_date))
+            .collect();
+
+        Event {
+            id: Uuid::new_v4(),
+            title: self.name.into_plain(),
+            description: self.description.map(EventbriteText::into_plain).unwrap_or_default(),
+            organizer: self.organizer_id.unwrap_or_default(),
+            venue,
+            event_date: self.start.utc,
+            // Eventbrite has no separate door time
+            door_time: self.start.utc,
+            end_date: Some(self.end.utc),
+            timezone: parse_timezone(&self.start.timezone),
+            local_times: None,
+            event_type: event_type_from_eventbrite(self.format_id.as_deref(), self.category_id.as_deref()),
+            ticket_types,
+            publications: HashMap::from([(
+                EventPlatform::Eventbrite,
+                PlatformSync::synced(EventPlatform::Eventbrite, self.id.clone(), self.url),
+            )]),
+            external_event_id: Some(self.id),
+            platform: EventPlatform::Eventbrite,
+            status: eventbrite_status(&self.status),
+            created_at: now,
+            updated_at: now,
+            sold_seats: HashSet::new(),
+            sessions: Vec::new(),
+            recurrence: None,
+            version: 0,
+            settlement: SettlementSchedule::default(),
+        }
+    }
+}
+
+fn eventbrite_status(status: &str) -> EventStatus {
+    match status {
+        "live" | "started" => EventStatus::OnSale,
+        "ended" | "completed" => EventStatus::Completed,
+        "canceled" => EventStatus::Cancelled,
+        _ => EventStatus::Draft,
+    }
+}
+
+// Eventbrite has no sold-out status, so those events are listed as live
+fn eventbrite_status_filter(status: &EventStatus) -> &'static str {
+    match status {
+        EventStatus::Draft => "draft",
+        EventStatus::Published | EventStatus::OnSale | EventStatus::SoldOut => "live",
+        EventStatus::Completed => "ended",
+        EventStatus::Cancelled => "canceled",
+    }
+}
+
+fn eventbrite_format(event_type: &EventType) -> (&'static str, Option<&'static str>) {
+    match event_type {
+        EventType::Concert => (EVENTBRITE_FORMAT_PERFORMANCE, Some(EVENTBRITE_CATEGORY_MUSIC)),
+        EventType::Theater => (EVENTBRITE_FORMAT_PERFORMANCE, Some(EVENTBRITE_CATEGORY_PERFORMING_ARTS)),
+        EventType::Conference => (EVENTBRITE_FORMAT_CONFERENCE, None),
+        EventType::Sports => (EVENTBRITE_FORMAT_TOURNAMENT, Some(EVENTBRITE_CATEGORY_SPORTS)),
+        EventType::Festival => (EVENTBRITE_FORMAT_FESTIVAL, None),
+        EventType::Workshop => (EVENTBRITE_FORMAT_WORKSHOP, None),
+        EventType::Exhibition => (EVENTBRITE_FORMAT_EXPO, None),
+    }
+}
+
+fn event_type_from_eventbrite(format_id: Option<&str>, category_id: Option<&str>) -> EventType {
+    match (format_id, category_id) {
+        (Some(EVENTBRITE_FORMAT_PERFORMANCE), Some(EVENTBRITE_CATEGORY_PERFORMING_ARTS)) => EventType::Theater,
+        (Some(EVENTBRITE_FORMAT_PERFORMANCE), _) => EventType::Concert,
+        (Some(EVENTBRITE_FORMAT_TOURNAMENT), _) | (_, Some(EVENTBRITE_CATEGORY_SPORTS)) => EventType::Sports,
+        (Some(EVENTBRITE_FORMAT_FESTIVAL), _) => EventType::Festival,
+        (Some(EVENTBRITE_FORMAT_WORKSHOP), _) => EventType::Workshop,
+        (Some(EVENTBRITE_FORMAT_EXPO), _) => EventType::Exhibition,
+        (_, Some(EVENTBRITE_CATEGORY_MUSIC)) => EventType::Concert,
+        _ => EventType::Conference,
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteVenue {
+    venue: EventbriteVenueData,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteVenueData {
+    name: String,
+    capacity: u32,
+    address: EventbriteAddress,
+}
+
+impl EventbriteVenue {
+    fn from_venue(venue: &Venue) -> Self {
+        Self {
+            venue: EventbriteVenueData {
+                name: venue.name.clone(),
+                capacity: venue.capacity,
+                address: EventbriteAddress {
+                    address_1: Some(venue.address.clone()),
+                    city: Some(venue.city.clone()),
+                    country: Some(country_code(&venue.country).to_string()),
+                    // Eventbrite encodes coordinates as strings
+                    latitude: venue.coordinates.as_ref().map(|c| c.latitude.to_string()),
+                    longitude: venue.coordinates.as_ref().map(|c| c.longitude.to_string()),
+                },
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteAddress {
+    address_1: Option<String>,
+    city: Option<String>,
+    country: Option<String>,
+    latitude: Option<String>,
+    longitude: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteVenueResponse {
+    id: String,
+    name: Option<String>,
+    capacity: Option<u32>,
+    address: Option<EventbriteAddress>,
+    // ... other response fields
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteVenueList {
+    #[serde(default)]
+    venues: Vec<EventbriteVenueResponse>,
+    pagination: Option<EventbritePagination>,
+}
+
+impl EventbriteVenueResponse {
+    fn is_same_place(&self, venue: &Venue) -> bool {
+        let address = self.address.as_ref();
+        let same = |value: Option<&str>, expected: &str| value.is_some_and(|value| value.eq_ignore_ascii_case(expected));
+        self.name.as_deref() == Some(venue.name.as_str())
+            && same(address.and_then(|address| address.address_1.as_deref()), &venue.address)
+            && same(address.and_then(|address| address.city.as_deref()), &venue.city)
+    }
+
+    fn into_venue(self, event_capacity: Option<u32>) -> Venue {
+        let address = self.address.unwrap_or(EventbriteAddress {
+            address_1: None,
+            city: None,
+            country: None,
+            latitude: None,
+            longitude: None,
+        });
+        let coordinates = parse_coordinates(address.latitude.as_deref(), address.longitude.as_deref());
+
+        Venue {
+            id: None,
+            name: self.name.unwrap_or_default(),
+            address: address.address_1.unwrap_or_default(),
+            city: address.city.unwrap_or_default(),
+            country: address.country.unwrap_or_default(),
+            capacity: self.capacity.or(event_capacity).unwrap_or(0),
+            coordinates,
+            timezone: None,
+            seat_map: None,
+            areas: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteOrganizationList {
+    organizations: Vec<EventbriteOrganization>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteOrganization {
+    id: String,
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteOrganizerList {
+    organizers: Vec<EventbriteOrganizer>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteOrganizer {
+    id: String,
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteTicketClass {
+    ticket_class: EventbriteTicketClassData,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteTicketClassData {
+    name: String,
+    free: bool,
+    minimum_quantity: u32,
+    maximum_quantity: u32,
+    delivery_methods: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cost: Option<String>,
+    quantity_total: u32,
+    sales_start: chrono::DateTime<Utc>,
+    sales_end: chrono::DateTime<Utc>,
+    // ... other ticket class fields
+}
+
+impl EventbriteTicketClass {
+    fn from_ticket_type(ticket_type: &TicketType) -> Self {
+        let free = ticket_type.price.is_zero();
+        Self {
+            ticket_class: EventbriteTicketClassData {
+                name: ticket_type.name.clone(),
+                free,
+                minimum_quantity: 1,
+                maximum_quantity: ticket_type.quantity_available.clamp(1, EVENTBRITE_MAX_QUANTITY_PER_ORDER),
+                delivery_methods: vec!["electronic".to_string()],
+                // Free ticket classes must not carry a cost
+                cost: if free { None } else { Some(format_cost(ticket_type.price, &ticket_type.currency)) },
+                quantity_total: ticket_type.quantity_available,
+                sales_start: ticket_type.sales_start,
+                sales_end: ticket_type.sales_end,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteTicketClassQuantity {
+    ticket_class: EventbriteTicketClassQuantityData,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteTicketClassQuantityData {
+    quantity_total: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteTicketClassResponse {
+    id: String,
+    name: Option<String>,
+    free: Option<bool>,
+    cost: Option<EventbriteCost>,
+    quantity_total: Option<u32>,
+    quantity_sold: Option<u32>,
+    sales_start: Option<chrono::DateTime<Utc>>,
+    sales_end: Option<chrono::DateTime<Utc>>,
+    // ... other response fields
+}
+
+impl EventbriteTicketClassResponse {
+    fn into_ticket_type(self, event_date: chrono::DateTime<Utc>) -> TicketType {
+        let (sales_start, sales_end) = sales_window(self.sales_start, self.sales_end, event_date);
+        let (price, currency) = match self.cost {
+            Some(cost) => (parse_minor_units(cost.value, &cost.currency), cost.currency),
+            None => (Decimal::ZERO, "HKD".to_string()),
+        };
+
+        let quantity_sold = self.quantity_sold.unwrap_or(0);
+
+        TicketType {
+            id: Uuid::new_v4(),
+            name: self.name.unwrap_or_default(),
+            price,
+            currency,
+            quantity_available: self.quantity_total.unwrap_or(0),
+            quantity_sold,
+            perks: Vec::new(),
+            nft_metadata: None,
+            sales_start,
+            sales_end,
+            transfer_policy: TransferPolicy::default(),
+            inventory: InventoryAllocation::default(),
+            channel_sales: HashMap::from([(EventPlatform::Eventbrite, quantity_sold)]),
+            external_ids: HashMap::from([(EventPlatform::Eventbrite, self.id)]),
+            price_zone: None,
+            area: None,
+            session_access: SessionAccess::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteOrderResponse {
+    id: String,
+    event_id: String,
+    attendees: Option<Vec<EventbriteAttendeeResponse>>,
+    // ... other response fields
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteAttendeeResponse {
+    id: String,
+    event_id: String,
+    ticket_class_id: String,
+    profile: Option<EventbriteProfile>,
+    checked_in: bool,
+    cancelled: bool,
+    refunded: bool,
+    // ... other response fields
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteProfile {
+    email: Option<String>,
+}
+
+impl EventbriteAttendeeResponse {
+    fn into_attendee(self) -> ExternalAttendee {
+        let status = if self.refunded {
+            AttendeeStatus::Refunded
+        } else if self.cancelled {
+            AttendeeStatus::Cancelled
+        } else if self.checked_in {
+            AttendeeStatus::CheckedIn
+        } else {
+            AttendeeStatus::Active
+        };
+
+        ExternalAttendee {
+            buyer_reference: self.profile
+                .and_then(|profile| profile.email)
+                .unwrap_or_else(|| self.id.clone()),
+            external_attendee_id: self.id,
+            external_event_id: self.event_id,
+            external_ticket_type_id: self.ticket_class_id,
+            status,
+        }
+    }
+}
+
+/// Eventbrite's response shape for money: `value` is in the currency's minor unit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EventbriteCost {
+    currency: String,
+    value: i64,
+    display: Option<String>,
+}
+
+// Ticketmaster-specific data structures
+
+// Discovery API segment ids
+const TICKETMASTER_SEGMENT_MUSIC: &str = "KZFzniwnSyZfZ7v7nJ";
+const TICKETMASTER_SEGMENT_SPORTS: &str = "KZFzniwnSyZfZ7v7nE";
+const TICKETMASTER_SEGMENT_ARTS_THEATRE: &str = "KZFzniwnSyZfZ7v7na";
+const TICKETMASTER_SEGMENT_MISCELLANEOUS: &str = "KZFzniwnSyZfZ7v7n1";
+
+const TICKETMASTER_PAGE_SIZE: u32 = 100;
+
+const TICKETMASTER_MAX_RESULTS: u32 = 1000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterPublishEvent {
+    name: TicketmasterLocalized,
+    description: TicketmasterLocalized,
+    dates: TicketmasterDates,
+    venue: TicketmasterVenue,
+    classifications: Vec<TicketmasterClassification>,
+    promoter: TicketmasterPromoter,
+}
+
+impl TicketmasterPublishEvent {
+    fn from_event(event: &Event) -> Self {
+        Self {
+            name: TicketmasterLocalized { en: event.title.clone() },
+            description: TicketmasterLocalized { en: event.description.clone() },
+            dates: TicketmasterDates {
+                start: TicketmasterStart { date_time: Some(event.event_date) },
+                timezone: Some(event.timezone().name().to_string()),
+                doors_times: Some(TicketmasterStart { date_time: Some(event.door_time) }),
+                status: None,
+            },
+            venue: TicketmasterVenue::from_venue(&event.venue),
+            classifications: vec![TicketmasterClassification {
+                segment: Some(TicketmasterNamed {
+                    id: Some(ticketmaster_segment(&event.event_type).to_string()),
+                    name: None,
+                }),
+                genre: None,
+            }],
+            promoter: TicketmasterPromoter { name: event.organizer.clone() },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterLocalized {
+    en: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterDates {
+    start: TicketmasterStart,
+    timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doors_times: Option<TicketmasterStart>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<TicketmasterStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterStart {
+    date_time: Option<chrono::DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterStatus {
+    code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterNamed {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterClassification {
+    segment: Option<TicketmasterNamed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    genre: Option<TicketmasterNamed>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterPromoter {
+    name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterVenue {
+    name: Option<String>,
+    address: Option<TicketmasterAddress>,
+    city: Option<TicketmasterCity>,
+    country: Option<TicketmasterCountry>,
+    location: Option<TicketmasterLocation>,
+    timezone: Option<String>,
+    // Not part of the Discovery payload; only sent when publishing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    capacity: Option<u32>,
+}
+
+impl TicketmasterVenue {
+    fn from_venue(venue: &Venue) -> Self {
+        Self {
+            name: Some(venue.name.clone()),
+            address: Some(TicketmasterAddress { line1: Some(venue.address.clone()) }),
+            city: Some(TicketmasterCity { name: Some(venue.city.clone()) }),
+            country: Some(TicketmasterCountry { country_code: Some(country_code(&venue.country).to_string()) }),
+            // Ticketmaster encodes coordinates as strings
+            location: venue.coordinates.as_ref().map(|c| TicketmasterLocation {
+                latitude: Some(c.latitude.to_string()),
+                longitude: Some(c.longitude.to_string()),
+            }),
+            timezone: venue.timezone.map(|zone| zone.name().to_string()),
+            capacity: Some(venue.capacity),
+        }
+    }
+
+    fn into_venue(self) -> Venue {
+        let coordinates = self.location.as_ref().and_then(|location| {
+            parse_coordinates(location.latitude.as_deref(), location.longitude.as_deref())
+        });
+
+        Venue {
+            id: None,
+            name: self.name.unwrap_or_default(),
+            address: self.address.and_then(|address| address.line1).unwrap_or_default(),
+            city: self.city.and_then(|city| city.name).unwrap_or_default(),
+            country: self.country.and_then(|country| country.country_code).unwrap_or_default(),
+            capacity: self.capacity.unwrap_or(0),
+            coordinates,
+            timezone: self.timezone.as_deref().and_then(parse_timezone),
+            seat_map: None,
+            areas: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterAddress {
+    line1: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterCity {
+    name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterCountry {
+    country_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterLocation {
+    latitude: Option<String>,
+    longitude: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterOffer {
+    name: String,
+    currency: String,
+    price: f64,
+    quantity: u32,
+    sales_start: chrono::DateTime<Utc>,
+    sales_end: chrono::DateTime<Utc>,
+}
+
+impl TicketmasterOffer {
+    fn from_ticket_type(ticket_type: &TicketType) -> Self {
+        Self {
+            name: ticket_type.name.clone(),
+            currency: ticket_type.currency.clone(),
+            price: ticket_type.price.to_f64().unwrap_or_default(),
+            quantity: ticket_type.quantity_available,
+            sales_start: ticket_type.sales_start,
+            sales_end: ticket_type.sales_end,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterOfferResponse {
+    id: String,
+    quantity: u32,
+    quantity_sold: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterOfferQuantity {
+    quantity: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterIdResponse {
+    id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterEventResponse {
+    id: String,
+    name: String,
+    url: Option<String>,
+    info: Option<String>,
+    dates: TicketmasterDates,
+    classifications: Option<Vec<TicketmasterClassification>>,
+    price_ranges: Option<Vec<TicketmasterPriceRange>>,
+    promoter: Option<TicketmasterPromoter>,
+    #[serde(rename = "_embedded")]
+    embedded: Option<TicketmasterEventEmbedded>,
+    // ... other response fields
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterEventEmbedded {
+    venues: Vec<TicketmasterVenue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterPriceRange {
+    #[serde(rename = "type")]
+    range_type: String,
+    currency: String,
+    min: f64,
+    max: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterEventListResponse {
+    #[serde(rename = "_embedded")]
+    embedded: Option<TicketmasterEventListEmbedded>,
+    page: Option<TicketmasterPage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TicketmasterEventListEmbedded {
+    events: Vec<TicketmasterEventResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterPage {
+    size: u32,
+    total_elements: u32,
+    total_pages: u32,
+    number: u32,
+}
+
+impl TicketmasterPage {
+    /// Discovery refuses to page past its first 1,000 results.
+    fn next_page(&self) -> Option<u32> {
+        let next = self.number + 1;
+        if next < self.total_pages && (next + 1) * self.size <= TICKETMASTER_MAX_RESULTS {
+            Some(next)
+        } else {
+            None
+        }
+    }
+}
+
+/// Discovery API search parameters we can push down; the rest is filtered client-side.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterSearchQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    segment_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keyword: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latlong: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    radius: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<&'static str>,
+    size: u32,
+    page: u32,
+}
+
+impl TicketmasterSearchQuery {
+    fn from_filters(filters: &EventFilters, page: u32) -> Self {
+        // Discovery rejects fractional seconds in date filters
+        let format = |date: chrono::DateTime<Utc>| date.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+        Self {
+            start_date_time: filters.start_date.map(format),
+            end_date_time: filters.end_date.map(format),
+            city: filters.venue_city.clone(),
+            segment_id: filters.event_type.as_ref().map(|t| ticketmaster_segment(t).to_string()),
+            keyword: filters.keyword.clone(),
+            latlong: filters.near.as_ref()
+                .map(|near| format!("{},{}", near.center.latitude, near.center.longitude)),
+            // Radius only takes whole units; the exact cut-off is applied client-side
+            radius: filters.near.as_ref().map(|near| near.radius_km.ceil() as u32),
+            unit: filters.near.as_ref().map(|_| "km"),
+            size: TICKETMASTER_PAGE_SIZE,
+            page,
+        }
+    }
+}
+
+impl TicketmasterEventResponse {
+    fn into_event(self) -> Event {
+        let now = Utc::now();
+        let event_date = self.dates.start.date_time.unwrap_or(now);
+        let timezone = self.dates.timezone.as_deref().and_then(parse_timezone);
+        let door_time = self.dates.doors_times
+            .and_then(|doors| doors.date_time)
+            .unwrap_or(event_date);
+        let event_type = self.classifications
+            .as_deref()
+            .and_then(|classifications| classifications.first())
+            .map(event_type_from_ticketmaster)
+            .unwrap_or(EventType::Concert);
+        let venue = self.embedded
+            .and_then(|embedded| embedded.venues.into_iter().next())
+            .map(TicketmasterVenue::into_venue)
+            .unwrap_or_else(|| empty_venue(0));
+        // Discovery exposes price ranges rather than individual offers
+        let (sales_start, sales_end) = sales_window(None, None, event_date);
+        let ticket_types = self.price_ranges
+            .unwrap_or_default()
+            .into_iter()
+            .map(|range| TicketType {
+                id: Uuid::new_v4(),
+                name: range.range_type,
+                price: Decimal::from_f64(range.min).unwrap_or_default(),
+                currency: range.currency,
+                quantity_available: 0,
+                quantity_sold: 0,
+                perks: Vec::new(),
+                nft_metadata: None,
+                sales_start,
+                sales_end,
+                transfer_policy: TransferPolicy::default(),
+                inventory: InventoryAllocation::default(),
+                channel_sales: HashMap::new(),
+                external_ids: HashMap::new(),
+                price_zone: None,
+                area: None,
+                session_access: SessionAccess::default(),
+            })
+            .collect();
+
+        Event {
+            id: Uuid::new_v4(),
+            title: self.name,
+            description: self.info.unwrap_or_default(),
+            organizer: self.promoter.map(|promoter| promoter.name).unwrap_or_default(),
+            venue,
+            event_date,
+            door_time,
+            end_date: None,
+            timezone,
+            local_times: None,
+            event_type,
+            ticket_types,
+            publications: HashMap::from([(
+                EventPlatform::Ticketmaster,
+                PlatformSync::synced(EventPlatform::Ticketmaster, self.id.clone(), self.url.unwrap_or_default()),
+            )]),
+            external_event_id: Some(self.id),
+            platform: EventPlatform::Ticketmaster,
+            status: self.dates.status
+                .map(|status| ticketmaster_status(&status.code))
+                .unwrap_or(EventStatus::Published),
+            created_at: now,
+            updated_at: now,
+            sold_seats: HashSet::new(),
+            sessions: Vec::new(),
+            recurrence: None,
+            version: 0,
+            settlement: SettlementSchedule::default(),
+        }
+    }
+}
+
+fn ticketmaster_status(code: &str) -> EventStatus {
+    match code {
+        "onsale" => EventStatus::OnSale,
+        "cancelled" => EventStatus::Cancelled,
+        // offsale, postponed and rescheduled events are still listed
+        _ => EventStatus::Published,
+    }
+}
+
+fn ticketmaster_segment(event_type: &EventType) -> &'static str {
+    match event_type {
+        EventType::Concert | EventType::Festival => TICKETMASTER_SEGMENT_MUSIC,
+        EventType::Sports => TICKETMASTER_SEGMENT_SPORTS,
+        EventType::Theater => TICKETMASTER_SEGMENT_ARTS_THEATRE,
+        EventType::Conference | EventType::Workshop | EventType::Exhibition => TICKETMASTER_SEGMENT_MISCELLANEOUS,
+    }
+}
+
+fn event_type_from_ticketmaster(classification: &TicketmasterClassification) -> EventType {
+    let segment = classification.segment.as_ref().and_then(|s| s.name.as_deref());
+    let genre = classification.genre.as_ref().and_then(|g| g.name.as_deref());
+    match (segment, genre) {
+        (_, Some("Fairs & Festivals")) | (_, Some("Festival")) => EventType::Festival,
+        (Some("Music"), _) => EventType::Concert,
+        (Some("Sports"), _) => EventType::Sports,
+        (Some("Arts & Theatre"), _) => EventType::Theater,
+        (_, Some("Lecture/Seminar")) => EventType::Conference,
+        (_, Some("Fine Art")) => EventType::Exhibition,
+        (_, Some("Hobby/Special Interest Expos")) => EventType::Exhibition,
+        _ => EventType::Conference,
+    }
+}
+
+// Cvent-specific data structures
+
+// Refresh OAuth tokens this long before they expire
+const CVENT_TOKEN_REFRESH_MARGIN_SECS: i64 = 60;
+
+const CVENT_PAGE_SIZE: u32 = 100;
+
+struct CventAccessToken {
+    token: String,
+    expires_at: chrono::DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CventTokenResponse {
+    access_token: String,
+    expires_in: i64,
+    token_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventEvent {
+    title: String,
+    description: String,
+    start: chrono::DateTime<Utc>,
+    end: chrono::DateTime<Utc>,
+    timezone: String,
+    format: String,
+    #[serde(rename = "type")]
+    event_type: String,
+    currency: String,
+    capacity: u32,
+    planner: String,
+    venues: Vec<CventVenue>,
+}
+
+impl CventEvent {
+    fn from_event(event: &Event) -> Self {
+        let currency = event.ticket_types.first()
+            .map(|ticket_type| ticket_type.currency.clone())
+            .unwrap_or_else(|| "HKD".to_string());
+
+        Self {
+            title: event.title.clone(),
+            description: event.description.clone(),
+            start: event.event_date,
+            end: event.end_time(),
+            timezone: event.timezone().name().to_string(),
+            format: "In-person".to_string(),
+            event_type: cvent_event_type(&event.event_type).to_string(),
+            currency,
+            capacity: event.venue.capacity,
+            planner: event.organizer.clone(),
+            venues: vec![CventVenue::from_venue(&event.venue)],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventVenue {
+    name: Option<String>,
+    address: Option<CventAddress>,
+    capacity: Option<u32>,
+}
+
+impl CventVenue {
+    fn from_venue(venue: &Venue) -> Self {
+        Self {
+            name: Some(venue.name.clone()),
+            address: Some(CventAddress {
+                address1: Some(venue.address.clone()),
+                city: Some(venue.city.clone()),
+                country_code: Some(country_code(&venue.country).to_string()),
+                latitude: venue.coordinates.as_ref().map(|c| c.latitude),
+                longitude: venue.coordinates.as_ref().map(|c| c.longitude),
+            }),
+            capacity: Some(venue.capacity),
+        }
+    }
+
+    fn into_venue(self, event_capacity: Option<u32>) -> Venue {
+        let address = self.address.unwrap_or(CventAddress {
+            address1: None,
+            city: None,
+            country_code: None,
+            latitude: None,
+            longitude: None,
+        });
+        let coordinates = match (address.latitude, address.longitude) {
+            (Some(latitude), Some(longitude)) => Some(Coordinates { latitude, longitude }),
+            _ => None,
+        };
+
+        Venue {
+            id: None,
+            name: self.name.unwrap_or_default(),
+            address: address.address1.unwrap_or_default(),
+            city: address.city.unwrap_or_default(),
+            country: address.country_code.unwrap_or_default(),
+            capacity: self.capacity.or(event_capacity).unwrap_or(0),
+            coordinates,
+            timezone: None,
+            seat_map: None,
+            areas: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventAddress {
+    address1: Option<String>,
+    city: Option<String>,
+    country_code: Option<String>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventAdmissionItem {
+    name: String,
+    amount: f64,
+    currency: String,
+    capacity: u32,
+    available_from: chrono::DateTime<Utc>,
+    available_to: chrono::DateTime<Utc>,
+}
+
+impl CventAdmissionItem {
+    fn from_ticket_type(ticket_type: &TicketType) -> Self {
+        Self {
+            name: ticket_type.name.clone(),
+            amount: ticket_type.price.to_f64().unwrap_or_default(),
+            currency: ticket_type.currency.clone(),
+            capacity: ticket_type.quantity_available,
+            available_from: ticket_type.sales_start,
+            available_to: ticket_type.sales_end,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventAdmissionItemResponse {
+    id: String,
+    name: String,
+    amount: f64,
+    currency: Option<String>,
+    capacity: Option<u32>,
+    registered: Option<u32>,
+    available_from: Option<chrono::DateTime<Utc>>,
+    available_to: Option<chrono::DateTime<Utc>>,
+}
+
+impl CventAdmissionItemResponse {
+    fn into_ticket_type(self, event_currency: Option<&str>, event_date: chrono::DateTime<Utc>) -> TicketType {
+        let quantity_sold = self.registered.unwrap_or(0);
+        let (sales_start, sales_end) = sales_window(self.available_from, self.available_to, event_date);
+
+        TicketType {
+            id: Uuid::new_v4(),
+            name: self.name,
+            price: Decimal::from_f64(self.amount).unwrap_or_default(),
+            currency: self.currency
+                .or_else(|| event_currency.map(str::to_string))
+                .unwrap_or_else(|| "HKD".to_string()),
+            quantity_available: self.capacity.unwrap_or(0),
+            quantity_sold,
+            perks: Vec::new(),
+            nft_metadata: None,
+            sales_start,
+            sales_end,
+            transfer_policy: TransferPolicy::default(),
+            inventory: InventoryAllocation::default(),
+            channel_sales: HashMap::from([(EventPlatform::Cvent, quantity_sold)]),
+            external_ids: HashMap::from([(EventPlatform::Cvent, self.id)]),
+            price_zone: None,
+            area: None,
+            session_access: SessionAccess::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CventAdmissionItemCapacity {
+    capacity: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CventIdResponse {
+    id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventEventResponse {
+    id: String,
+    title: String,
+    description: Option<String>,
+    start: chrono::DateTime<Utc>,
+    end: Option<chrono::DateTime<Utc>>,
+    timezone: Option<String>,
+    status: String,
+    #[serde(rename = "type")]
+    event_type: Option<String>,
+    currency: Option<String>,
+    capacity: Option<u32>,
+    planner: Option<String>,
+    venues: Option<Vec<CventVenue>>,
+    admission_items: Option<Vec<CventAdmissionItemResponse>>,
+    links: Option<CventLinks>,
+    // ... other response fields
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventLinks {
+    invitation: Option<CventLink>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CventLink {
+    href: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventEventListResponse {
+    data: Vec<CventEventResponse>,
+    paging: Option<CventPaging>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventPaging {
+    next_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CventEventQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<chrono::DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<chrono::DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token: Option<String>,
+    limit: u32,
+}
+
+impl CventEventQuery {
+    fn from_filters(filters: &EventFilters, token: Option<String>) -> Self {
+        Self {
+            after: filters.start_date,
+            before: filters.end_date,
+            token,
+            limit: CVENT_PAGE_SIZE,
+        }
+    }
+}
+
+impl CventEventResponse {
+    fn event_url(&self) -> String {
+        self.links.as_ref()
+            .and_then(|links| links.invitation.as_ref())
+            .map(|link| link.href.clone())
+            .unwrap_or_default()
+    }
+
+    fn into_event(self) -> Event {
+        let now = Utc::now();
+        let event_url = self.event_url();
+        let event_date = self.start;
+        let capacity = self.capacity;
+        let currency = self.currency;
+        let venue = self.venues
+            .and_then(|venues| venues.into_iter().next())
+            .map(|venue| venue.into_venue(capacity))
+            .unwrap_or_else(|| empty_venue(capacity.unwrap_or(0)));
+        let ticket_types = self.admission_items
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| item.into_ticket_type(currency.as_deref(), event_date))
+            .collect();
+
+        Event {
+            id: Uuid::new_v4(),
+            title: self.title,
+            description: self.description.unwrap_or_default(),
+            organizer: self.planner.unwrap_or_default(),
+            venue,
+            event_date,
+            door_time: event_date,
+            end_date: self.end,
+            timezone: self.timezone.as_deref().and_then(parse_timezone),
+            local_times: None,
+            event_type: self.event_type
+                .as_deref()
+                .map(event_type_from_cvent)
+                .unwrap_or(EventType::Conference),
+            ticket_types,
+            publications: HashMap::from([(
+                EventPlatform::Cvent,
+                PlatformSync::synced(EventPlatform::Cvent, self.id.clone(), event_url),
+            )]),
+            external_event_id: Some(self.id),
+            platform: EventPlatform::Cvent,
+            status: cvent_status(&self.status),
+            created_at: now,
+            updated_at: now,
+            sold_seats: HashSet::new(),
+            sessions: Vec::new(),
+            recurrence: None,
+            version: 0,
+            settlement: SettlementSchedule::default(),
+        }
+    }
+}
+
+fn cvent_status(status: &str) -> EventStatus {
+    match status {
+        "Active" => EventStatus::OnSale,
+        "Closed" => EventStatus::SoldOut,
+        "Completed" => EventStatus::Completed,
+        "Cancelled" => EventStatus::Cancelled,
+        _ => EventStatus::Draft,
+    }
+}
+
+fn cvent_event_type(event_type: &EventType) -> &'static str {
+    match event_type {
+        EventType::Concert => "Concert",
+        EventType::Conference => "Conference",
+        EventType::Sports => "Sporting Event",
+        EventType::Theater => "Performance",
+        EventType::Festival => "Festival",
+        EventType::Workshop => "Workshop",
+        EventType::Exhibition => "Trade Show",
+    }
+}
+
+fn event_type_from_cvent(event_type: &str) -> EventType {
+    match event_type {
+        "Concert" => EventType::Concert,
+        "Sporting Event" => EventType::Sports,
+        "Performance" => EventType::Theater,
+        "Festival" => EventType::Festival,
+        "Workshop" | "Training" => EventType::Workshop,
+        "Trade Show" | "Exhibition" => EventType::Exhibition,
+        _ => EventType::Conference,
+    }
+}
+
+// Helpers shared across platform mappings
+
+fn currency_exponent(currency: &str) -> u32 {
+    match currency {
+        "JPY" | "KRW" | "VND" | "CLP" | "ISK" => 0,
+        _ => 2,
+    }
+}
+
+/// Formats a price the way Eventbrite's ticket class `cost` expects: "HKD,12000".
+fn format_cost(price: Decimal, currency: &str) -> String {
+    let minor_units = (price * Decimal::from(10i64.pow(currency_exponent(currency)))).round();
+    format!("{},{}", currency, minor_units)
+}
+
+fn parse_minor_units(value: i64, currency: &str) -> Decimal {
+    Decimal::new(value, currency_exponent(currency))
+}
+
+fn parse_coordinates(latitude: Option<&str>, longitude: Option<&str>) -> Option<Coordinates> {
+    match (latitude?.parse(), longitude?.parse()) {
+        (Ok(latitude), Ok(longitude)) => Some(Coordinates { latitude, longitude }),
+        _ => None,
+    }
+}
+
+// Platforms that leave out a sales window are taken to have sold since long
+// before the import and up to the start, which passes validation even for
+// events already underway
+fn sales_window(
+    start: Option<chrono::DateTime<Utc>>,
+    end: Option<chrono::DateTime<Utc>>,
+    event_date: chrono::DateTime<Utc>,
+) -> (chrono::DateTime<Utc>, chrono::DateTime<Utc>) {
+    (start.unwrap_or(chrono::DateTime::<Utc>::UNIX_EPOCH), end.unwrap_or(event_date))
+}
+
+fn empty_venue(capacity: u32) -> Venue {
+    Venue {
+        id: None,
+        name: String::new(),
+        address: String::new(),
+        city: String::new(),
+        country: String::new(),
+        capacity,
+        coordinates: None,
+        timezone: None,
+        seat_map: None,
+        areas: Vec::new(),
+    }
+}
+
+fn country_code(country: &str) -> &str {
+    VENUE_TIMEZONES.iter()
+        .find(|(_, name, _)| name.eq_ignore_ascii_case(country))
+        .map(|(code, _, _)| *code)
+        .unwrap_or(country)
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Blank lines become paragraphs and single newlines become `<br>`.
+fn description_to_html(description: &str) -> String {
+    description
+        .split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| format!("<p>{}</p>", escape_html(paragraph).replace('\n', "<br>")))
+        .collect()
+}
+
+fn html_to_text(html: &str) -> String {
+    let with_breaks = html
+        .replace("<br>", "\n")
+        .replace("<br/>", "\n")
+        .replace("</p>", "\n\n");
+    let mut text = String::with_capacity(with_breaks.len());
+    let mut in_tag = false;
+    for c in with_breaks.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use crate::services::fake_platform::FakePlatform;
+
+    #[test]
+    fn empty_filters_match_everything() {
+        assert!(EventFilters::default().matches(&fixtures::event()));
+    }
+
+    #[test]
+    fn status_filters_match_only_that_status() {
+        let event = fixtures::event();
+        let filters = |status| EventFilters { status: Some(status), ..Default::default() };
+
+        assert!(filters(EventStatus::Published).matches(&event));
+        assert!(!filters(EventStatus::Draft).matches(&event));
+        assert!(!filters(EventStatus::Cancelled).matches(&event));
+    }
+
+    #[test]
+    fn price_filters_match_any_ticket_type_in_range() {
+        let mut event = fixtures::event();
+        event.ticket_types.push(fixtures::ticket_type("VIP", Decimal::new(280000, 2), 50));
+        let range = |min: Option<i64>, max: Option<i64>| EventFilters {
+            min_price: min.map(Decimal::from),
+            max_price: max.map(Decimal::from),
+            ..Default::default()
+        };
+
+        assert!(range(Some(2000), None).matches(&event));
+        assert!(range(None, Some(900)).matches(&event));
+        assert!(range(Some(800), Some(900)).matches(&event));
+        assert!(!range(Some(900), Some(2000)).matches(&event));
+    }
+
+    #[test]
+    fn text_place_and_platform_filters() {
+        let mut event = fixtures::event();
+        event.publications.insert(EventPlatform::Cvent, PlatformSync::pending(EventPlatform::Cvent));
+
+        let keyword = |keyword: &str| EventFilters { keyword: Some(keyword.to_string()), ..Default::default() };
+        assert!(keyword("WATERFRONT").matches(&event));
+        assert!(!keyword("opera").matches(&event));
+
+        let city = |city: &str| EventFilters { venue_city: Some(city.to_string()), ..Default::default() };
+        assert!(city("hong kong").matches(&event));
+        assert!(!city("Macau").matches(&event));
+
+        let near = |latitude, longitude, radius_km| EventFilters {
+            near: Some(GeoRadius { center: Coordinates { latitude, longitude }, radius_km }),
+            ..Default::default()
+        };
+        // Central is roughly 25 km from the airport
+        assert!(near(22.2819, 114.1582, 30.0).matches(&event));
+        assert!(!near(22.2819, 114.1582, 10.0).matches(&event));
+
+        let platform = |platform| EventFilters { platform: Some(platform), ..Default::default() };
+        assert!(platform(EventPlatform::Internal).matches(&event));
+        assert!(platform(EventPlatform::Cvent).matches(&event));
+        assert!(!platform(EventPlatform::Ticketmaster).matches(&event));
+    }
+
+    #[test]
+    fn date_filters_are_inclusive() {
+        let event = fixtures::event();
+        let window = |start, end| EventFilters { start_date: Some(start), end_date: Some(end), ..Default::default() };
+
+        assert!(window(event.event_date, event.event_date).matches(&event));
+        assert!(!window(event.event_date + chrono::Duration::seconds(1), event.event_date + chrono::Duration::days(1)).matches(&event));
+    }
+
+    #[test]
+    fn imported_ticket_types_without_a_sales_window_sell_until_the_start() {
+        // Already underway, so the import time can't bound the window
+        let started = Utc::now() - chrono::Duration::hours(1);
+
+        let ticket_class: EventbriteTicketClassResponse = serde_json::from_value(serde_json::json!({
+            "id": "tc-1",
+            "name": "General Admission",
+        })).unwrap();
+        let admission_item: CventAdmissionItemResponse = serde_json::from_value(serde_json::json!({
+            "id": "ai-1",
+            "name": "General Admission",
+            "amount": 500.0,
+        })).unwrap();
+        let discovery: TicketmasterEventResponse = serde_json::from_value(serde_json::json!({
+            "id": "tm-1",
+            "name": "Harbour Lights",
+            "dates": { "start": { "dateTime": started } },
+            "priceRanges": [{ "type": "standard", "currency": "HKD", "min": 500.0, "max": 800.0 }],
+        })).unwrap();
+        let ticketmaster = discovery.into_event();
+
+        let imported = [
+            ticket_class.into_ticket_type(started),
+            admission_item.into_ticket_type(None, started),
+            ticketmaster.ticket_types[0].clone(),
+        ];
+        for ticket_type in &imported {
+            assert!(ticket_type.sales_start < ticket_type.sales_end);
+            assert_eq!(ticket_type.sales_end, started);
+        }
+        assert!(crate::services::schedule::schedule_violations(&ticketmaster).is_empty());
+    }
+
+    #[tokio::test]
+    async fn event_streams_fetch_pages_as_they_are_consumed() {
+        let platform = FakePlatform::new(EventPlatform::Eventbrite);
+        {
+            let mut state = platform.state.lock().unwrap();
+            state.page_size = Some(2);
+            state.listed = (0..5).map(|_| fixtures::event()).collect();
+        }
+
+        let mut stream = event_stream(&platform, EventFilters::default());
+        stream.next().await.unwrap().unwrap();
+        assert_eq!(platform.state.lock().unwrap().pages_served, 1);
+
+        let rest: Vec<Event> = stream.try_collect().await.unwrap();
+        assert_eq!(rest.len(), 4);
+        assert_eq!(platform.state.lock().unwrap().pages_served, 3);
+
+        assert_eq!(platform.list_events(EventFilters::default()).await.unwrap().len(), 5);
+    }
 }
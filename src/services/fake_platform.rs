@@ -0,0 +1,138 @@
+// src/services/fake_platform.rs
+//! An in-memory `TicketingPlatform` for unit tests. Clones share state, so a
+//! test can keep one handle while the service owns another.
+use crate::error::HKDError;
+use crate::models::ticketing::*;
+use crate::services::external_apis::{
+    EventFilters, EventPage, ExternalEventResponse, ExternalTicketTypeResponse, TicketingPlatform,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+#[derive(Default)]
+pub(crate) struct FakeState {
+    pub sold: HashMap<String, u32>, // Keyed by external ticket type id
+    pub quantity_totals: HashMap<String, u32>, // Last total pushed per ticket type
+    pub listed: Vec<Event>,
+    pub created: Vec<Uuid>, // Local ids of events created here
+    pub updated: Vec<String>, // External ids of events updated here
+    pub failure: Option<String>, // When set, every write fails with this error
+    pub page_size: Option<usize>, // Listings come back in pages of this size
+    pub pages_served: u32,
+}
+
+#[derive(Clone)]
+pub(crate) struct FakePlatform {
+    platform: EventPlatform,
+    pub state: Arc<Mutex<FakeState>>,
+}
+
+impl FakePlatform {
+    pub fn new(platform: EventPlatform) -> Self {
+        Self { platform, state: Arc::default() }
+    }
+
+    pub fn set_sold(&self, ticket_type_id: &str, sold: u32) {
+        self.state.lock().unwrap().sold.insert(ticket_type_id.to_string(), sold);
+    }
+
+    pub fn fail_with(&self, error: &str) {
+        self.state.lock().unwrap().failure = Some(error.to_string());
+    }
+
+    pub fn recover(&self) {
+        self.state.lock().unwrap().failure = None;
+    }
+
+    pub fn created(&self) -> Vec<Uuid> {
+        self.state.lock().unwrap().created.clone()
+    }
+
+    pub fn updated(&self) -> Vec<String> {
+        self.state.lock().unwrap().updated.clone()
+    }
+
+    fn check_failure(&self) -> Result<(), HKDError> {
+        match &self.state.lock().unwrap().failure {
+            Some(error) => Err(HKDError::ExternalApiError(error.clone())),
+            None => Ok(()),
+        }
+    }
+
+    pub fn quantity_total(&self, ticket_type_id: &str) -> Option<u32> {
+        self.state.lock().unwrap().quantity_totals.get(ticket_type_id).copied()
+    }
+}
+
+#[async_trait]
+impl TicketingPlatform for FakePlatform {
+    fn platform(&self) -> EventPlatform {
+        self.platform.clone()
+    }
+
+    async fn create_event(&self, event: &Event) -> Result<ExternalEventResponse, HKDError> {
+        self.check_failure()?;
+        self.state.lock().unwrap().created.push(event.id);
+        Ok(ExternalEventResponse {
+            external_id: format!("fake-{}", event.id),
+            event_url: format!("https://fake.test/events/{}", event.id),
+            platform: self.platform.clone(),
+            sync_status: SyncStatus::Synced,
+        })
+    }
+
+    async fn update_event(&self, event_id: &str, _event: &Event) -> Result<ExternalEventResponse, HKDError> {
+        self.check_failure()?;
+        self.state.lock().unwrap().updated.push(event_id.to_string());
+        Ok(ExternalEventResponse {
+            external_id: event_id.to_string(),
+            event_url: format!("https://fake.test/events/{}", event_id),
+            platform: self.platform.clone(),
+            sync_status: SyncStatus::Synced,
+        })
+    }
+
+    async fn get_event(&self, event_id: &str) -> Result<Event, HKDError> {
+        self.state.lock().unwrap().listed.iter()
+            .find(|event| event.external_event_id.as_deref() == Some(event_id))
+            .cloned()
+            .ok_or_else(|| HKDError::ExternalApiError(format!("No such event {}", event_id)))
+    }
+
+    async fn list_events_page(&self, filters: &EventFilters, cursor: Option<String>) -> Result<EventPage, HKDError> {
+        let mut state = self.state.lock().unwrap();
+        state.pages_served += 1;
+        let matching: Vec<Event> = state.listed.iter()
+            .filter(|event| filters.matches(event))
+            .cloned()
+            .collect();
+
+        // Cursors are plain offsets into the matching events
+        let offset: usize = cursor.and_then(|cursor| cursor.parse().ok()).unwrap_or(0);
+        let end = state.page_size.map_or(matching.len(), |size| (offset + size).min(matching.len()));
+        let next_cursor = (end < matching.len()).then(|| end.to_string());
+        Ok(EventPage { events: matching[offset.min(end)..end].to_vec(), next_cursor })
+    }
+
+    async fn create_ticket_types(&self, event_id: &str, ticket_types: &[TicketType]) -> Result<Vec<ExternalTicketTypeResponse>, HKDError> {
+        self.check_failure()?;
+        Ok(ticket_types.iter()
+            .map(|ticket_type| ExternalTicketTypeResponse {
+                external_id: format!("{}-{}", event_id, ticket_type.id),
+                ticket_type_id: ticket_type.id,
+                platform: self.platform.clone(),
+            })
+            .collect())
+    }
+
+    async fn get_quantity_sold(&self, _event_id: &str, ticket_type_id: &str) -> Result<u32, HKDError> {
+        Ok(self.state.lock().unwrap().sold.get(ticket_type_id).copied().unwrap_or(0))
+    }
+
+    async fn set_quantity_total(&self, _event_id: &str, ticket_type_id: &str, quantity_total: u32) -> Result<(), HKDError> {
+        self.state.lock().unwrap().quantity_totals.insert(ticket_type_id.to_string(), quantity_total);
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+// src/services/fx.rs
+use crate::error::HKDError;
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use uuid::Uuid;
+
+// How long a quoted price is honoured
+pub const DEFAULT_QUOTE_TTL_SECONDS: i64 = 300;
+
+// Currencies charged in whole units
+const ZERO_DECIMAL_CURRENCIES: &[&str] = &["JPY", "KRW"];
+
+#[async_trait::async_trait]
+pub trait FxRateProvider {
+    /// Units of `to` per unit of `from`.
+    async fn rate(&self, from: &str, to: &str) -> Result<Decimal, HKDError>;
+}
+
+/// Rates against a single base currency, set in code or loaded from a JSON file
+/// such as `{"base": "HKD", "rates": {"USD": "0.128", "JPY": "19.2"}}`.
+/// Cross rates go through the base.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FixedRateProvider {
+    base: String,
+    rates: HashMap<String, Decimal>, // Units of each currency per unit of `base`
+}
+
+impl FixedRateProvider {
+    pub fn new(base: &str) -> Self {
+        Self {
+            base: base.to_string(),
+            rates: HashMap::new(),
+        }
+    }
+
+    pub fn with_rate(mut self, currency: &str, per_base: Decimal) -> Self {
+        self.rates.insert(currency.to_string(), per_base);
+        self
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, HKDError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| HKDError::ExternalApiError(format!("Reading FX rates from {}: {}", path.display(), e)))?;
+        serde_json::from_str(&contents)
+            .map_err(|e| HKDError::ExternalApiError(format!("Parsing FX rates from {}: {}", path.display(), e)))
+    }
+
+    fn per_base(&self, currency: &str) -> Option<Decimal> {
+        if currency == self.base {
+            return Some(Decimal::ONE);
+        }
+        self.rates.get(currency).copied().filter(|rate| *rate > Decimal::ZERO)
+    }
+}
+
+impl Default for FixedRateProvider {
+    fn default() -> Self {
+        Self::new("HKD")
+    }
+}
+
+#[async_trait::async_trait]
+impl FxRateProvider for FixedRateProvider {
+    async fn rate(&self, from: &str, to: &str) -> Result<Decimal, HKDError> {
+        match (self.per_base(from), self.per_base(to)) {
+            (Some(from_rate), Some(to_rate)) => Ok(to_rate / from_rate),
+            _ => Err(HKDError::FxRateUnavailable { from: from.to_string(), to: to.to_string() }),
+        }
+    }
+}
+
+/// A ticket price converted into the buyer's currency, honoured until `expires_at`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceQuote {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub ticket_type_id: Uuid,
+    pub base_price: Decimal,
+    pub base_currency: String,
+    pub price: Decimal, // Per ticket, rounded to the currency's minor unit
+    pub currency: String,
+    pub rate: Decimal,
+    pub quoted_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl PriceQuote {
+    pub fn is_expired(&self, at: DateTime<Utc>) -> bool {
+        at > self.expires_at
+    }
+}
+
+/// Converts a per-ticket price, returning the rounded price and the rate used.
+pub async fn convert_price(
+    provider: &(dyn FxRateProvider + Send + Sync),
+    price: Decimal,
+    from: &str,
+    to: &str,
+) -> Result<(Decimal, Decimal), HKDError> {
+    let rate = if from == to { Decimal::ONE } else { provider.rate(from, to).await? };
+    Ok((round_to_minor_unit(price * rate, to), rate))
+}
+
+pub fn round_to_minor_unit(amount: Decimal, currency: &str) -> Decimal {
+    let places = if ZERO_DECIMAL_CURRENCIES.contains(&currency) { 0 } else { 2 };
+    amount.round_dp_with_strategy(places, RoundingStrategy::MidpointAwayFromZero)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use std::io::Write;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn rates() -> FixedRateProvider {
+        FixedRateProvider::new("HKD")
+            .with_rate("USD", dec("0.128"))
+            .with_rate("JPY", dec("19.2"))
+            .with_rate("EUR", dec("0.118"))
+    }
+
+    #[tokio::test]
+    async fn prices_convert_from_the_base_currency_and_round_to_cents() {
+        let (price, rate) = convert_price(&rates(), dec("880.00"), "HKD", "USD").await.unwrap();
+        assert_eq!(rate, dec("0.128"));
+        assert_eq!(price, dec("112.64"));
+
+        let (price, _) = convert_price(&rates(), dec("388.88"), "HKD", "USD").await.unwrap();
+        assert_eq!(price, dec("49.78")); // 49.77664
+    }
+
+    #[tokio::test]
+    async fn zero_decimal_currencies_round_to_whole_units() {
+        let (price, _) = convert_price(&rates(), dec("388.88"), "HKD", "JPY").await.unwrap();
+        assert_eq!(price, dec("7466")); // 7466.496
+    }
+
+    #[tokio::test]
+    async fn cross_rates_go_through_the_base() {
+        let (price, rate) = convert_price(&rates(), dec("100.00"), "USD", "EUR").await.unwrap();
+        assert_eq!(rate, dec("0.118") / dec("0.128"));
+        assert_eq!(price, dec("92.19"));
+    }
+
+    #[tokio::test]
+    async fn same_currency_needs_no_rate() {
+        let empty = FixedRateProvider::new("HKD");
+        assert_eq!(convert_price(&empty, dec("10.00"), "SGD", "SGD").await.unwrap(), (dec("10.00"), Decimal::ONE));
+    }
+
+    #[tokio::test]
+    async fn unknown_or_zero_rates_are_unavailable() {
+        let provider = rates().with_rate("GBP", Decimal::ZERO);
+        for to in ["GBP", "AUD"] {
+            match convert_price(&provider, dec("10.00"), "HKD", to).await {
+                Err(HKDError::FxRateUnavailable { from, to: quoted }) => {
+                    assert_eq!((from.as_str(), quoted.as_str()), ("HKD", to))
+                }
+                other => panic!("expected no rate to {}, got {:?}", to, other),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn rates_load_from_a_json_file() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, r#"{{"base": "HKD", "rates": {{"USD": "0.128"}}}}"#).unwrap();
+
+        let provider = FixedRateProvider::from_file(file.path()).unwrap();
+        assert_eq!(provider.rate("HKD", "USD").await.unwrap(), dec("0.128"));
+        assert!(FixedRateProvider::from_file(file.path().with_extension("missing")).is_err());
+    }
+
+    #[test]
+    fn quotes_are_honoured_until_they_expire() {
+        let quoted_at = Utc.with_ymd_and_hms(2030, 5, 1, 12, 0, 0).unwrap();
+        let quote = PriceQuote {
+            id: Uuid::new_v4(),
+            event_id: Uuid::new_v4(),
+            ticket_type_id: Uuid::new_v4(),
+            base_price: dec("880.00"),
+            base_currency: "HKD".to_string(),
+            price: dec("112.64"),
+            currency: "USD".to_string(),
+            rate: dec("0.128"),
+            quoted_at,
+            expires_at: quoted_at + Duration::seconds(DEFAULT_QUOTE_TTL_SECONDS),
+        };
+
+        assert!(!quote.is_expired(quoted_at));
+        assert!(!quote.is_expired(quote.expires_at));
+        assert!(quote.is_expired(quote.expires_at + Duration::seconds(1)));
+    }
+}
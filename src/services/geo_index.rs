@@ -0,0 +1,337 @@
+// src/services/geo_index.rs
+use crate::models::ticketing::*;
+use crate::services::event_search::is_listed;
+use crate::services::event_validation::Violation;
+use crate::error::HKDError;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+// Grid cell size; ~5.5 km north-south, narrower east-west away from the equator
+const CELL_DEGREES: f64 = 0.05;
+const KM_PER_DEGREE_LATITUDE: f64 = 111.32;
+const LONGITUDE_CELLS: i32 = (360.0 / CELL_DEGREES) as i32;
+
+/// Fixed-grid spatial index of event venues. Only venues with valid
+/// coordinates are indexed; the rest are reachable through the city fallback.
+#[derive(Debug, Default)]
+pub struct GeoIndex {
+    cells: HashMap<(i32, i32), HashSet<Uuid>>,
+    positions: HashMap<Uuid, Coordinates>,
+}
+
+impl GeoIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn upsert(&mut self, event_id: Uuid, coordinates: Option<&Coordinates>) {
+        self.remove(event_id);
+        let coordinates = match coordinates.filter(|c| is_valid(c)) {
+            Some(coordinates) => coordinates.clone(),
+            None => return,
+        };
+        self.cells.entry(cell_of(&coordinates)).or_default().insert(event_id);
+        self.positions.insert(event_id, coordinates);
+    }
+
+    pub fn remove(&mut self, event_id: Uuid) {
+        if let Some(previous) = self.positions.remove(&event_id) {
+            let cell = cell_of(&previous);
+            if let Some(ids) = self.cells.get_mut(&cell) {
+                ids.remove(&event_id);
+                if ids.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+    }
+
+    /// Indexed events within `radius_km` of `center`, nearest first.
+    pub fn within(&self, center: &Coordinates, radius_km: f64) -> Vec<(Uuid, f64)> {
+        let mut hits: Vec<(Uuid, f64)> = self.candidates(center, radius_km)
+            .into_iter()
+            .filter_map(|event_id| {
+                let distance = self.positions.get(&event_id)?.distance_km(center);
+                (distance <= radius_km).then_some((event_id, distance))
+            })
+            .collect();
+        hits.sort_by(|(a_id, a), (b_id, b)| a.total_cmp(b).then_with(|| a_id.cmp(b_id)));
+        hits
+    }
+
+    /// Events in every cell overlapping the radius' bounding box.
+    fn candidates(&self, center: &Coordinates, radius_km: f64) -> Vec<Uuid> {
+        let lat_span = radius_km / KM_PER_DEGREE_LATITUDE;
+        let min_lat = (center.latitude - lat_span).max(-90.0);
+        let max_lat = (center.latitude + lat_span).min(90.0);
+        // Longitude degrees shrink towards the poles; size the box for the widest row.
+        // Near a pole that is the whole ring, so the span stops at half of it
+        let widest = min_lat.abs().max(max_lat.abs()).to_radians().cos();
+        let lon_span = (radius_km / (KM_PER_DEGREE_LATITUDE * widest.max(f64::EPSILON))).min(180.0);
+
+        let lat_cells = cell_index(min_lat)..=cell_index(max_lat);
+        let first_lon = cell_index(center.longitude - lon_span);
+        let last_lon = cell_index(center.longitude + lon_span);
+        let lon_cells = i64::from(last_lon) - i64::from(first_lon) + 1;
+
+        // Walk the occupied cells instead when that means fewer lookups
+        if lon_cells >= i64::from(LONGITUDE_CELLS) || lon_cells * lat_cells.clone().count() as i64 > self.cells.len() as i64 {
+            return self.cells.iter()
+                .filter(|((lat, _), _)| lat_cells.contains(lat))
+                .flat_map(|(_, ids)| ids.iter().copied())
+                .collect();
+        }
+
+        let mut candidates = Vec::new();
+        for lat in lat_cells {
+            for lon in first_lon..=last_lon {
+                if let Some(ids) = self.cells.get(&(lat, wrap_longitude_cell(lon))) {
+                    candidates.extend(ids.iter().copied());
+                }
+            }
+        }
+        candidates
+    }
+}
+
+fn is_valid(coordinates: &Coordinates) -> bool {
+    (-90.0..=90.0).contains(&coordinates.latitude) && (-180.0..=180.0).contains(&coordinates.longitude)
+}
+
+fn cell_index(degrees: f64) -> i32 {
+    (degrees / CELL_DEGREES).floor() as i32
+}
+
+fn cell_of(coordinates: &Coordinates) -> (i32, i32) {
+    (cell_index(coordinates.latitude), wrap_longitude_cell(cell_index(coordinates.longitude)))
+}
+
+// Boxes crossing the antimeridian continue on the other side
+fn wrap_longitude_cell(cell: i32) -> i32 {
+    let half = LONGITUDE_CELLS / 2;
+    (cell + half).rem_euclid(LONGITUDE_CELLS) - half
+}
+
+/// "Events within 5 km of Central MTR in the next 7 days".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NearbyQuery {
+    pub center: Coordinates,
+    pub radius_km: f64,
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>, // Defaults to now
+    #[serde(default)]
+    pub within_days: Option<i64>,
+    // Venues without coordinates are matched on city, or country when no city is
+    // given; with neither, on the cities of the venues found within the radius
+    #[serde(default)]
+    pub city: Option<String>,
+    #[serde(default)]
+    pub country: Option<String>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NearbyEvent<'a> {
+    pub event: &'a Event,
+    pub distance_km: Option<f64>, // None for events matched by locality
+}
+
+/// Measured events nearest first, then locality matches soonest first.
+pub fn events_near<'a>(
+    index: &GeoIndex,
+    events: &'a HashMap<Uuid, Event>,
+    query: &NearbyQuery,
+    now: DateTime<Utc>,
+) -> Result<Vec<NearbyEvent<'a>>, HKDError> {
+    let from = query.from.unwrap_or(now);
+    let until = match query.within_days {
+        Some(days) => Some(
+            Duration::try_days(days)
+                .and_then(|window| from.checked_add_signed(window))
+                .ok_or_else(|| HKDError::ValidationFailed(vec![
+                    Violation::new("within_days", format!("{} days from {} is out of range", days, from)),
+                ]))?,
+        ),
+        None => None,
+    };
+    let in_window = |event: &Event| {
+        is_listed(event) && event.event_date >= from && until.is_none_or(|until| event.event_date <= until)
+    };
+
+    let mut nearby: Vec<NearbyEvent<'a>> = index.within(&query.center, query.radius_km)
+        .into_iter()
+        .filter_map(|(event_id, distance)| {
+            let event = events.get(&event_id)?;
+            in_window(event).then_some(NearbyEvent { event, distance_km: Some(distance) })
+        })
+        .collect();
+
+    let matches_locality: Box<dyn Fn(&Venue) -> bool> = match (&query.city, &query.country) {
+        (Some(city), _) => Box::new(move |venue| venue.city.eq_ignore_ascii_case(city)),
+        (None, Some(country)) => Box::new(move |venue| venue.country.eq_ignore_ascii_case(country)),
+        (None, None) => {
+            let cities: HashSet<String> = nearby.iter()
+                .map(|hit| hit.event.venue.city.to_lowercase())
+                .filter(|city| !city.is_empty())
+                .collect();
+            Box::new(move |venue| cities.contains(&venue.city.to_lowercase()))
+        }
+    };
+
+    let mut by_locality: Vec<&'a Event> = events.values()
+        .filter(|event| event.venue.coordinates.as_ref().is_none_or(|c| !is_valid(c)))
+        .filter(|event| matches_locality(&event.venue) && in_window(event))
+        .collect();
+    by_locality.sort_by(|a, b| a.event_date.cmp(&b.event_date).then_with(|| a.id.cmp(&b.id)));
+    nearby.extend(by_locality.into_iter().map(|event| NearbyEvent { event, distance_km: None }));
+
+    if let Some(limit) = query.limit {
+        nearby.truncate(limit);
+    }
+    Ok(nearby)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+
+    const CENTRAL: Coordinates = Coordinates { latitude: 22.2819, longitude: 114.1582 };
+    const COLISEUM: Coordinates = Coordinates { latitude: 22.3019, longitude: 114.1822 };
+
+    #[derive(Default)]
+    struct Catalogue {
+        index: GeoIndex,
+        events: HashMap<Uuid, Event>,
+    }
+
+    impl Catalogue {
+        fn add(&mut self, title: &str, city: &str, coordinates: Option<Coordinates>) -> Uuid {
+            let mut event = fixtures::event();
+            event.title = title.to_string();
+            event.event_date = Utc::now() + Duration::days(3);
+            event.venue.city = city.to_string();
+            event.venue.coordinates = coordinates;
+            self.index.upsert(event.id, event.venue.coordinates.as_ref());
+            let event_id = event.id;
+            self.events.insert(event_id, event);
+            event_id
+        }
+
+        fn near(&self, query: &NearbyQuery) -> Vec<(String, Option<f64>)> {
+            events_near(&self.index, &self.events, query, Utc::now())
+                .unwrap()
+                .into_iter()
+                .map(|hit| (hit.event.title.clone(), hit.distance_km))
+                .collect()
+        }
+    }
+
+    fn query(center: Coordinates, radius_km: f64) -> NearbyQuery {
+        NearbyQuery {
+            center,
+            radius_km,
+            from: None,
+            within_days: None,
+            city: None,
+            country: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn only_venues_inside_the_radius_are_returned_nearest_first() {
+        let mut catalogue = Catalogue::default();
+        catalogue.add("Coliseum", "Hong Kong", Some(COLISEUM));
+        catalogue.add("Sha Tin Hall", "Hong Kong", Some(Coordinates { latitude: 22.3817, longitude: 114.1877 }));
+        catalogue.add("Cultural Centre", "Hong Kong", Some(Coordinates { latitude: 22.2936, longitude: 114.1700 }));
+
+        let hits = catalogue.near(&NearbyQuery { city: Some("Nowhere".to_string()), ..query(CENTRAL, 5.0) });
+        let titles: Vec<&str> = hits.iter().map(|(title, _)| title.as_str()).collect();
+        assert_eq!(titles, vec!["Cultural Centre", "Coliseum"]);
+        assert!(hits.iter().all(|(_, distance)| distance.is_some_and(|km| km <= 5.0)));
+    }
+
+    #[test]
+    fn the_radius_reaches_across_the_antimeridian() {
+        let mut catalogue = Catalogue::default();
+        catalogue.add("Dateline", "Taveuni", Some(Coordinates { latitude: -16.8, longitude: -179.99 }));
+
+        let hits = catalogue.near(&query(Coordinates { latitude: -16.8, longitude: 179.99 }, 5.0));
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].1.unwrap() < 5.0);
+    }
+
+    #[test]
+    fn the_radius_reaches_over_the_pole() {
+        let mut catalogue = Catalogue::default();
+        catalogue.add("Greenwich side", "North Pole", Some(Coordinates { latitude: 89.99, longitude: 0.0 }));
+        catalogue.add("Dateline side", "North Pole", Some(Coordinates { latitude: 89.99, longitude: 180.0 }));
+        catalogue.add("Central", "Hong Kong", Some(CENTRAL));
+
+        let hits = catalogue.near(&query(Coordinates { latitude: 90.0, longitude: 0.0 }, 5.0));
+        let mut titles: Vec<String> = hits.into_iter().map(|(title, _)| title).collect();
+        titles.sort();
+        assert_eq!(titles, vec!["Dateline side", "Greenwich side"]);
+    }
+
+    #[test]
+    fn venues_without_coordinates_match_the_cities_found_nearby() {
+        let mut catalogue = Catalogue::default();
+        catalogue.add("Coliseum", "Hong Kong", Some(COLISEUM));
+        catalogue.add("Pop-up", "hong kong", None);
+        catalogue.add("Elsewhere", "Macau", None);
+        // Out-of-range coordinates are treated as missing
+        catalogue.add("Bad pin", "Hong Kong", Some(Coordinates { latitude: 122.0, longitude: 114.0 }));
+
+        let hits = catalogue.near(&query(CENTRAL, 5.0));
+        assert_eq!(hits[0].0, "Coliseum");
+        let by_locality: Vec<&str> = hits[1..].iter()
+            .filter(|(_, distance)| distance.is_none())
+            .map(|(title, _)| title.as_str())
+            .collect();
+        assert_eq!(by_locality.len(), 2);
+        assert!(by_locality.contains(&"Pop-up") && by_locality.contains(&"Bad pin"));
+    }
+
+    #[test]
+    fn events_outside_the_window_or_unlisted_are_left_out() {
+        let mut catalogue = Catalogue::default();
+        catalogue.add("This week", "Hong Kong", Some(COLISEUM));
+        let later = catalogue.add("Next month", "Hong Kong", Some(COLISEUM));
+        catalogue.events.get_mut(&later).unwrap().event_date = Utc::now() + Duration::days(30);
+        let draft = catalogue.add("Draft", "Hong Kong", Some(COLISEUM));
+        catalogue.events.get_mut(&draft).unwrap().status = EventStatus::Draft;
+
+        let hits = catalogue.near(&NearbyQuery { within_days: Some(7), ..query(CENTRAL, 5.0) });
+        let titles: Vec<&str> = hits.iter().map(|(title, _)| title.as_str()).collect();
+        assert_eq!(titles, vec!["This week"]);
+    }
+
+    #[test]
+    fn windows_past_the_date_range_are_rejected() {
+        let catalogue = Catalogue::default();
+        let query = NearbyQuery { within_days: Some(i64::MAX), ..query(CENTRAL, 5.0) };
+        match events_near(&catalogue.index, &catalogue.events, &query, Utc::now()) {
+            Err(HKDError::ValidationFailed(violations)) => assert_eq!(violations[0].field, "within_days"),
+            other => panic!("expected the window to be rejected, got {:?}", other.map(|hits| hits.len())),
+        }
+    }
+
+    #[test]
+    fn moving_a_venue_reindexes_it() {
+        let mut catalogue = Catalogue::default();
+        let event_id = catalogue.add("Roaming", "Hong Kong", Some(COLISEUM));
+        let far = Coordinates { latitude: 35.6762, longitude: 139.6503 };
+        catalogue.index.upsert(event_id, Some(&far));
+
+        assert!(catalogue.index.within(&CENTRAL, 50.0).is_empty());
+        assert_eq!(catalogue.index.within(&far, 1.0).len(), 1);
+
+        catalogue.index.remove(event_id);
+        assert!(catalogue.index.within(&far, 1.0).is_empty());
+    }
+}
@@ -0,0 +1,238 @@
+// src/services/inventory_sync.rs
+use crate::models::ticketing::*;
+use crate::services::external_apis::TicketingPlatform;
+use crate::services::ticketing_service::TicketingService;
+use crate::error::HKDError;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventorySyncReport {
+    pub event_id: Uuid,
+    pub pulled: u32, // Channels whose sales count changed
+    pub pushed: u32, // External quantities updated
+    pub conflicts: Vec<InventoryConflict>,
+}
+
+impl InventorySyncReport {
+    pub fn new(event_id: Uuid) -> Self {
+        Self {
+            event_id,
+            pulled: 0,
+            pushed: 0,
+            conflicts: Vec::new(),
+        }
+    }
+}
+
+/// Sales landed on several channels between syncs and exceeded stock.
+/// Every channel is capped at what it sold until an operator resolves the overage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InventoryConflict {
+    pub ticket_type_id: Option<Uuid>, // None when only the venue total is exceeded
+    pub oversold: u32,
+}
+
+/// Tickets still sellable before the venue is full, across all ticket types.
+pub fn venue_remaining(event: &Event) -> u32 {
+    clamp_u32(u64::from(event.venue.capacity).saturating_sub(total_sold(event)))
+}
+
+/// Tickets sold across all ticket types, summed wide enough not to overflow.
+pub fn total_sold(event: &Event) -> u64 {
+    event.ticket_types.iter().map(|tt| u64::from(tt.quantity_sold)).sum()
+}
+
+fn clamp_u32(value: u64) -> u32 {
+    u32::try_from(value).unwrap_or(u32::MAX)
+}
+
+/// Total sales `platform` may reach for this ticket type, including what it already sold.
+pub fn channel_limit(ticket_type: &TicketType, platform: &EventPlatform, venue_remaining: u32) -> u32 {
+    let sold_here = channel_sold(ticket_type, platform);
+    let type_remaining = ticket_type.quantity_available.saturating_sub(ticket_type.quantity_sold);
+    let quota = match &ticket_type.inventory {
+        InventoryAllocation::SharedPool => sold_here.saturating_add(type_remaining),
+        InventoryAllocation::Split(allocations) => {
+            let share_of = |percent: u8| {
+                u64::from(ticket_type.quantity_available) * u64::from(percent) / 100
+            };
+            let mut quota: u64 = allocations.iter()
+                .filter(|allocation| &allocation.platform == platform)
+                .map(|allocation| share_of(allocation.percent))
+                .sum();
+            if *platform == EventPlatform::Internal {
+                let allocated: u64 = allocations.iter().map(|allocation| share_of(allocation.percent)).sum();
+                quota += u64::from(ticket_type.quantity_available).saturating_sub(allocated);
+            }
+            // A share never outlasts the ticket type, so an oversold type closes every channel
+            clamp_u32(quota).min(sold_here.saturating_add(type_remaining))
+        }
+    };
+    quota.min(sold_here.saturating_add(venue_remaining))
+}
+
+/// Tickets `platform` can still sell right now.
+pub fn channel_available(ticket_type: &TicketType, platform: &EventPlatform, venue_remaining: u32) -> u32 {
+    channel_limit(ticket_type, platform, venue_remaining).saturating_sub(channel_sold(ticket_type, platform))
+}
+
+pub fn channel_sold(ticket_type: &TicketType, platform: &EventPlatform) -> u32 {
+    ticket_type.channel_sales.get(platform).copied().unwrap_or(0)
+}
+
+/// Records a channel's absolute sales count and keeps `quantity_sold` as the
+/// sum over channels. Returns whether anything changed.
+pub fn record_channel_sales(ticket_type: &mut TicketType, platform: EventPlatform, sold: u32) -> bool {
+    let previous = ticket_type.channel_sales.insert(platform, sold);
+    ticket_type.quantity_sold = clamp_u32(ticket_type.channel_sales.values().map(|&sold| u64::from(sold)).sum());
+    previous != Some(sold)
+}
+
+/// A ticket type's listing on an external platform, with the client that reaches it.
+#[derive(Clone)]
+pub struct InventoryChannel {
+    pub ticket_type_id: Uuid,
+    pub platform: EventPlatform,
+    pub external_event_id: String,
+    pub external_ticket_id: String,
+    pub client: Arc<dyn TicketingPlatform>,
+}
+
+/// Fetches how many tickets each channel has sold.
+pub async fn pull_channel_sales(channels: &[InventoryChannel]) -> Result<Vec<u32>, HKDError> {
+    let mut sales = Vec::with_capacity(channels.len());
+    for channel in channels {
+        sales.push(channel.client.get_quantity_sold(&channel.external_event_id, &channel.external_ticket_id).await?);
+    }
+    Ok(sales)
+}
+
+/// Sets each channel's total allowance, returning how many were pushed.
+pub async fn push_channel_limits(limits: &[(InventoryChannel, u32)]) -> Result<u32, HKDError> {
+    for (channel, limit) in limits {
+        channel.client.set_quantity_total(&channel.external_event_id, &channel.external_ticket_id, *limit).await?;
+    }
+    Ok(limits.len() as u32)
+}
+
+/// Periodically reconciles inventory for every event mirrored to an external platform.
+pub async fn run_inventory_sync_job(service: Arc<Mutex<TicketingService>>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        let event_ids = service.lock().await.externally_synced_event_ids();
+        for event_id in event_ids {
+            match sync_event_inventory(&service, event_id).await {
+                Ok(report) if !report.conflicts.is_empty() => log::warn!(
+                    "Inventory conflicts on event {}: {:?}",
+                    report.event_id, report.conflicts
+                ),
+                Ok(_) => {}
+                Err(e) => log::warn!("Inventory sync for event {} failed: {}", event_id, e),
+            }
+        }
+    }
+}
+
+// Same as `TicketingService::sync_inventory`, but only locks the service
+// between platform calls
+async fn sync_event_inventory(service: &Mutex<TicketingService>, event_id: Uuid) -> Result<InventorySyncReport, HKDError> {
+    let channels = service.lock().await.inventory_channels(event_id)?;
+    let sales = pull_channel_sales(&channels).await?;
+    let (mut report, limits) = service.lock().await.apply_channel_sales(event_id, &channels, &sales)?;
+    report.pushed = push_channel_limits(&limits).await?;
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use rust_decimal::Decimal;
+
+    fn ticket_type(quantity: u32, inventory: InventoryAllocation) -> TicketType {
+        let mut ticket_type = fixtures::ticket_type("General Admission", Decimal::new(50000, 2), quantity);
+        ticket_type.inventory = inventory;
+        ticket_type
+    }
+
+    fn split(allocations: &[(EventPlatform, u8)]) -> InventoryAllocation {
+        InventoryAllocation::Split(allocations.iter()
+            .map(|(platform, percent)| ChannelAllocation { platform: platform.clone(), percent: *percent })
+            .collect())
+    }
+
+    #[test]
+    fn shared_pool_channels_can_sell_everything_left() {
+        let mut ticket_type = ticket_type(100, InventoryAllocation::SharedPool);
+        record_channel_sales(&mut ticket_type, EventPlatform::Internal, 20);
+        record_channel_sales(&mut ticket_type, EventPlatform::Eventbrite, 30);
+
+        assert_eq!(channel_limit(&ticket_type, &EventPlatform::Eventbrite, 1_000), 80);
+        assert_eq!(channel_limit(&ticket_type, &EventPlatform::Internal, 1_000), 70);
+        assert_eq!(channel_available(&ticket_type, &EventPlatform::Ticketmaster, 1_000), 50);
+    }
+
+    #[test]
+    fn split_shares_round_down_and_internal_keeps_the_remainder() {
+        let ticket_type = ticket_type(101, split(&[
+            (EventPlatform::Eventbrite, 33),
+            (EventPlatform::Ticketmaster, 33),
+        ]));
+
+        assert_eq!(channel_limit(&ticket_type, &EventPlatform::Eventbrite, 1_000), 33);
+        assert_eq!(channel_limit(&ticket_type, &EventPlatform::Ticketmaster, 1_000), 33);
+        assert_eq!(channel_limit(&ticket_type, &EventPlatform::Internal, 1_000), 35);
+        assert_eq!(channel_limit(&ticket_type, &EventPlatform::Cvent, 1_000), 0);
+    }
+
+    #[test]
+    fn an_oversold_split_ticket_type_closes_every_channel() {
+        let mut ticket_type = ticket_type(100, split(&[(EventPlatform::Eventbrite, 40)]));
+        record_channel_sales(&mut ticket_type, EventPlatform::Internal, 70);
+        record_channel_sales(&mut ticket_type, EventPlatform::Eventbrite, 35);
+
+        // Eventbrite is within its share, but the ticket type is already over
+        assert_eq!(channel_available(&ticket_type, &EventPlatform::Eventbrite, 1_000), 0);
+        assert_eq!(channel_limit(&ticket_type, &EventPlatform::Eventbrite, 1_000), 35);
+        assert_eq!(channel_available(&ticket_type, &EventPlatform::Internal, 1_000), 0);
+    }
+
+    #[test]
+    fn split_channels_only_sell_their_own_share() {
+        let mut ticket_type = ticket_type(100, split(&[(EventPlatform::Eventbrite, 40)]));
+        record_channel_sales(&mut ticket_type, EventPlatform::Internal, 55);
+
+        assert_eq!(channel_available(&ticket_type, &EventPlatform::Eventbrite, 1_000), 40);
+        assert_eq!(channel_available(&ticket_type, &EventPlatform::Internal, 1_000), 5);
+    }
+
+    #[test]
+    fn venue_capacity_caps_every_channel() {
+        let mut ticket_type = ticket_type(100, InventoryAllocation::SharedPool);
+        record_channel_sales(&mut ticket_type, EventPlatform::Eventbrite, 10);
+
+        assert_eq!(channel_limit(&ticket_type, &EventPlatform::Eventbrite, 5), 15);
+        assert_eq!(channel_available(&ticket_type, &EventPlatform::Internal, 5), 5);
+    }
+
+    #[test]
+    fn recording_channel_sales_keeps_the_total_in_step() {
+        let mut ticket_type = ticket_type(100, InventoryAllocation::SharedPool);
+
+        assert!(record_channel_sales(&mut ticket_type, EventPlatform::Internal, 12));
+        assert!(record_channel_sales(&mut ticket_type, EventPlatform::Cvent, 8));
+        assert!(!record_channel_sales(&mut ticket_type, EventPlatform::Cvent, 8));
+        assert_eq!(ticket_type.quantity_sold, 20);
+        assert_eq!(channel_sold(&ticket_type, &EventPlatform::Cvent), 8);
+
+        let mut event = fixtures::event();
+        event.venue.capacity = 50;
+        event.ticket_types = vec![ticket_type];
+        assert_eq!(venue_remaining(&event), 30);
+    }
+}
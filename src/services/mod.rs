@@ -0,0 +1,25 @@
+// src/services/mod.rs
+pub mod escrow;
+pub mod event_changes;
+pub mod event_import;
+pub mod event_lifecycle;
+pub mod event_search;
+pub mod event_validation;
+pub mod external_apis;
+pub mod geo_index;
+pub mod fx;
+pub mod inventory_sync;
+pub mod nft_service;
+pub mod payments;
+pub mod platform_http;
+pub mod schedule;
+pub mod seating;
+pub mod sessions;
+pub mod sync_outbox;
+pub mod ticket_lifecycle;
+pub mod ticketing_service;
+pub mod venue_registry;
+pub mod webhooks;
+
+#[cfg(test)]
+pub(crate) mod fake_platform;
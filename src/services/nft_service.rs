@@ -1,31 +1,45 @@
 // src/services/nft_service.rs
-use crate::models::ticketing::{NFTMetadata, Ticket, Event};
+use crate::models::ticketing::{NFTMetadata, Ticket, Event, TransferPolicy};
 use crate::error::HKDError;
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Mutex;
 
 #[async_trait::async_trait]
 pub trait NFTMinter {
-    async fn mint_ticket_nft(&self, ticket: &Ticket, event: &Event, metadata: &NFTMetadata) -> Result<String, HKDError>;
+    async fn mint_ticket_nft(&self, ticket: &Ticket, event: &Event, metadata: &NFTMetadata, transfer_policy: &TransferPolicy) -> Result<String, HKDError>;
     async fn transfer_nft(&self, token_id: &str, to_wallet: &str) -> Result<String, HKDError>;
     async fn burn_nft(&self, token_id: &str) -> Result<String, HKDError>;
     async fn get_nft_metadata(&self, token_id: &str) -> Result<NFTMetadata, HKDError>;
 }
 
+#[allow(dead_code)] // Read once the on-chain calls are implemented
 pub struct EthereumNFTService {
     rpc_url: String,
     contract_address: String,
     private_key: String,
 }
 
+#[allow(dead_code)] // Read once the on-chain calls are implemented
 pub struct SolanaNFTService {
     rpc_url: String,
     program_id: String,
     private_key: String,
 }
 
+#[derive(Default)]
 pub struct MockNFTService {
-    nfts: HashMap<String, NFTMetadata>,
+    transfer_policies: Mutex<HashMap<String, TransferPolicy>>,
+}
+
+/// On-chain lock timestamp for a transfer policy: `None` for freely transferable
+/// tokens, `Some(0)` for tokens locked at mint (soulbound), otherwise the unix
+/// time from which the contract rejects transfers.
+fn lock_timestamp(policy: &TransferPolicy) -> Option<i64> {
+    match policy {
+        TransferPolicy::Transferable => None,
+        TransferPolicy::TransferableUntil(lock_date) => Some(lock_date.timestamp()),
+        TransferPolicy::Soulbound => Some(0),
+    }
 }
 
 impl EthereumNFTService {
@@ -38,8 +52,9 @@ impl EthereumNFTService {
     }
 
     // Implementation for Ethereum NFT minting using web3.rs or similar
-    async fn mint_ethereum_nft(&self, metadata_uri: String, to_wallet: &str) -> Result<String, HKDError> {
-        // This would contain actual Ethereum smart contract interactions
+    async fn mint_ethereum_nft(&self, _metadata_uri: String, _to_wallet: &str, _lock_timestamp: Option<i64>) -> Result<String, HKDError> {
+        // This would contain actual Ethereum smart contract interactions,
+        // calling `safeMintLocked` (ERC-5192) instead of `safeMint` when a lock is set
         // For now, return a mock transaction hash
         Ok(format!("0x{:064x}", rand::random::<u128>()))
     }
@@ -47,32 +62,32 @@ impl EthereumNFTService {
 
 #[async_trait::async_trait]
 impl NFTMinter for EthereumNFTService {
-    async fn mint_ticket_nft(&self, ticket: &Ticket, event: &Event, metadata: &NFTMetadata) -> Result<String, HKDError> {
+    async fn mint_ticket_nft(&self, ticket: &Ticket, _event: &Event, metadata: &NFTMetadata, transfer_policy: &TransferPolicy) -> Result<String, HKDError> {
         let metadata_uri = self.pin_metadata_to_ipfs(metadata).await?;
-        let transaction_hash = self.mint_ethereum_nft(metadata_uri, &ticket.owner_wallet).await?;
+        let transaction_hash = self.mint_ethereum_nft(metadata_uri, &ticket.owner_wallet, lock_timestamp(transfer_policy)).await?;
         
         // Store the token ID mapping
         Ok(transaction_hash)
     }
 
-    async fn transfer_nft(&self, token_id: &str, to_wallet: &str) -> Result<String, HKDError> {
+    async fn transfer_nft(&self, _token_id: &str, _to_wallet: &str) -> Result<String, HKDError> {
         // Implement NFT transfer logic
         Ok(format!("0x{:064x}", rand::random::<u128>()))
     }
 
-    async fn burn_nft(&self, token_id: &str) -> Result<String, HKDError> {
+    async fn burn_nft(&self, _token_id: &str) -> Result<String, HKDError> {
         // Implement NFT burn logic
         Ok(format!("0x{:064x}", rand::random::<u128>()))
     }
 
-    async fn get_nft_metadata(&self, token_id: &str) -> Result<NFTMetadata, HKDError> {
+    async fn get_nft_metadata(&self, _token_id: &str) -> Result<NFTMetadata, HKDError> {
         // Implement metadata retrieval
         Err(HKDError::ExternalApiError("Not implemented".to_string()))
     }
 }
 
 impl EthereumNFTService {
-    async fn pin_metadata_to_ipfs(&self, metadata: &NFTMetadata) -> Result<String, HKDError> {
+    async fn pin_metadata_to_ipfs(&self, _metadata: &NFTMetadata) -> Result<String, HKDError> {
         // Implementation for pinning metadata to IPFS via Pinata or similar
         // Return the IPFS CID
         Ok(format!("ipfs://{}", uuid::Uuid::new_v4()))
@@ -81,21 +96,26 @@ impl EthereumNFTService {
 
 impl MockNFTService {
     pub fn new() -> Self {
-        Self {
-            nfts: HashMap::new(),
-        }
+        Self::default()
     }
 }
 
 #[async_trait::async_trait]
 impl NFTMinter for MockNFTService {
-    async fn mint_ticket_nft(&self, ticket: &Ticket, event: &Event, metadata: &NFTMetadata) -> Result<String, HKDError> {
+    async fn mint_ticket_nft(&self, _ticket: &Ticket, _event: &Event, _metadata: &NFTMetadata, transfer_policy: &TransferPolicy) -> Result<String, HKDError> {
         let token_id = uuid::Uuid::new_v4().to_string();
         // In real implementation, we'd store this mapping
+        self.transfer_policies.lock().unwrap().insert(token_id.clone(), transfer_policy.clone());
         Ok(token_id)
     }
 
-    async fn transfer_nft(&self, token_id: &str, to_wallet: &str) -> Result<String, HKDError> {
+    async fn transfer_nft(&self, token_id: &str, _to_wallet: &str) -> Result<String, HKDError> {
+        // Mirror the on-chain lock so soulbound tokens can't move in tests either
+        if let Some(policy) = self.transfer_policies.lock().unwrap().get(token_id) {
+            if !policy.allows_transfer_at(chrono::Utc::now()) {
+                return Err(HKDError::TransferNotAllowed);
+            }
+        }
         Ok(token_id.to_string())
     }
 
@@ -103,7 +123,46 @@ impl NFTMinter for MockNFTService {
         Ok(token_id.to_string())
     }
 
-    async fn get_nft_metadata(&self, token_id: &str) -> Result<NFTMetadata, HKDError> {
+    async fn get_nft_metadata(&self, _token_id: &str) -> Result<NFTMetadata, HKDError> {
         Err(HKDError::ExternalApiError("Not implemented".to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use chrono::{Duration, TimeZone, Utc};
+
+    #[test]
+    fn lock_timestamps_follow_the_transfer_policy() {
+        let lock = Utc.with_ymd_and_hms(2030, 6, 13, 0, 0, 0).unwrap();
+        assert_eq!(lock_timestamp(&TransferPolicy::Transferable), None);
+        assert_eq!(lock_timestamp(&TransferPolicy::TransferableUntil(lock)), Some(lock.timestamp()));
+        assert_eq!(lock_timestamp(&TransferPolicy::Soulbound), Some(0));
+    }
+
+    #[tokio::test]
+    async fn mock_tokens_refuse_transfers_once_locked() {
+        let event = fixtures::event();
+        let ticket = fixtures::ticket(&event);
+        let metadata = NFTMetadata {
+            name: event.title.clone(),
+            description: event.description.clone(),
+            image: String::new(),
+            animation_url: None,
+            attributes: Vec::new(),
+            external_url: None,
+        };
+        let minter = MockNFTService::default();
+        let past = Utc::now() - Duration::days(1);
+
+        let open = minter.mint_ticket_nft(&ticket, &event, &metadata, &TransferPolicy::Transferable).await.unwrap();
+        let locked = minter.mint_ticket_nft(&ticket, &event, &metadata, &TransferPolicy::TransferableUntil(past)).await.unwrap();
+        let soulbound = minter.mint_ticket_nft(&ticket, &event, &metadata, &TransferPolicy::Soulbound).await.unwrap();
+
+        assert!(minter.transfer_nft(&open, "wallet:friend").await.is_ok());
+        assert!(matches!(minter.transfer_nft(&locked, "wallet:friend").await, Err(HKDError::TransferNotAllowed)));
+        assert!(matches!(minter.transfer_nft(&soulbound, "wallet:friend").await, Err(HKDError::TransferNotAllowed)));
+    }
+}
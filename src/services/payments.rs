@@ -0,0 +1,257 @@
+// src/services/payments.rs
+use crate::models::ticketing::PaymentMethod;
+use crate::error::HKDError;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A charge for a purchase on a rail other than the HKD stablecoin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentRequest {
+    pub method: PaymentMethod,
+    pub amount: Decimal,
+    pub currency: String,
+    pub payer: String, // Wallet or customer reference
+    pub reference: String,
+}
+
+/// A provider notification, normalized across providers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaymentWebhookEvent {
+    Captured { payment_id: String },
+    Refunded { payment_id: String }, // Refunded in full on the provider's side
+    Disputed { payment_id: String }, // Chargeback or reversal
+    Ignored { kind: String },
+}
+
+/// Card, FPS and third-party stablecoin payments. Funds are authorized before
+/// tickets are issued and captured once they exist.
+#[async_trait::async_trait]
+pub trait PaymentProvider {
+    fn methods(&self) -> Vec<PaymentMethod>;
+    /// Reserves the funds, returning the provider's payment id.
+    async fn authorize(&self, request: &PaymentRequest) -> Result<String, HKDError>;
+    async fn capture(&self, payment_id: &str) -> Result<(), HKDError>;
+    /// Releases an authorization that won't be captured.
+    async fn void(&self, payment_id: &str) -> Result<(), HKDError>;
+    /// Returns `amount` of a captured payment to where it came from, returning the refund id.
+    async fn refund(&self, payment_id: &str, amount: Decimal) -> Result<String, HKDError>;
+    /// Verifies and parses a delivery. Header names must be lowercase.
+    fn parse_webhook(&self, headers: &HashMap<String, String>, body: &[u8]) -> Result<PaymentWebhookEvent, HKDError>;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FakePaymentState {
+    Authorized,
+    Captured,
+    Voided,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FakePayment {
+    pub request: PaymentRequest,
+    pub state: FakePaymentState,
+    pub refunded: Decimal,
+}
+
+/// In-memory provider for local runs and tests. Webhooks are unsigned JSON:
+/// `{"type": "payment.refunded", "payment_id": "fake_pay_..."}`.
+pub struct FakePaymentProvider {
+    methods: Vec<PaymentMethod>,
+    decline_reason: Option<String>,
+    payments: Mutex<HashMap<String, FakePayment>>,
+}
+
+impl FakePaymentProvider {
+    pub fn new(methods: Vec<PaymentMethod>) -> Self {
+        Self {
+            methods,
+            decline_reason: None,
+            payments: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Declines every authorization with `reason`.
+    pub fn declining(mut self, reason: &str) -> Self {
+        self.decline_reason = Some(reason.to_string());
+        self
+    }
+
+    pub fn payment(&self, payment_id: &str) -> Option<FakePayment> {
+        self.payments.lock().unwrap().get(payment_id).cloned()
+    }
+
+    fn update<T>(&self, payment_id: &str, apply: impl FnOnce(&mut FakePayment) -> Result<T, HKDError>) -> Result<T, HKDError> {
+        let mut payments = self.payments.lock().unwrap();
+        let payment = payments.get_mut(payment_id)
+            .ok_or_else(|| HKDError::PaymentFailed(format!("Unknown payment {}", payment_id)))?;
+        apply(payment)
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentProvider for FakePaymentProvider {
+    fn methods(&self) -> Vec<PaymentMethod> {
+        self.methods.clone()
+    }
+
+    async fn authorize(&self, request: &PaymentRequest) -> Result<String, HKDError> {
+        if let Some(reason) = &self.decline_reason {
+            return Err(HKDError::PaymentDeclined(reason.clone()));
+        }
+        let payment_id = format!("fake_pay_{}", uuid::Uuid::new_v4().simple());
+        self.payments.lock().unwrap().insert(payment_id.clone(), FakePayment {
+            request: request.clone(),
+            state: FakePaymentState::Authorized,
+            refunded: Decimal::ZERO,
+        });
+        Ok(payment_id)
+    }
+
+    async fn capture(&self, payment_id: &str) -> Result<(), HKDError> {
+        self.update(payment_id, |payment| match payment.state {
+            FakePaymentState::Authorized => {
+                payment.state = FakePaymentState::Captured;
+                Ok(())
+            }
+            ref state => Err(HKDError::PaymentFailed(format!("Can't capture a {:?} payment", state))),
+        })
+    }
+
+    async fn void(&self, payment_id: &str) -> Result<(), HKDError> {
+        self.update(payment_id, |payment| match payment.state {
+            FakePaymentState::Authorized => {
+                payment.state = FakePaymentState::Voided;
+                Ok(())
+            }
+            ref state => Err(HKDError::PaymentFailed(format!("Can't void a {:?} payment", state))),
+        })
+    }
+
+    async fn refund(&self, payment_id: &str, amount: Decimal) -> Result<String, HKDError> {
+        self.update(payment_id, |payment| {
+            if payment.state != FakePaymentState::Captured {
+                return Err(HKDError::PaymentFailed(format!("Can't refund a {:?} payment", payment.state)));
+            }
+            if payment.refunded + amount > payment.request.amount {
+                return Err(HKDError::PaymentFailed("Refund exceeds the amount captured".to_string()));
+            }
+            payment.refunded += amount;
+            Ok(format!("fake_refund_{}", uuid::Uuid::new_v4().simple()))
+        })
+    }
+
+    fn parse_webhook(&self, _headers: &HashMap<String, String>, body: &[u8]) -> Result<PaymentWebhookEvent, HKDError> {
+        let webhook: FakeWebhook = serde_json::from_slice(body)
+            .map_err(|e| HKDError::PaymentFailed(format!("Invalid payment webhook payload: {}", e)))?;
+        Ok(match webhook.kind.as_str() {
+            "payment.captured" => PaymentWebhookEvent::Captured { payment_id: webhook.payment_id },
+            "payment.refunded" => PaymentWebhookEvent::Refunded { payment_id: webhook.payment_id },
+            "payment.disputed" => PaymentWebhookEvent::Disputed { payment_id: webhook.payment_id },
+            _ => PaymentWebhookEvent::Ignored { kind: webhook.kind },
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FakeWebhook {
+    #[serde(rename = "type")]
+    kind: String,
+    payment_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card_provider() -> FakePaymentProvider {
+        FakePaymentProvider::new(vec![PaymentMethod::Card, PaymentMethod::Fps])
+    }
+
+    fn request(amount: i64) -> PaymentRequest {
+        PaymentRequest {
+            method: PaymentMethod::Card,
+            amount: Decimal::new(amount, 2),
+            currency: "USD".to_string(),
+            payer: "wallet:buyer".to_string(),
+            reference: "Ticket purchase for Harbour Lights Festival".to_string(),
+        }
+    }
+
+    fn is_failure<T>(result: Result<T, HKDError>) -> bool {
+        matches!(result, Err(HKDError::PaymentFailed(_)))
+    }
+
+    #[tokio::test]
+    async fn authorized_payments_are_captured_then_refunded_up_to_the_amount() {
+        let provider = card_provider();
+        let payment_id = provider.authorize(&request(22528)).await.unwrap();
+        assert_eq!(provider.payment(&payment_id).unwrap().state, FakePaymentState::Authorized);
+
+        provider.capture(&payment_id).await.unwrap();
+        assert_eq!(provider.payment(&payment_id).unwrap().state, FakePaymentState::Captured);
+
+        provider.refund(&payment_id, Decimal::new(11264, 2)).await.unwrap();
+        provider.refund(&payment_id, Decimal::new(11264, 2)).await.unwrap();
+        assert!(is_failure(provider.refund(&payment_id, Decimal::new(1, 2)).await));
+        assert_eq!(provider.payment(&payment_id).unwrap().refunded, Decimal::new(22528, 2));
+    }
+
+    #[tokio::test]
+    async fn declined_authorizations_record_nothing() {
+        let provider = card_provider().declining("insufficient funds");
+        match provider.authorize(&request(1000)).await {
+            Err(HKDError::PaymentDeclined(reason)) => assert_eq!(reason, "insufficient funds"),
+            other => panic!("expected a decline, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn captures_need_a_live_authorization() {
+        let provider = card_provider();
+        assert!(is_failure(provider.capture("fake_pay_unknown").await));
+
+        let payment_id = provider.authorize(&request(1000)).await.unwrap();
+        provider.void(&payment_id).await.unwrap();
+        assert!(is_failure(provider.capture(&payment_id).await));
+        assert!(is_failure(provider.void(&payment_id).await));
+    }
+
+    #[tokio::test]
+    async fn only_captured_payments_are_refunded() {
+        let provider = card_provider();
+        let payment_id = provider.authorize(&request(1000)).await.unwrap();
+        assert!(is_failure(provider.refund(&payment_id, Decimal::new(1000, 2)).await));
+
+        provider.capture(&payment_id).await.unwrap();
+        assert!(is_failure(provider.capture(&payment_id).await));
+        assert!(is_failure(provider.void(&payment_id).await));
+    }
+
+    #[test]
+    fn providers_list_the_methods_they_route() {
+        assert_eq!(card_provider().methods(), vec![PaymentMethod::Card, PaymentMethod::Fps]);
+    }
+
+    #[test]
+    fn webhooks_are_normalized() {
+        let provider = card_provider();
+        let headers = HashMap::new();
+        let parse = |body: &str| provider.parse_webhook(&headers, body.as_bytes());
+
+        assert_eq!(
+            parse(r#"{"type": "payment.refunded", "payment_id": "fake_pay_1"}"#).unwrap(),
+            PaymentWebhookEvent::Refunded { payment_id: "fake_pay_1".to_string() }
+        );
+        assert_eq!(
+            parse(r#"{"type": "payment.disputed", "payment_id": "fake_pay_2"}"#).unwrap(),
+            PaymentWebhookEvent::Disputed { payment_id: "fake_pay_2".to_string() }
+        );
+        assert_eq!(
+            parse(r#"{"type": "payment.pending", "payment_id": "fake_pay_3"}"#).unwrap(),
+            PaymentWebhookEvent::Ignored { kind: "payment.pending".to_string() }
+        );
+        assert!(is_failure(parse("not json")));
+    }
+}
@@ -0,0 +1,409 @@
+// src/services/platform_http.rs
+use crate::error::HKDError;
+use crate::models::ticketing::EventPlatform;
+use rand::Rng;
+use reqwest::{header, Client, IntoUrl, RequestBuilder, Response, StatusCode};
+use std::sync::Mutex as StdMutex;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::{sleep, Instant};
+
+/// At most `requests` calls in any `period`.
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub period: Duration,
+}
+
+impl RateLimit {
+    pub fn per_second(requests: u32) -> Self {
+        Self { requests, period: Duration::from_secs(1) }
+    }
+
+    pub fn per_hour(requests: u32) -> Self {
+        Self { requests, period: Duration::from_secs(3600) }
+    }
+
+    pub fn per_day(requests: u32) -> Self {
+        Self { requests, period: Duration::from_secs(86_400) }
+    }
+}
+
+/// Retries of a single request on 429, 5xx, timeouts and connection failures.
+/// Anything still failing after this is left to the sync outbox.
+#[derive(Debug, Clone)]
+pub struct BackoffPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for BackoffPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl BackoffPolicy {
+    /// Exponential backoff with full jitter: a random delay up to base * 2^retry.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let ceiling = self.base_delay
+            .saturating_mul(1 << retry.min(16))
+            .min(self.max_delay);
+        let millis = rand::thread_rng().gen_range(0..=ceiling.as_millis() as u64);
+        Duration::from_millis(millis)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    pub failure_threshold: u32,
+    pub open_for: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_for: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ResilienceConfig {
+    pub rate_limits: Vec<RateLimit>,
+    pub backoff: BackoffPolicy,
+    pub circuit_breaker: CircuitBreakerConfig,
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+}
+
+impl ResilienceConfig {
+    /// Defaults following each platform's documented quotas.
+    pub fn for_platform(platform: &EventPlatform) -> Self {
+        let rate_limits = match platform {
+            // 2,000 calls an hour and 48,000 a day per OAuth token
+            EventPlatform::Eventbrite => vec![RateLimit::per_hour(2_000), RateLimit::per_day(48_000)],
+            // 5 requests a second and 5,000 a day per API key
+            EventPlatform::Ticketmaster => vec![RateLimit::per_second(5), RateLimit::per_day(5_000)],
+            // Quotas are set per account; stay well under the standard tier
+            EventPlatform::Cvent => vec![RateLimit::per_second(10)],
+            EventPlatform::Internal => Vec::new(),
+        };
+
+        Self {
+            rate_limits,
+            backoff: BackoffPolicy::default(),
+            circuit_breaker: CircuitBreakerConfig::default(),
+            timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Token buckets, one per quota window; a request needs a token from each.
+struct RateLimiter {
+    buckets: Mutex<Vec<TokenBucket>>,
+}
+
+struct TokenBucket {
+    limit: RateLimit,
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        let rate = self.limit.requests as f64 / self.limit.period.as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate).min(self.limit.requests as f64);
+        self.refilled_at = now;
+    }
+
+    fn wait_for_token(&self) -> Duration {
+        if self.tokens >= 1.0 {
+            return Duration::ZERO;
+        }
+        let rate = self.limit.requests as f64 / self.limit.period.as_secs_f64();
+        Duration::from_secs_f64((1.0 - self.tokens) / rate)
+    }
+}
+
+impl RateLimiter {
+    fn new(limits: &[RateLimit]) -> Self {
+        let now = Instant::now();
+        let buckets = limits.iter()
+            .filter(|limit| limit.requests > 0)
+            .map(|limit| TokenBucket {
+                limit: limit.clone(),
+                tokens: limit.requests as f64,
+                refilled_at: now,
+            })
+            .collect();
+        Self { buckets: Mutex::new(buckets) }
+    }
+
+    async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut buckets = self.buckets.lock().await;
+                let now = Instant::now();
+                for bucket in buckets.iter_mut() {
+                    bucket.refill(now);
+                }
+
+                let wait = buckets.iter()
+                    .map(TokenBucket::wait_for_token)
+                    .max()
+                    .unwrap_or(Duration::ZERO);
+                if wait.is_zero() {
+                    for bucket in buckets.iter_mut() {
+                        bucket.tokens -= 1.0;
+                    }
+                    return;
+                }
+                wait
+            };
+            sleep(wait).await;
+        }
+    }
+}
+
+#[derive(Debug)]
+enum CircuitState {
+    Closed { consecutive_failures: u32 },
+    Open { until: Instant },
+    // A single probe request is in flight
+    HalfOpen,
+}
+
+/// How a whole `send`, retries included, went as far as the breaker is concerned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CallOutcome {
+    Succeeded, // The platform answered, even if it rejected the request
+    Failed,
+    Inconclusive, // Throttled, or cancelled before it finished
+}
+
+/// Stops calling a platform after repeated failures, letting one probe
+/// through once `open_for` has passed.
+struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: StdMutex<CircuitState>,
+}
+
+impl CircuitBreaker {
+    fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: StdMutex::new(CircuitState::Closed { consecutive_failures: 0 }),
+        }
+    }
+
+    /// Lets a call through unless the circuit is open or a probe is already in flight.
+    fn permit(&self) -> Option<CircuitPermit<'_>> {
+        let mut state = self.state.lock().unwrap();
+        let probe = match *state {
+            CircuitState::Closed { .. } => false,
+            CircuitState::Open { until } if Instant::now() >= until => {
+                *state = CircuitState::HalfOpen;
+                true
+            }
+            CircuitState::Open { .. } | CircuitState::HalfOpen => return None,
+        };
+        Some(CircuitPermit { breaker: self, probe, settled: false })
+    }
+
+    fn record(&self, outcome: CallOutcome) {
+        let mut state = self.state.lock().unwrap();
+        match outcome {
+            CallOutcome::Succeeded => *state = CircuitState::Closed { consecutive_failures: 0 },
+            CallOutcome::Failed => {
+                let failures = match *state {
+                    CircuitState::Closed { consecutive_failures } => consecutive_failures + 1,
+                    CircuitState::Open { .. } | CircuitState::HalfOpen => self.config.failure_threshold,
+                };
+                *state = if failures >= self.config.failure_threshold {
+                    CircuitState::Open { until: Instant::now() + self.config.open_for }
+                } else {
+                    CircuitState::Closed { consecutive_failures: failures }
+                };
+            }
+            // Says nothing about the platform's health; the next call probes again
+            CallOutcome::Inconclusive => {
+                if matches!(*state, CircuitState::HalfOpen) {
+                    *state = CircuitState::Open { until: Instant::now() };
+                }
+            }
+        }
+    }
+}
+
+/// A call's pass through the breaker. A probe dropped without settling, because
+/// its future was cancelled, counts as inconclusive so the circuit can't stay
+/// half-open.
+struct CircuitPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    settled: bool,
+}
+
+impl CircuitPermit<'_> {
+    fn settle(mut self, outcome: CallOutcome) {
+        self.settled = true;
+        self.breaker.record(outcome);
+    }
+}
+
+impl Drop for CircuitPermit<'_> {
+    fn drop(&mut self) {
+        if self.probe && !self.settled {
+            self.breaker.record(CallOutcome::Inconclusive);
+        }
+    }
+}
+
+/// HTTP client shared by an external platform's API client: applies the
+/// platform's rate limits, retries transient failures, trips a circuit
+/// breaker and turns error responses into `HKDError::PlatformApiError`.
+pub struct PlatformHttp {
+    platform: EventPlatform,
+    client: Client,
+    rate_limiter: RateLimiter,
+    circuit_breaker: CircuitBreaker,
+    backoff: BackoffPolicy,
+}
+
+impl PlatformHttp {
+    pub fn new(platform: EventPlatform) -> Self {
+        let config = ResilienceConfig::for_platform(&platform);
+        Self::with_config(platform, config)
+    }
+
+    pub fn with_config(platform: EventPlatform, config: ResilienceConfig) -> Self {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .build()
+            // Only fails when the TLS backend can't start, and a client without timeouts could hang forever
+            .expect("building the platform HTTP client");
+
+        Self {
+            platform,
+            client,
+            rate_limiter: RateLimiter::new(&config.rate_limits),
+            circuit_breaker: CircuitBreaker::new(config.circuit_breaker),
+            backoff: config.backoff,
+        }
+    }
+
+    pub fn get<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.get(url)
+    }
+
+    pub fn post<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.post(url)
+    }
+
+    pub fn put<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.put(url)
+    }
+
+    pub fn patch<U: IntoUrl>(&self, url: U) -> RequestBuilder {
+        self.client.patch(url)
+    }
+
+    /// Sends the request, returning the response only if it is a 2xx.
+    /// Retries count as one call for the circuit breaker.
+    pub async fn send(&self, request: RequestBuilder) -> Result<Response, HKDError> {
+        let permit = self.circuit_breaker.permit()
+            .ok_or_else(|| HKDError::PlatformUnavailable(self.platform.clone()))?;
+        let (outcome, result) = self.send_with_retries(request).await;
+        permit.settle(outcome);
+        result
+    }
+
+    async fn send_with_retries(&self, request: RequestBuilder) -> (CallOutcome, Result<Response, HKDError>) {
+        let mut retry = 0;
+        loop {
+            self.rate_limiter.acquire().await;
+
+            // Bodies here are always buffered JSON or forms, so cloning cannot fail
+            let attempt = match request.try_clone() {
+                Some(attempt) => attempt,
+                None => {
+                    let error = HKDError::ExternalApiError("Request body cannot be retried".to_string());
+                    return (CallOutcome::Inconclusive, Err(error));
+                }
+            };
+
+            let delay = match attempt.send().await {
+                Ok(response) if response.status().is_success() => {
+                    return (CallOutcome::Succeeded, Ok(response));
+                }
+                Ok(response) => {
+                    let status = response.status();
+                    if !is_transient(status) {
+                        // The platform is up; the request itself was rejected
+                        return (CallOutcome::Succeeded, Err(self.api_error(response).await));
+                    }
+
+                    // Throttling means we're calling too often, not that the platform is down
+                    let outcome = if status == StatusCode::TOO_MANY_REQUESTS {
+                        CallOutcome::Inconclusive
+                    } else {
+                        CallOutcome::Failed
+                    };
+                    let delay = retry_after(&response).unwrap_or_else(|| self.backoff.delay(retry));
+                    if retry >= self.backoff.max_retries || delay > self.backoff.max_delay {
+                        return (outcome, Err(self.api_error(response).await));
+                    }
+                    log::warn!("{:?} API returned {}, retrying in {:?}", self.platform, status, delay);
+                    delay
+                }
+                Err(err) => {
+                    if !(err.is_timeout() || err.is_connect()) || retry >= self.backoff.max_retries {
+                        return (CallOutcome::Failed, Err(err.into()));
+                    }
+                    let delay = self.backoff.delay(retry);
+                    log::warn!("{:?} API request failed ({}), retrying in {:?}", self.platform, err, delay);
+                    delay
+                }
+            };
+
+            sleep(delay).await;
+            retry += 1;
+        }
+    }
+
+    async fn api_error(&self, response: Response) -> HKDError {
+        let status = response.status().as_u16();
+        let body = response.text().await.unwrap_or_default();
+        HKDError::PlatformApiError {
+            platform: self.platform.clone(),
+            status,
+            body,
+        }
+    }
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// `Retry-After` as either delay-seconds or an HTTP date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(wait.to_std().unwrap_or(Duration::ZERO))
+}
@@ -0,0 +1,233 @@
+// src/services/schedule.rs
+use crate::models::ticketing::*;
+use crate::services::event_validation::Violation;
+use crate::services::sessions::sales_deadline;
+use chrono::{DateTime, Duration, Offset, Utc};
+use chrono_tz::Tz;
+
+// Festivals run for days, but anything longer is almost certainly a typo
+const MAX_EVENT_DURATION_DAYS: i64 = 31;
+
+// (ISO country code, common name, IANA zone) for venues we host events at
+pub(crate) const VENUE_TIMEZONES: &[(&str, &str, Tz)] = &[
+    ("HK", "Hong Kong", Tz::Asia__Hong_Kong),
+    ("MO", "Macau", Tz::Asia__Macau),
+    ("CN", "China", Tz::Asia__Shanghai),
+    ("TW", "Taiwan", Tz::Asia__Taipei),
+    ("JP", "Japan", Tz::Asia__Tokyo),
+    ("KR", "South Korea", Tz::Asia__Seoul),
+    ("SG", "Singapore", Tz::Asia__Singapore),
+    ("TH", "Thailand", Tz::Asia__Bangkok),
+    ("GB", "United Kingdom", Tz::Europe__London),
+    ("DE", "Germany", Tz::Europe__Berlin),
+    ("FR", "France", Tz::Europe__Paris),
+];
+
+// Countries spanning several zones are resolved by city
+const CITY_TIMEZONES: &[(&str, Tz)] = &[
+    ("New York", Tz::America__New_York),
+    ("Chicago", Tz::America__Chicago),
+    ("Los Angeles", Tz::America__Los_Angeles),
+    ("San Francisco", Tz::America__Los_Angeles),
+    ("Sydney", Tz::Australia__Sydney),
+    ("Melbourne", Tz::Australia__Melbourne),
+    ("Brisbane", Tz::Australia__Brisbane),
+    ("Perth", Tz::Australia__Perth),
+];
+
+/// Best guess at a venue's zone from its city, then its country.
+pub fn infer_timezone(venue: &Venue) -> Option<Tz> {
+    if let Some((_, zone)) = CITY_TIMEZONES.iter().find(|(city, _)| city.eq_ignore_ascii_case(&venue.city)) {
+        return Some(*zone);
+    }
+    VENUE_TIMEZONES.iter()
+        .find(|(code, name, _)| code.eq_ignore_ascii_case(&venue.country) || name.eq_ignore_ascii_case(&venue.country))
+        .map(|(_, _, zone)| *zone)
+}
+
+pub fn parse_timezone(name: &str) -> Option<Tz> {
+    name.parse().ok()
+}
+
+pub fn local_times(event: &Event) -> LocalTimes {
+    let timezone = event.timezone();
+    let local = |at: DateTime<Utc>| {
+        let at = at.with_timezone(&timezone);
+        at.with_timezone(&at.offset().fix())
+    };
+    LocalTimes {
+        timezone,
+        event_date: local(event.event_date),
+        door_time: local(event.door_time),
+        end_date: local(event.end_time()),
+    }
+}
+
+/// Doors open before the start, the end follows the start, and ticket sales
+/// close by the time the event (or the last session a ticket admits to) begins.
+pub fn schedule_violations(event: &Event) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    if event.door_time > event.event_date {
+        violations.push(Violation::new("door_time", "doors must open before the event starts"));
+    }
+    if let Some(end_date) = event.end_date {
+        if end_date <= event.event_date {
+            violations.push(Violation::new("end_date", "the event must end after it starts"));
+        } else if end_date - event.event_date > Duration::days(MAX_EVENT_DURATION_DAYS) {
+            violations.push(Violation::new(
+                "end_date",
+                format!("events can't run longer than {} days", MAX_EVENT_DURATION_DAYS),
+            ));
+        }
+    }
+    for (index, ticket_type) in event.ticket_types.iter().enumerate() {
+        if ticket_type.sales_start >= ticket_type.sales_end {
+            violations.push(Violation::new(
+                format!("ticket_types[{}].sales_start", index),
+                "sales must start before they end",
+            ));
+        }
+        if ticket_type.sales_end > sales_deadline(event, ticket_type) {
+            violations.push(Violation::new(
+                format!("ticket_types[{}].sales_end", index),
+                "sales must close by the time the event starts",
+            ));
+        }
+    }
+    violations
+}
+
+/// NFT traits with the event's local date, zone and, for multi-day events, last day.
+pub fn schedule_attributes(event: &Event) -> Vec<NFTAttribute> {
+    let timezone = event.timezone();
+    let start = event.event_date.with_timezone(&timezone);
+    let mut attributes = vec![
+        NFTAttribute {
+            trait_type: "Date".to_string(),
+            value: start.format("%Y-%m-%d %H:%M %Z").to_string(),
+        },
+        NFTAttribute {
+            trait_type: "Timezone".to_string(),
+            value: timezone.name().to_string(),
+        },
+    ];
+
+    let end = event.end_time().with_timezone(&timezone);
+    if end.date_naive() != start.date_naive() {
+        attributes.push(NFTAttribute {
+            trait_type: "Ends".to_string(),
+            value: end.format("%Y-%m-%d %H:%M %Z").to_string(),
+        });
+    }
+    attributes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use chrono::TimeZone;
+
+    fn rejected(event: &Event) -> bool {
+        !schedule_violations(event).is_empty()
+    }
+
+    #[test]
+    fn venue_zones_are_inferred_from_city_then_country() {
+        let mut venue = fixtures::event().venue;
+        assert_eq!(infer_timezone(&venue), Some(Tz::Asia__Hong_Kong));
+
+        venue.city = "Chicago".to_string();
+        venue.country = "US".to_string();
+        assert_eq!(infer_timezone(&venue), Some(Tz::America__Chicago));
+
+        venue.city = "Osaka".to_string();
+        venue.country = "jp".to_string();
+        assert_eq!(infer_timezone(&venue), Some(Tz::Asia__Tokyo));
+
+        venue.city = "Atlantis".to_string();
+        venue.country = "Nowhere".to_string();
+        assert_eq!(infer_timezone(&venue), None);
+    }
+
+    #[test]
+    fn local_times_carry_the_venue_offset() {
+        let local = local_times(&fixtures::event());
+        assert_eq!(local.timezone, Tz::Asia__Hong_Kong);
+        assert_eq!(local.event_date.to_rfc3339(), "2030-06-14T20:00:00+08:00");
+        assert_eq!(local.door_time.to_rfc3339(), "2030-06-14T19:00:00+08:00");
+    }
+
+    #[test]
+    fn local_times_follow_daylight_saving() {
+        let mut event = fixtures::event();
+        event.timezone = Some(Tz::Europe__London);
+
+        event.event_date = Utc.with_ymd_and_hms(2030, 7, 1, 18, 0, 0).unwrap();
+        assert_eq!(local_times(&event).event_date.to_rfc3339(), "2030-07-01T19:00:00+01:00");
+
+        event.event_date = Utc.with_ymd_and_hms(2030, 12, 1, 18, 0, 0).unwrap();
+        assert_eq!(local_times(&event).event_date.to_rfc3339(), "2030-12-01T18:00:00+00:00");
+    }
+
+    #[test]
+    fn an_event_zone_overrides_the_venue_and_utc_is_the_fallback() {
+        let mut event = fixtures::event();
+        event.timezone = Some(Tz::Asia__Tokyo);
+        assert_eq!(event.timezone(), Tz::Asia__Tokyo);
+
+        event.timezone = None;
+        event.venue.timezone = None;
+        assert_eq!(event.timezone(), Tz::UTC);
+    }
+
+    #[test]
+    fn doors_open_before_the_start_and_the_end_follows_it() {
+        let mut event = fixtures::event();
+        assert!(schedule_violations(&event).is_empty());
+
+        event.door_time = event.event_date + Duration::minutes(30);
+        assert!(rejected(&event));
+
+        event.door_time = event.event_date;
+        event.end_date = Some(event.event_date - Duration::hours(1));
+        assert!(rejected(&event));
+    }
+
+    #[test]
+    fn events_can_run_for_days_but_not_months() {
+        let mut event = fixtures::event();
+        event.end_date = Some(event.event_date + Duration::days(3));
+        assert!(schedule_violations(&event).is_empty());
+
+        event.end_date = Some(event.event_date + Duration::days(40));
+        assert!(rejected(&event));
+    }
+
+    #[test]
+    fn ticket_sales_close_by_the_start() {
+        let mut event = fixtures::event();
+        event.ticket_types[0].sales_end = event.event_date + Duration::hours(1);
+        let violations = schedule_violations(&event);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].field, "ticket_types[0].sales_end");
+
+        event.ticket_types[0].sales_end = event.event_date;
+        event.ticket_types[0].sales_start = event.event_date;
+        assert!(rejected(&event));
+    }
+
+    #[test]
+    fn nft_attributes_show_local_dates_and_the_last_day() {
+        let mut event = fixtures::event();
+        let single_day = schedule_attributes(&event);
+        assert_eq!(single_day[0].value, "2030-06-14 20:00 HKT");
+        assert_eq!(single_day[1].value, "Asia/Hong_Kong");
+        assert_eq!(single_day.len(), 2);
+
+        event.end_date = Some(event.event_date + Duration::days(2));
+        let multi_day = schedule_attributes(&event);
+        assert_eq!(multi_day[2].trait_type, "Ends");
+        assert_eq!(multi_day[2].value, "2030-06-16 20:00 HKT");
+    }
+}
@@ -0,0 +1,296 @@
+// src/services/seating.rs
+use crate::models::ticketing::*;
+use crate::error::HKDError;
+use std::collections::HashSet;
+
+/// Picks one seat per ticket for a reserved seating ticket type. General
+/// admission types get no seats.
+pub fn select_seats(
+    event: &Event,
+    ticket_type: &TicketType,
+    selection: &SeatSelection,
+    quantity: u32,
+) -> Result<Vec<SeatAssignment>, HKDError> {
+    let zone = match &ticket_type.price_zone {
+        Some(zone) => zone,
+        None => return Ok(Vec::new()),
+    };
+    let seat_map = event.venue.seat_map.as_ref().ok_or_else(|| {
+        HKDError::InvalidSeatSelection(format!("{} has no seat map", event.venue.name))
+    })?;
+
+    match selection {
+        SeatSelection::Specific(seats) => specific_seats(event, seat_map, zone, seats, quantity),
+        SeatSelection::BestAvailable { accessibility } => {
+            best_available(event, seat_map, zone, accessibility, quantity as usize)
+        }
+    }
+}
+
+/// Unsold seats in `zone`, best first.
+pub fn open_seats(event: &Event, zone: &str) -> Vec<SeatAssignment> {
+    match &event.venue.seat_map {
+        Some(seat_map) => ranked_open_seats(event, seat_map, zone, &[]),
+        None => Vec::new(),
+    }
+}
+
+/// NFT traits describing where the ticket holder sits.
+pub fn seat_attributes(seat: &SeatAssignment) -> Vec<NFTAttribute> {
+    [("Section", &seat.section), ("Row", &seat.row), ("Seat", &seat.seat)]
+        .into_iter()
+        .map(|(trait_type, value)| NFTAttribute {
+            trait_type: trait_type.to_string(),
+            value: value.clone(),
+        })
+        .collect()
+}
+
+fn specific_seats(
+    event: &Event,
+    seat_map: &SeatMap,
+    zone: &str,
+    seats: &[SeatAssignment],
+    quantity: u32,
+) -> Result<Vec<SeatAssignment>, HKDError> {
+    if seats.len() != quantity as usize {
+        return Err(HKDError::InvalidSeatSelection(format!(
+            "{} seats chosen for {} tickets", seats.len(), quantity
+        )));
+    }
+
+    let mut chosen = HashSet::new();
+    for assignment in seats {
+        let seat = seat_map.seat(assignment).ok_or_else(|| {
+            HKDError::InvalidSeatSelection(format!("{} doesn't exist", assignment))
+        })?;
+        if seat.zone != zone {
+            return Err(HKDError::InvalidSeatSelection(format!("{} isn't in zone {}", assignment, zone)));
+        }
+        if event.sold_seats.contains(assignment) || !chosen.insert(assignment) {
+            return Err(HKDError::SeatUnavailable(assignment.to_string()));
+        }
+    }
+    Ok(seats.to_vec())
+}
+
+/// The most central block of adjacent seats in the best row that fits the
+/// party, otherwise the best individual seats wherever they are.
+fn best_available(
+    event: &Event,
+    seat_map: &SeatMap,
+    zone: &str,
+    accessibility: &[AccessibilityFeature],
+    quantity: usize,
+) -> Result<Vec<SeatAssignment>, HKDError> {
+    if quantity == 0 {
+        return Ok(Vec::new());
+    }
+
+    for section in &seat_map.sections {
+        for row in &section.rows {
+            let open: Vec<bool> = row.seats.iter()
+                .map(|seat| is_open(event, section, row, seat, zone, accessibility))
+                .collect();
+            if let Some(start) = central_block(&open, quantity) {
+                return Ok(row.seats[start..start + quantity].iter()
+                    .map(|seat| assignment(section, row, seat))
+                    .collect());
+            }
+        }
+    }
+
+    let seats = ranked_open_seats(event, seat_map, zone, accessibility);
+    if seats.len() < quantity {
+        return Err(HKDError::InsufficientTickets);
+    }
+    Ok(seats.into_iter().take(quantity).collect())
+}
+
+// Start of the all-open window of `size` seats whose middle is nearest the row's
+fn central_block(open: &[bool], size: usize) -> Option<usize> {
+    if size > open.len() {
+        return None;
+    }
+    let row_middle = open.len() - 1;
+    (0..=open.len() - size)
+        .filter(|&start| open[start..start + size].iter().all(|&open| open))
+        .min_by_key(|&start| (2 * start + size - 1).abs_diff(row_middle))
+}
+
+// Rows in map order, and within a row from the middle outwards
+fn ranked_open_seats(
+    event: &Event,
+    seat_map: &SeatMap,
+    zone: &str,
+    accessibility: &[AccessibilityFeature],
+) -> Vec<SeatAssignment> {
+    let mut seats = Vec::new();
+    for section in &seat_map.sections {
+        for row in &section.rows {
+            let row_middle = row.seats.len().saturating_sub(1);
+            let mut open: Vec<(usize, &Seat)> = row.seats.iter()
+                .enumerate()
+                .filter(|(_, seat)| is_open(event, section, row, seat, zone, accessibility))
+                .collect();
+            open.sort_by_key(|(index, _)| (2 * index).abs_diff(row_middle));
+            seats.extend(open.into_iter().map(|(_, seat)| assignment(section, row, seat)));
+        }
+    }
+    seats
+}
+
+fn is_open(
+    event: &Event,
+    section: &Section,
+    row: &SeatRow,
+    seat: &Seat,
+    zone: &str,
+    accessibility: &[AccessibilityFeature],
+) -> bool {
+    seat.zone == zone
+        && accessibility.iter().all(|feature| seat.accessibility.contains(feature))
+        && !event.sold_seats.contains(&assignment(section, row, seat))
+}
+
+fn assignment(section: &Section, row: &SeatRow, seat: &Seat) -> SeatAssignment {
+    SeatAssignment {
+        section: section.name.clone(),
+        row: row.label.clone(),
+        seat: seat.number.clone(),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use rust_decimal::Decimal;
+
+    fn seat(section: &str, row: &str, number: &str) -> SeatAssignment {
+        SeatAssignment { section: section.to_string(), row: row.to_string(), seat: number.to_string() }
+    }
+
+    fn row(label: &str, seats: usize, zone: &str) -> SeatRow {
+        SeatRow {
+            label: label.to_string(),
+            seats: (1..=seats)
+                .map(|number| Seat { number: number.to_string(), zone: zone.to_string(), accessibility: Vec::new() })
+                .collect(),
+        }
+    }
+
+    // Stalls rows A and B of six seats, with a wheelchair space at B1, and a
+    // balcony row in another zone.
+    fn seated_event() -> (Event, TicketType) {
+        let mut rows = vec![row("A", 6, "Stalls"), row("B", 6, "Stalls")];
+        rows[1].seats[0].accessibility = vec![AccessibilityFeature::WheelchairSpace];
+        let seat_map = SeatMap {
+            sections: vec![
+                Section { name: "Stalls".to_string(), rows },
+                Section { name: "Balcony".to_string(), rows: vec![row("C", 4, "Balcony")] },
+            ],
+        };
+
+        let mut event = fixtures::event();
+        event.venue.seat_map = Some(seat_map);
+        let mut ticket_type = fixtures::ticket_type("Stalls", Decimal::new(68000, 2), 12);
+        ticket_type.price_zone = Some("Stalls".to_string());
+        event.ticket_types = vec![ticket_type.clone()];
+        (event, ticket_type)
+    }
+
+    fn best_available() -> SeatSelection {
+        SeatSelection::BestAvailable { accessibility: Vec::new() }
+    }
+
+    #[test]
+    fn general_admission_gets_no_seats() {
+        let event = fixtures::event();
+        let seats = select_seats(&event, &event.ticket_types[0], &best_available(), 2).unwrap();
+        assert!(seats.is_empty());
+    }
+
+    #[test]
+    fn best_available_takes_the_central_block_of_the_front_row() {
+        let (event, ticket_type) = seated_event();
+        let seats = select_seats(&event, &ticket_type, &best_available(), 2).unwrap();
+        assert_eq!(seats, vec![seat("Stalls", "A", "3"), seat("Stalls", "A", "4")]);
+    }
+
+    #[test]
+    fn parties_move_back_a_row_rather_than_split() {
+        let (mut event, ticket_type) = seated_event();
+        event.sold_seats.insert(seat("Stalls", "A", "3"));
+        event.sold_seats.insert(seat("Stalls", "A", "4"));
+
+        let seats = select_seats(&event, &ticket_type, &best_available(), 3).unwrap();
+        assert!(seats.iter().all(|assigned| assigned.row == "B"));
+        assert_eq!(seats.len(), 3);
+    }
+
+    #[test]
+    fn without_a_block_the_best_single_seats_are_used() {
+        let (mut event, ticket_type) = seated_event();
+        for (row, number) in [("A", "2"), ("A", "4"), ("A", "6"), ("B", "2"), ("B", "4"), ("B", "6")] {
+            event.sold_seats.insert(seat("Stalls", row, number));
+        }
+
+        let seats = select_seats(&event, &ticket_type, &best_available(), 2).unwrap();
+        assert_eq!(seats, vec![seat("Stalls", "A", "3"), seat("Stalls", "A", "5")]);
+    }
+
+    #[test]
+    fn accessibility_requirements_limit_the_seats_offered() {
+        let (event, ticket_type) = seated_event();
+        let selection = SeatSelection::BestAvailable { accessibility: vec![AccessibilityFeature::WheelchairSpace] };
+
+        assert_eq!(select_seats(&event, &ticket_type, &selection, 1).unwrap(), vec![seat("Stalls", "B", "1")]);
+        assert!(matches!(
+            select_seats(&event, &ticket_type, &selection, 2),
+            Err(HKDError::InsufficientTickets)
+        ));
+    }
+
+    #[test]
+    fn specific_seats_must_exist_be_in_zone_and_be_free() {
+        let (mut event, ticket_type) = seated_event();
+        event.sold_seats.insert(seat("Stalls", "A", "1"));
+        let choose = |seats: Vec<SeatAssignment>| {
+            let quantity = seats.len() as u32;
+            select_seats(&event, &ticket_type, &SeatSelection::Specific(seats), quantity)
+        };
+
+        assert!(choose(vec![seat("Stalls", "A", "2"), seat("Stalls", "B", "5")]).is_ok());
+        assert!(matches!(choose(vec![seat("Stalls", "Z", "1")]), Err(HKDError::InvalidSeatSelection(_))));
+        assert!(matches!(choose(vec![seat("Balcony", "C", "1")]), Err(HKDError::InvalidSeatSelection(_))));
+        assert!(matches!(choose(vec![seat("Stalls", "A", "1")]), Err(HKDError::SeatUnavailable(_))));
+        assert!(matches!(
+            choose(vec![seat("Stalls", "A", "2"), seat("Stalls", "A", "2")]),
+            Err(HKDError::SeatUnavailable(_))
+        ));
+    }
+
+    #[test]
+    fn specific_seats_must_match_the_ticket_count() {
+        let (event, ticket_type) = seated_event();
+        let selection = SeatSelection::Specific(vec![seat("Stalls", "A", "2")]);
+        assert!(matches!(
+            select_seats(&event, &ticket_type, &selection, 2),
+            Err(HKDError::InvalidSeatSelection(_))
+        ));
+    }
+
+    #[test]
+    fn open_seats_skip_sold_seats_and_other_zones() {
+        let (mut event, _) = seated_event();
+        event.sold_seats.insert(seat("Stalls", "A", "3"));
+
+        let open = open_seats(&event, "Stalls");
+        assert_eq!(open.len(), 11);
+        assert!(!open.contains(&seat("Stalls", "A", "3")));
+        assert_eq!(open[0], seat("Stalls", "A", "4"));
+        assert_eq!(open_seats(&event, "Balcony").len(), 4);
+    }
+}
@@ -0,0 +1,336 @@
+// src/services/sessions.rs
+use crate::models::ticketing::*;
+use crate::error::HKDError;
+use crate::services::event_validation::Violation;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use uuid::Uuid;
+
+// Upper bound on generated occurrences, about a year of daily sessions
+const MAX_OCCURRENCES: u32 = 366;
+
+// Gates open this long before a session starts
+const CHECK_IN_OPENS_HOURS: i64 = 2;
+
+/// Sessions generated by `rule`, starting with the event's own first sitting.
+/// Times repeat on the venue's wall clock, so a 19:00 workshop stays at 19:00
+/// across a daylight saving change; local times that don't exist are skipped.
+pub fn expand_recurrence(event: &Event, rule: &RecurrenceRule) -> Result<Vec<Session>, HKDError> {
+    if rule.count.is_none() && rule.until.is_none() {
+        return Err(HKDError::InvalidSchedule("A recurrence needs a count or an end date".to_string()));
+    }
+    if rule.interval == 0 {
+        return Err(HKDError::InvalidSchedule("A recurrence interval must be at least 1".to_string()));
+    }
+    if rule.count.is_some_and(|count| count > MAX_OCCURRENCES) {
+        return Err(HKDError::InvalidSchedule(format!("A recurrence can have at most {} occurrences", MAX_OCCURRENCES)));
+    }
+
+    let timezone = event.timezone();
+    let first = event.event_date.with_timezone(&timezone).naive_local();
+    let duration = event.end_time() - event.event_date;
+    let interval = i64::from(rule.interval);
+    let step = match rule.frequency {
+        RecurrenceFrequency::Daily => Duration::try_days(interval),
+        RecurrenceFrequency::Weekly => Duration::try_weeks(interval),
+    };
+    let out_of_range = || HKDError::InvalidSchedule("The recurrence runs past the supported date range".to_string());
+    let step = step.ok_or_else(out_of_range)?;
+    // One past the limit so an `until` that allows more is caught below
+    let count = rule.count.unwrap_or(MAX_OCCURRENCES + 1);
+
+    let mut sessions = Vec::new();
+    for occurrence in 0..count {
+        let local = step.checked_mul(occurrence as i32)
+            .and_then(|offset| first.checked_add_signed(offset))
+            .ok_or_else(out_of_range)?;
+        let starts_at = match timezone.from_local_datetime(&local).earliest() {
+            Some(starts_at) => starts_at.with_timezone(&Utc),
+            None => continue,
+        };
+        if rule.until.is_some_and(|until| starts_at > until) {
+            break;
+        }
+        let ends_at = starts_at.checked_add_signed(duration).ok_or_else(out_of_range)?;
+        sessions.push(Session {
+            id: Uuid::new_v4(),
+            name: format!("{} ({})", event.title, local.format("%a %-d %b")),
+            starts_at,
+            ends_at,
+            capacity: rule.session_capacity,
+        });
+    }
+    if sessions.len() > MAX_OCCURRENCES as usize {
+        return Err(HKDError::InvalidSchedule(format!(
+            "The recurrence produces more than {} occurrences before its end date", MAX_OCCURRENCES
+        )));
+    }
+    Ok(sessions)
+}
+
+/// The event's sessions, or the event itself as its only session.
+pub fn effective_sessions(event: &Event) -> Vec<Session> {
+    if !event.sessions.is_empty() {
+        return event.sessions.clone();
+    }
+    vec![Session {
+        id: event.id,
+        name: event.title.clone(),
+        starts_at: event.event_date,
+        ends_at: event.end_time(),
+        capacity: None,
+    }]
+}
+
+/// Sessions must be well formed and ticket types may only name sessions that exist.
+pub fn session_violations(event: &Event) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for (index, session) in event.sessions.iter().enumerate() {
+        if session.ends_at <= session.starts_at {
+            violations.push(Violation::new(format!("sessions[{}].ends_at", index), "a session must end after it starts"));
+        }
+    }
+    for (index, ticket_type) in event.ticket_types.iter().enumerate() {
+        if let SessionAccess::Sessions(session_ids) = &ticket_type.session_access {
+            let field = format!("ticket_types[{}].session_access", index);
+            if session_ids.is_empty() {
+                violations.push(Violation::new(field.clone(), "must admit to at least one session"));
+            }
+            for unknown in session_ids.iter().filter(|id| !event.sessions.iter().any(|s| s.id == **id)) {
+                violations.push(Violation::new(field.clone(), format!("unknown session {}", unknown)));
+            }
+        }
+    }
+    violations
+}
+
+/// When sales must close: the start of the last session the ticket type admits to.
+pub fn sales_deadline(event: &Event, ticket_type: &TicketType) -> DateTime<Utc> {
+    match &ticket_type.session_access {
+        SessionAccess::Sessions(session_ids) => event.sessions.iter()
+            .filter(|session| session_ids.contains(&session.id))
+            .map(|session| session.starts_at)
+            .max()
+            .unwrap_or(event.event_date),
+        SessionAccess::FullPass => event.event_date,
+    }
+}
+
+/// Admissions left across the sessions a ticket type covers; `None` when none
+/// of them is capped.
+pub fn session_availability(event: &Event, ticket_type: &TicketType) -> Option<u32> {
+    event.sessions.iter()
+        .filter(|session| ticket_type.session_access.covers(session.id))
+        .filter_map(|session| {
+            let capacity = session.capacity?;
+            let admitted: u64 = event.ticket_types.iter()
+                .filter(|tt| tt.session_access.covers(session.id))
+                .map(|tt| u64::from(tt.quantity_sold))
+                .sum();
+            Some(u64::from(capacity).saturating_sub(admitted) as u32)
+        })
+        .min()
+}
+
+/// The session a ticket admits to at `at`: it must be running on the venue's
+/// calendar day, its gates must be open, and the ticket must not have used it yet.
+pub fn session_for_check_in(
+    event: &Event,
+    ticket_type: &TicketType,
+    ticket: &Ticket,
+    at: DateTime<Utc>,
+) -> Result<Session, HKDError> {
+    if !matches!(ticket.status, TicketStatus::Active) {
+        return Err(HKDError::CheckInRejected(format!("Ticket is {:?}", ticket.status)));
+    }
+
+    let timezone = event.timezone();
+    let today = at.with_timezone(&timezone).date_naive();
+    let todays: Vec<Session> = effective_sessions(event).into_iter()
+        .filter(|session| {
+            let first_day = session.starts_at.with_timezone(&timezone).date_naive();
+            let last_day = session.ends_at.with_timezone(&timezone).date_naive();
+            first_day <= today && today <= last_day
+        })
+        .collect();
+    if todays.is_empty() {
+        return Err(HKDError::CheckInRejected(format!("{} has no session today", event.title)));
+    }
+
+    let open: Vec<Session> = todays.into_iter()
+        .filter(|session| session.starts_at - Duration::hours(CHECK_IN_OPENS_HOURS) <= at && at <= session.ends_at)
+        .filter(|session| ticket_type.session_access.covers(session.id))
+        .collect();
+    if open.is_empty() {
+        return Err(HKDError::CheckInRejected(format!(
+            "{} isn't valid for a session open now", ticket_type.name
+        )));
+    }
+
+    open.iter()
+        .find(|session| !ticket.checked_in_sessions.contains(&session.id))
+        .cloned()
+        .ok_or_else(|| HKDError::CheckInRejected(format!("Already checked in to {}", open[0].name)))
+}
+
+/// Whether the ticket has been used for every session it admits to.
+pub fn is_fully_used(event: &Event, ticket_type: &TicketType, ticket: &Ticket) -> bool {
+    effective_sessions(event).iter()
+        .filter(|session| ticket_type.session_access.covers(session.id))
+        .all(|session| ticket.checked_in_sessions.contains(&session.id))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use chrono_tz::Tz;
+
+    fn rule(frequency: RecurrenceFrequency, count: Option<u32>, until: Option<DateTime<Utc>>) -> RecurrenceRule {
+        RecurrenceRule { frequency, interval: 1, count, until, session_capacity: None }
+    }
+
+    fn london_event(starts_at: DateTime<Utc>) -> Event {
+        let mut event = fixtures::event();
+        event.timezone = Some(Tz::Europe__London);
+        event.event_date = starts_at;
+        event.door_time = starts_at - Duration::hours(1);
+        event
+    }
+
+    fn local_starts(event: &Event, sessions: &[Session]) -> Vec<String> {
+        sessions.iter()
+            .map(|session| session.starts_at.with_timezone(&event.timezone()).format("%Y-%m-%d %H:%M").to_string())
+            .collect()
+    }
+
+    fn assert_invalid(result: Result<Vec<Session>, HKDError>) {
+        assert!(matches!(result, Err(HKDError::InvalidSchedule(_))), "expected InvalidSchedule");
+    }
+
+    #[test]
+    fn weekly_sessions_keep_their_wall_clock_time_across_dst() {
+        let event = london_event(Utc.with_ymd_and_hms(2030, 3, 20, 19, 0, 0).unwrap());
+        let sessions = expand_recurrence(&event, &rule(RecurrenceFrequency::Weekly, Some(3), None)).unwrap();
+
+        assert_eq!(local_starts(&event, &sessions), vec!["2030-03-20 19:00", "2030-03-27 19:00", "2030-04-03 19:00"]);
+        assert_eq!(sessions[2].starts_at, Utc.with_ymd_and_hms(2030, 4, 3, 18, 0, 0).unwrap());
+        assert!(sessions.iter().all(|session| session.ends_at - session.starts_at == Duration::hours(3)));
+    }
+
+    #[test]
+    fn local_times_that_do_not_exist_are_skipped() {
+        // 01:30 on 31 March 2030 falls in London's spring-forward gap
+        let event = london_event(Utc.with_ymd_and_hms(2030, 3, 30, 1, 30, 0).unwrap());
+        let sessions = expand_recurrence(&event, &rule(RecurrenceFrequency::Daily, Some(3), None)).unwrap();
+        assert_eq!(local_starts(&event, &sessions), vec!["2030-03-30 01:30", "2030-04-01 01:30"]);
+    }
+
+    #[test]
+    fn until_ends_the_series() {
+        let event = london_event(Utc.with_ymd_and_hms(2030, 5, 1, 18, 0, 0).unwrap());
+        let until = Utc.with_ymd_and_hms(2030, 5, 4, 18, 0, 0).unwrap();
+        let mut every_other_day = rule(RecurrenceFrequency::Daily, None, Some(until));
+        every_other_day.interval = 2;
+        every_other_day.session_capacity = Some(200);
+
+        let sessions = expand_recurrence(&event, &every_other_day).unwrap();
+        assert_eq!(local_starts(&event, &sessions), vec!["2030-05-01 19:00", "2030-05-03 19:00"]);
+        assert!(sessions.iter().all(|session| session.capacity == Some(200)));
+    }
+
+    #[test]
+    fn malformed_rules_are_rejected() {
+        let event = fixtures::event();
+        assert_invalid(expand_recurrence(&event, &rule(RecurrenceFrequency::Daily, None, None)));
+
+        let mut never = rule(RecurrenceFrequency::Daily, Some(3), None);
+        never.interval = 0;
+        assert_invalid(expand_recurrence(&event, &never));
+    }
+
+    #[test]
+    fn series_are_capped_at_a_year_of_daily_sessions() {
+        let event = fixtures::event();
+        assert!(expand_recurrence(&event, &rule(RecurrenceFrequency::Daily, Some(366), None)).is_ok());
+        assert_invalid(expand_recurrence(&event, &rule(RecurrenceFrequency::Daily, Some(367), None)));
+
+        let two_years = event.event_date + Duration::days(730);
+        assert_invalid(expand_recurrence(&event, &rule(RecurrenceFrequency::Daily, None, Some(two_years))));
+    }
+
+    #[test]
+    fn series_past_the_calendar_are_rejected() {
+        let event = fixtures::event();
+        let mut huge = rule(RecurrenceFrequency::Weekly, Some(2), None);
+        huge.interval = u32::MAX;
+        assert_invalid(expand_recurrence(&event, &huge));
+    }
+
+    // A two-day festival with a day ticket for the first day.
+    fn festival() -> (Event, TicketType) {
+        let mut event = fixtures::event();
+        let day_one = event.event_date;
+        event.sessions = [day_one, day_one + Duration::days(1)].iter()
+            .enumerate()
+            .map(|(index, starts_at)| Session {
+                id: Uuid::new_v4(),
+                name: format!("Day {}", index + 1),
+                starts_at: *starts_at,
+                ends_at: *starts_at + Duration::hours(6),
+                capacity: Some(1_000),
+            })
+            .collect();
+        let mut day_ticket = event.ticket_types[0].clone();
+        day_ticket.session_access = SessionAccess::Sessions(vec![event.sessions[0].id]);
+        event.ticket_types = vec![day_ticket.clone()];
+        (event, day_ticket)
+    }
+
+    #[test]
+    fn day_tickets_check_in_once_to_their_own_session() {
+        let (event, day_ticket) = festival();
+        let mut ticket = fixtures::ticket(&event);
+        let day_one = event.sessions[0].starts_at;
+
+        let session = session_for_check_in(&event, &day_ticket, &ticket, day_one - Duration::hours(1)).unwrap();
+        assert_eq!(session.name, "Day 1");
+        ticket.checked_in_sessions.push(session.id);
+        assert!(is_fully_used(&event, &day_ticket, &ticket));
+
+        assert!(matches!(
+            session_for_check_in(&event, &day_ticket, &ticket, day_one + Duration::hours(1)),
+            Err(HKDError::CheckInRejected(_))
+        ));
+        assert!(matches!(
+            session_for_check_in(&event, &day_ticket, &fixtures::ticket(&event), day_one + Duration::days(1)),
+            Err(HKDError::CheckInRejected(_))
+        ));
+    }
+
+    #[test]
+    fn gates_open_two_hours_before_a_session() {
+        let (event, day_ticket) = festival();
+        let ticket = fixtures::ticket(&event);
+        let day_one = event.sessions[0].starts_at;
+
+        assert!(session_for_check_in(&event, &day_ticket, &ticket, day_one - Duration::hours(3)).is_err());
+        assert!(session_for_check_in(&event, &day_ticket, &ticket, day_one - Duration::hours(2)).is_ok());
+    }
+
+    #[test]
+    fn availability_is_the_tightest_session_cap() {
+        let (mut event, _) = festival();
+        event.sessions[0].capacity = Some(300);
+        event.ticket_types[0].quantity_sold = 120;
+        let mut full_pass = event.ticket_types[0].clone();
+        full_pass.session_access = SessionAccess::FullPass;
+        full_pass.quantity_sold = 80;
+        event.ticket_types.push(full_pass.clone());
+
+        assert_eq!(session_availability(&event, &event.ticket_types[0]), Some(100));
+        assert_eq!(session_availability(&event, &full_pass), Some(100));
+        assert_eq!(sales_deadline(&event, &full_pass), event.event_date);
+        assert_eq!(sales_deadline(&event, &event.ticket_types[0]), event.sessions[0].starts_at);
+    }
+}
@@ -0,0 +1,365 @@
+// src/services/sync_outbox.rs
+use crate::models::ticketing::{Event, EventPlatform, PlatformSync, SyncStatus, TicketType};
+use crate::services::external_apis::TicketingPlatform;
+use crate::services::ticketing_service::TicketingService;
+use crate::error::HKDError;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SyncOperation {
+    Create,
+    Update,
+}
+
+/// A queued push of a local event to one external platform.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncJob {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub platform: EventPlatform,
+    pub operation: SyncOperation,
+    pub attempts: u32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    #[serde(default)]
+    pub revision: u32, // Bumped each time another change is folded in
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay_secs: i64,
+    pub max_delay_secs: i64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 8,
+            base_delay_secs: 30,
+            max_delay_secs: 3600,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Exponential backoff: base, 2x base, 4x base, ... capped at `max_delay_secs`.
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(20);
+        let delay = self.base_delay_secs.saturating_mul(1 << exponent);
+        Duration::seconds(delay.min(self.max_delay_secs))
+    }
+}
+
+pub struct SyncOutbox {
+    jobs: HashMap<Uuid, SyncJob>,
+    policy: RetryPolicy,
+}
+
+impl SyncOutbox {
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self {
+            jobs: HashMap::new(),
+            policy,
+        }
+    }
+
+    /// Queues a job, collapsing it into an existing one for the same event and platform.
+    /// A pending `Create` absorbs later updates since it sends the latest event anyway.
+    pub fn enqueue(&mut self, event_id: Uuid, platform: EventPlatform, operation: SyncOperation) -> Uuid {
+        if let Some(job) = self.jobs.values_mut()
+            .find(|job| job.event_id == event_id && job.platform == platform)
+        {
+            if operation == SyncOperation::Create {
+                job.operation = SyncOperation::Create;
+            }
+            job.revision += 1;
+            job.next_attempt_at = Utc::now();
+            return job.id;
+        }
+
+        let job = SyncJob {
+            id: Uuid::new_v4(),
+            event_id,
+            platform,
+            operation,
+            attempts: 0,
+            next_attempt_at: Utc::now(),
+            last_error: None,
+            revision: 0,
+        };
+        let job_id = job.id;
+        self.jobs.insert(job_id, job);
+        job_id
+    }
+
+    pub fn due_jobs(&self, now: DateTime<Utc>) -> Vec<SyncJob> {
+        let mut due: Vec<SyncJob> = self.jobs.values()
+            .filter(|job| job.next_attempt_at <= now)
+            .cloned()
+            .collect();
+        due.sort_by_key(|job| job.next_attempt_at);
+        due
+    }
+
+    /// Drops a delivered job unless changes were queued onto it since it was
+    /// taken, returning whether it was dropped.
+    pub fn complete(&mut self, delivered: &SyncJob) -> bool {
+        match self.jobs.get(&delivered.id) {
+            Some(job) if job.revision != delivered.revision => false,
+            _ => {
+                self.jobs.remove(&delivered.id);
+                true
+            }
+        }
+    }
+
+    /// Records a failed attempt and schedules the retry. Jobs that exhaust
+    /// their attempts are dropped and reported as `Failed`.
+    pub fn fail(&mut self, job_id: Uuid, error: String, now: DateTime<Utc>) -> SyncStatus {
+        let job = match self.jobs.get_mut(&job_id) {
+            Some(job) => job,
+            None => return SyncStatus::Failed,
+        };
+
+        job.attempts += 1;
+        job.last_error = Some(error);
+        if job.attempts >= self.policy.max_attempts {
+            self.jobs.remove(&job_id);
+            return SyncStatus::Failed;
+        }
+
+        job.next_attempt_at = now + self.policy.backoff(job.attempts);
+        SyncStatus::Pending
+    }
+
+    pub fn jobs_for_event(&self, event_id: Uuid) -> Vec<&SyncJob> {
+        self.jobs.values().filter(|job| job.event_id == event_id).collect()
+    }
+
+    pub fn pending_jobs(&self) -> Vec<&SyncJob> {
+        self.jobs.values().collect()
+    }
+}
+
+/// A due job with a copy of its event, pushed without holding the service.
+pub struct SyncWork {
+    pub job: SyncJob,
+    pub event: Event,
+    pub client: Option<Arc<dyn TicketingPlatform>>,
+}
+
+/// Creates or updates the event on the job's platform, then any ticket types
+/// it doesn't have yet. What the platform acknowledges is written to `work.event`.
+pub async fn push_event(work: &mut SyncWork) -> Result<(), HKDError> {
+    let SyncWork { job, event, client } = work;
+    let client = client.as_ref()
+        .ok_or_else(|| HKDError::ExternalApiError(format!("No client registered for {:?}", job.platform)))?;
+    let known_external_id = event.publications.get(&job.platform)
+        .and_then(|publication| publication.external_id.clone());
+
+    // A previous attempt may have created the event before failing on ticket
+    // types, and updates queued since are folded into the `Create`, so an
+    // event the platform already has is always updated
+    let external_id = match known_external_id {
+        Some(external_id) => {
+            client.update_event(&external_id, event).await?;
+            external_id
+        }
+        None => {
+            let response = client.create_event(event).await?;
+            let publication = event.publications.entry(job.platform.clone())
+                .or_insert_with(|| PlatformSync::pending(job.platform.clone()));
+            publication.external_id = Some(response.external_id.clone());
+            publication.event_url = Some(response.event_url);
+            response.external_id
+        }
+    };
+
+    let unsynced: Vec<TicketType> = event.ticket_types.iter()
+        .filter(|tt| !tt.external_ids.contains_key(&job.platform))
+        .cloned()
+        .collect();
+    if !unsynced.is_empty() {
+        let ticket_responses = client.create_ticket_types(&external_id, &unsynced).await?;
+        for ticket_response in ticket_responses {
+            if let Some(ticket_type) = event.ticket_types.iter_mut().find(|tt| tt.id == ticket_response.ticket_type_id) {
+                ticket_type.external_ids.insert(ticket_response.platform, ticket_response.external_id);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies the external ids `platform` assigned during a push onto the stored
+/// event, leaving everything edited since alone.
+pub fn merge_acknowledged(event: &mut Event, pushed: &Event, platform: &EventPlatform) {
+    if let (Some(publication), Some(acknowledged)) = (event.publications.get_mut(platform), pushed.publications.get(platform)) {
+        if publication.external_id.is_none() {
+            publication.external_id = acknowledged.external_id.clone();
+            publication.event_url = acknowledged.event_url.clone();
+        }
+    }
+    for ticket_type in event.ticket_types.iter_mut() {
+        if ticket_type.external_ids.contains_key(platform) {
+            continue;
+        }
+        let acknowledged = pushed.ticket_types.iter()
+            .find(|tt| tt.id == ticket_type.id)
+            .and_then(|tt| tt.external_ids.get(platform));
+        if let Some(external_id) = acknowledged {
+            ticket_type.external_ids.insert(platform.clone(), external_id.clone());
+        }
+    }
+}
+
+/// Periodically drains due outbox jobs. Pushes can wait on rate limits and
+/// retries, so the service is only locked to take jobs and record outcomes.
+pub async fn run_sync_outbox_job(service: Arc<Mutex<TicketingService>>, interval: std::time::Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        let now = Utc::now();
+        let work = service.lock().await.due_sync_work(now);
+        let processed = work.len();
+        for mut item in work {
+            let result = push_event(&mut item).await;
+            service.lock().await.finish_sync_job(item, result, now);
+        }
+        if processed > 0 {
+            log::debug!("Processed {} event sync jobs", processed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy { max_attempts: 3, base_delay_secs: 30, max_delay_secs: 100 }
+    }
+
+    #[test]
+    fn jobs_for_the_same_target_are_folded() {
+        let mut outbox = SyncOutbox::new(policy());
+        let event_id = Uuid::new_v4();
+
+        let create = outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Create);
+        let update = outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Update);
+        assert_eq!(create, update);
+        assert_eq!(outbox.jobs_for_event(event_id)[0].operation, SyncOperation::Create);
+
+        // Each platform gets its own job
+        outbox.enqueue(event_id, EventPlatform::Cvent, SyncOperation::Update);
+        assert_eq!(outbox.pending_jobs().len(), 2);
+    }
+
+    #[test]
+    fn a_create_upgrades_a_pending_update() {
+        let mut outbox = SyncOutbox::new(policy());
+        let event_id = Uuid::new_v4();
+
+        outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Update);
+        outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Create);
+        assert_eq!(outbox.jobs_for_event(event_id)[0].operation, SyncOperation::Create);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let policy = policy();
+        let delays: Vec<i64> = (1..=4).map(|attempts| policy.backoff(attempts).num_seconds()).collect();
+        assert_eq!(delays, vec![30, 60, 100, 100]);
+        assert_eq!(policy.backoff(u32::MAX).num_seconds(), 100);
+    }
+
+    #[test]
+    fn failures_are_retried_later_then_dropped() {
+        let mut outbox = SyncOutbox::new(policy());
+        let event_id = Uuid::new_v4();
+        let job_id = outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Create);
+        let now = Utc::now();
+
+        assert_eq!(outbox.fail(job_id, "503".to_string(), now), SyncStatus::Pending);
+        assert!(outbox.due_jobs(now).is_empty());
+        assert_eq!(outbox.due_jobs(now + Duration::seconds(30)).len(), 1);
+
+        assert_eq!(outbox.fail(job_id, "503".to_string(), now), SyncStatus::Pending);
+        let job = outbox.jobs_for_event(event_id)[0];
+        assert_eq!(job.next_attempt_at, now + Duration::seconds(60));
+        assert_eq!(job.last_error.as_deref(), Some("503"));
+
+        assert_eq!(outbox.fail(job_id, "503".to_string(), now), SyncStatus::Failed);
+        assert!(outbox.jobs_for_event(event_id).is_empty());
+    }
+
+    #[test]
+    fn completed_jobs_leave_the_outbox() {
+        let mut outbox = SyncOutbox::new(policy());
+        let event_id = Uuid::new_v4();
+        outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Update);
+
+        let delivered = outbox.due_jobs(Utc::now()).remove(0);
+        assert!(outbox.complete(&delivered));
+        assert!(outbox.due_jobs(Utc::now()).is_empty());
+    }
+
+    #[test]
+    fn folding_a_change_bumps_the_revision() {
+        let mut outbox = SyncOutbox::new(policy());
+        let event_id = Uuid::new_v4();
+        outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Create);
+        assert_eq!(outbox.jobs_for_event(event_id)[0].revision, 0);
+
+        outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Update);
+        outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Update);
+        assert_eq!(outbox.jobs_for_event(event_id)[0].revision, 2);
+    }
+
+    #[test]
+    fn a_stale_delivery_keeps_the_job_queued() {
+        let mut outbox = SyncOutbox::new(policy());
+        let event_id = Uuid::new_v4();
+        outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Update);
+        let delivered = outbox.due_jobs(Utc::now()).remove(0);
+
+        // Edited while the push was in flight
+        outbox.enqueue(event_id, EventPlatform::Eventbrite, SyncOperation::Update);
+        assert!(!outbox.complete(&delivered));
+        assert_eq!(outbox.jobs_for_event(event_id).len(), 1);
+
+        let redelivered = outbox.due_jobs(Utc::now()).remove(0);
+        assert!(outbox.complete(&redelivered));
+        assert!(outbox.jobs_for_event(event_id).is_empty());
+    }
+
+    #[test]
+    fn acknowledged_ids_are_merged_without_undoing_later_edits() {
+        let mut stored = fixtures::event();
+        stored.publications.insert(EventPlatform::Eventbrite, PlatformSync::pending(EventPlatform::Eventbrite));
+
+        let mut pushed = stored.clone();
+        let publication = pushed.publications.get_mut(&EventPlatform::Eventbrite).unwrap();
+        publication.external_id = Some("eb-3003".to_string());
+        publication.event_url = Some("https://eventbrite.test/e/3003".to_string());
+        pushed.ticket_types[0].external_ids.insert(EventPlatform::Eventbrite, "eb-tc-1".to_string());
+
+        // Renamed while the push was in flight
+        stored.title = "Harbour Lights Festival 2030".to_string();
+        merge_acknowledged(&mut stored, &pushed, &EventPlatform::Eventbrite);
+
+        assert_eq!(stored.title, "Harbour Lights Festival 2030");
+        assert_eq!(stored.publications[&EventPlatform::Eventbrite].external_id.as_deref(), Some("eb-3003"));
+        assert_eq!(
+            stored.ticket_types[0].external_ids.get(&EventPlatform::Eventbrite).map(String::as_str),
+            Some("eb-tc-1")
+        );
+    }
+}
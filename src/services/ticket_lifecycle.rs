@@ -0,0 +1,130 @@
+// src/services/ticket_lifecycle.rs
+use crate::models::ticketing::*;
+use crate::error::HKDError;
+use uuid::Uuid;
+
+/// Only a live ticket changes state; every other status is final.
+pub fn is_allowed(from: &TicketStatus, to: &TicketStatus) -> bool {
+    use TicketStatus::*;
+    matches!(
+        (from, to),
+        (Active, Used | Transferred | Resold | Refunded | Cancelled)
+    )
+}
+
+/// Moves `ticket` to `to`, refusing steps the lifecycle doesn't allow.
+pub fn transition(ticket: &mut Ticket, to: TicketStatus) -> Result<(), HKDError> {
+    ensure_allowed(ticket, &to)?;
+    ticket.status = to;
+    Ok(())
+}
+
+pub fn ensure_allowed(ticket: &Ticket, to: &TicketStatus) -> Result<(), HKDError> {
+    if !is_allowed(&ticket.status, to) {
+        return Err(HKDError::InvalidTicketTransition {
+            ticket_id: ticket.id,
+            from: ticket.status.clone(),
+            to: to.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// The ticket a new holder receives. It gets its own id and QR code so the
+/// previous holder's code stops working at the gate.
+pub fn reissue(ticket: &Ticket, owner_wallet: String) -> Ticket {
+    let ticket_id = Uuid::new_v4();
+    Ticket {
+        id: ticket_id,
+        owner_wallet,
+        status: TicketStatus::Active,
+        qr_code: format!("TICKET_{}_{}", ticket.event_id, ticket_id),
+        resale_price: None,
+        previous_ticket_id: Some(ticket.id),
+        refund_pending: false,
+        ..ticket.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use chrono::{Duration, TimeZone, Utc};
+
+    const STATUSES: [TicketStatus; 6] = [
+        TicketStatus::Active,
+        TicketStatus::Used,
+        TicketStatus::Transferred,
+        TicketStatus::Resold,
+        TicketStatus::Refunded,
+        TicketStatus::Cancelled,
+    ];
+
+    #[test]
+    fn only_active_tickets_change_state() {
+        for from in &STATUSES {
+            for to in &STATUSES {
+                let expected = *from == TicketStatus::Active && *to != TicketStatus::Active;
+                assert_eq!(is_allowed(from, to), expected, "{:?} → {:?}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn transitions_apply_once_and_then_are_final() {
+        let mut ticket = fixtures::ticket(&fixtures::event());
+        transition(&mut ticket, TicketStatus::Used).unwrap();
+        assert_eq!(ticket.status, TicketStatus::Used);
+
+        match transition(&mut ticket, TicketStatus::Refunded) {
+            Err(HKDError::InvalidTicketTransition { ticket_id, from, to }) => {
+                assert_eq!(ticket_id, ticket.id);
+                assert_eq!((from, to), (TicketStatus::Used, TicketStatus::Refunded));
+            }
+            other => panic!("expected the refund to be refused, got {:?}", other),
+        }
+        assert_eq!(ticket.status, TicketStatus::Used);
+    }
+
+    #[test]
+    fn ensure_allowed_leaves_the_ticket_alone() {
+        let mut ticket = fixtures::ticket(&fixtures::event());
+        ticket.status = TicketStatus::Refunded;
+        assert!(ensure_allowed(&ticket, &TicketStatus::Transferred).is_err());
+        assert_eq!(ticket.status, TicketStatus::Refunded);
+    }
+
+    #[test]
+    fn reissued_tickets_get_a_new_id_and_qr_code() {
+        let event = fixtures::event();
+        let mut original = fixtures::ticket(&event);
+        original.resale_price = Some(original.purchase_price);
+        original.seat = Some(SeatAssignment { section: "A".to_string(), row: "1".to_string(), seat: "7".to_string() });
+
+        let reissued = reissue(&original, "wallet:friend".to_string());
+        assert_ne!(reissued.id, original.id);
+        assert_ne!(reissued.qr_code, original.qr_code);
+        assert_eq!(reissued.previous_ticket_id, Some(original.id));
+        assert_eq!(reissued.owner_wallet, "wallet:friend");
+        assert_eq!(reissued.status, TicketStatus::Active);
+        assert_eq!(reissued.resale_price, None);
+        // Where it's for and what was paid travel with the ticket
+        assert_eq!(reissued.seat, original.seat);
+        assert_eq!(reissued.payment, original.payment);
+        assert_eq!(reissued.purchase_price, original.purchase_price);
+    }
+
+    #[test]
+    fn transfer_policies_lock_at_their_deadline() {
+        let lock = Utc.with_ymd_and_hms(2030, 6, 13, 0, 0, 0).unwrap();
+        let policy = TransferPolicy::TransferableUntil(lock);
+        assert!(policy.allows_transfer_at(lock - Duration::seconds(1)));
+        assert!(!policy.allows_transfer_at(lock));
+
+        assert!(TransferPolicy::Transferable.allows_transfer_at(lock));
+        assert!(!TransferPolicy::Soulbound.allows_transfer_at(lock - Duration::days(365)));
+        assert!(TransferPolicy::Soulbound.is_soulbound());
+        assert!(!policy.is_soulbound());
+    }
+}
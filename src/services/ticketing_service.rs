@@ -1,7 +1,38 @@
 // src/services/ticketing_service.rs
 use crate::models::ticketing::*;
-use crate::services::external_apis::{EventbriteClient, TicketmasterClient, CventClient};
+use crate::services::external_apis::{
+    AttendeeStatus, EventFilters, ExternalAttendee, ExternalOrder, TicketingPlatform,
+};
+use crate::services::event_changes::{
+    apply_update, is_editable, material_changes, open_refund_window, refund_window_ends, EventChangeNotice,
+    EventRevision, EventUpdate, HolderNotifier, LogHolderNotifier,
+};
+use crate::services::event_lifecycle::{check_transition, remaining, StatusTransition, SYSTEM_ACTOR};
+use crate::services::event_import::{reconcile_imported_event, ImportOutcome, ImportRejection, ImportReport};
+use crate::services::event_search::{search_events, EventQuery, EventSearchResults};
+use crate::services::geo_index::{events_near, GeoIndex, NearbyEvent, NearbyQuery};
+use crate::services::inventory_sync::{
+    channel_available, channel_limit, channel_sold, pull_channel_sales, push_channel_limits,
+    record_channel_sales, total_sold, venue_remaining, InventoryChannel, InventoryConflict, InventorySyncReport,
+};
 use crate::services::nft_service::{NFTMinter, MockNFTService};
+use crate::services::event_validation::{validate_event, Violation, SUPPORTED_CURRENCIES};
+use crate::services::escrow::{
+    due_tranches, escrow_account, release_amounts, EscrowRelease, EventEscrow, SettlementConfig,
+};
+use crate::services::payments::{PaymentProvider, PaymentRequest, PaymentWebhookEvent};
+use crate::services::fx::{convert_price, FixedRateProvider, FxRateProvider, PriceQuote, DEFAULT_QUOTE_TTL_SECONDS};
+use crate::services::schedule::{infer_timezone, local_times, schedule_attributes};
+use crate::services::sessions::{
+    expand_recurrence, is_fully_used, session_availability, session_for_check_in,
+};
+use crate::services::seating::{open_seats, seat_attributes, select_seats};
+use crate::services::venue_registry::{capacity_violations, VenueRegistry};
+use crate::services::ticket_lifecycle::{ensure_allowed, reissue, transition};
+use crate::services::sync_outbox::{
+    merge_acknowledged, push_event, RetryPolicy, SyncJob, SyncOperation, SyncOutbox, SyncWork,
+};
+use crate::services::webhooks::WebhookEvent;
 use crate::engine::HKDEngine;
 use crate::error::HKDError;
 use uuid::Uuid;
@@ -11,10 +42,21 @@ use std::sync::Arc;
 
 pub struct TicketingService {
     events: HashMap<Uuid, Event>,
+    geo_index: GeoIndex,
+    venues: VenueRegistry,
     tickets: HashMap<Uuid, Ticket>,
-    eventbrite_client: Option<EventbriteClient>,
-    ticketmaster_client: Option<TicketmasterClient>,
-    cvent_client: Option<CventClient>,
+    listings: HashMap<Uuid, ResaleListing>,
+    platforms: HashMap<EventPlatform, Arc<dyn TicketingPlatform>>,
+    outbox: SyncOutbox,
+    revisions: HashMap<Uuid, Vec<EventRevision>>,
+    transitions: HashMap<Uuid, Vec<StatusTransition>>,
+    quotes: HashMap<Uuid, PriceQuote>,
+    quote_ttl: chrono::Duration,
+    fx_provider: Arc<dyn FxRateProvider + Send + Sync>,
+    payment_providers: HashMap<PaymentMethod, Arc<dyn PaymentProvider + Send + Sync>>,
+    escrows: HashMap<Uuid, EventEscrow>,
+    settlement: SettlementConfig,
+    holder_notifier: Arc<dyn HolderNotifier + Send + Sync>,
     nft_minter: Arc<dyn NFTMinter + Send + Sync>,
     stablecoin_engine: Arc<HKDEngine>,
 }
@@ -23,88 +65,668 @@ impl TicketingService {
     pub fn new(stablecoin_engine: Arc<HKDEngine>) -> Self {
         Self {
             events: HashMap::new(),
+            geo_index: GeoIndex::new(),
+            venues: VenueRegistry::new(),
             tickets: HashMap::new(),
-            eventbrite_client: None,
-            ticketmaster_client: None,
-            cvent_client: None,
+            listings: HashMap::new(),
+            platforms: HashMap::new(),
+            outbox: SyncOutbox::new(RetryPolicy::default()),
+            revisions: HashMap::new(),
+            transitions: HashMap::new(),
+            quotes: HashMap::new(),
+            quote_ttl: chrono::Duration::seconds(DEFAULT_QUOTE_TTL_SECONDS),
+            fx_provider: Arc::new(FixedRateProvider::default()),
+            payment_providers: HashMap::new(),
+            escrows: HashMap::new(),
+            settlement: SettlementConfig::default(),
+            holder_notifier: Arc::new(LogHolderNotifier),
             nft_minter: Arc::new(MockNFTService::new()),
             stablecoin_engine,
         }
     }
 
-    pub fn with_eventbrite(mut self, client: EventbriteClient) -> Self {
-        self.eventbrite_client = Some(client);
+    pub fn with_platform(mut self, client: Box<dyn TicketingPlatform>) -> Self {
+        self.platforms.insert(client.platform(), Arc::from(client));
         self
     }
 
-    pub fn with_ticketmaster(mut self, client: TicketmasterClient) -> Self {
-        self.ticketmaster_client = Some(client);
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.outbox = SyncOutbox::new(policy);
         self
     }
 
-    pub fn with_cvent(mut self, client: CventClient) -> Self {
-        self.cvent_client = Some(client);
+    pub fn with_nft_minter(mut self, minter: Arc<dyn NFTMinter + Send + Sync>) -> Self {
+        self.nft_minter = minter;
         self
     }
 
-    pub fn with_nft_minter(mut self, minter: Arc<dyn NFTMinter + Send + Sync>) -> Self {
-        self.nft_minter = minter;
+    pub fn with_holder_notifier(mut self, notifier: Arc<dyn HolderNotifier + Send + Sync>) -> Self {
+        self.holder_notifier = notifier;
+        self
+    }
+
+    pub fn with_fx_provider(mut self, provider: Arc<dyn FxRateProvider + Send + Sync>) -> Self {
+        self.fx_provider = provider;
+        self
+    }
+
+    pub fn with_quote_ttl(mut self, ttl: chrono::Duration) -> Self {
+        self.quote_ttl = ttl;
+        self
+    }
+
+    // Checked with `SettlementConfig::validate` before any money is released
+    pub fn with_settlement_config(mut self, settlement: SettlementConfig) -> Self {
+        self.settlement = settlement;
         self
     }
 
+    /// Routes every method the provider supports through it.
+    pub fn with_payment_provider(mut self, provider: Arc<dyn PaymentProvider + Send + Sync>) -> Self {
+        for method in provider.methods() {
+            self.payment_providers.insert(method, provider.clone());
+        }
+        self
+    }
+
+    /// Creates an event from a draft. A supplied id is kept unless it is taken,
+    /// a nil one is replaced, and the timestamps are always set here.
     pub async fn create_event(&mut self, mut event: Event) -> Result<Event, HKDError> {
-        event.id = Uuid::new_v4();
+        let mut violations = Vec::new();
+        if event.id.is_nil() {
+            event.id = Uuid::new_v4();
+        } else if self.events.contains_key(&event.id) {
+            violations.push(Violation::new("id", format!("{} is already in use", event.id)));
+        }
+        // Later states are only reached through `transition_event`
+        if !matches!(event.status, EventStatus::Draft | EventStatus::Published) {
+            violations.push(Violation::new("status", "a new event must be Draft or Published"));
+        }
         event.created_at = chrono::Utc::now();
-        event.updated_at = chrono::Utc::now();
+        event.updated_at = event.created_at;
+        event.version = 1;
+        self.resolve_venue(&mut event.venue)?;
+        if event.sessions.is_empty() {
+            if let Some(rule) = &event.recurrence {
+                match expand_recurrence(&event, rule) {
+                    Ok(sessions) => event.sessions = sessions,
+                    Err(e) => violations.push(Violation::new("recurrence", e.to_string())),
+                }
+            }
+        }
 
-        // Sync with external platforms if specified
-        if event.platform != EventPlatform::Internal {
-            self.sync_event_to_external_platform(&event).await?;
+        violations.extend(validate_event(&event));
+        if !violations.is_empty() {
+            return Err(HKDError::ValidationFailed(violations));
         }
+        event.local_times = Some(local_times(&event));
+
+        let event_id = event.id;
 
-        self.events.insert(event.id, event.clone());
+        // Events created here originate here; `platform` only names where an
+        // imported event came from, so mirrors come from `publications` alone
+        event.platform = EventPlatform::Internal;
+        event.external_event_id = None;
+        let targets: Vec<EventPlatform> = event.publications.keys()
+            .filter(|platform| **platform != EventPlatform::Internal)
+            .cloned()
+            .collect();
+
+        // Queue sync with each platform; the event exists locally regardless
+        event.publications.clear();
+        for platform in targets {
+            event.publications.insert(platform.clone(), PlatformSync::pending(platform.clone()));
+            self.outbox.enqueue(event_id, platform, SyncOperation::Create);
+        }
+        self.store_event(event.clone());
         Ok(event)
     }
 
+    /// Edits an event. `expected_version` must match the stored version so two
+    /// editors can't silently overwrite each other. Accepted edits are recorded
+    /// field by field, pushed to every platform the event is mirrored to, and
+    /// material ones (date, door time, venue) are announced to ticket holders.
+    pub async fn update_event(
+        &mut self,
+        event_id: Uuid,
+        expected_version: u64,
+        mut update: EventUpdate,
+    ) -> Result<Event, HKDError> {
+        let mut event = self.events.get(&event_id)
+            .cloned()
+            .ok_or_else(|| HKDError::EventNotFound(event_id))?;
+        if event.version != expected_version {
+            return Err(HKDError::VersionConflict { expected: expected_version, actual: event.version });
+        }
+        if !is_editable(&event.status) {
+            return Err(HKDError::ValidationFailed(vec![
+                Violation::new("status", format!("a {:?} event can't be edited", event.status)),
+            ]));
+        }
+        if let Some(venue) = update.venue.as_mut() {
+            self.resolve_venue(venue)?;
+        }
+
+        let changes = apply_update(&mut event, update);
+        if changes.is_empty() {
+            return Ok(event);
+        }
+        let violations = validate_event(&event);
+        if !violations.is_empty() {
+            return Err(HKDError::ValidationFailed(violations));
+        }
+
+        let now = chrono::Utc::now();
+        event.version += 1;
+        event.updated_at = now;
+        let material = material_changes(&changes);
+        let refund_window = (!material.is_empty()).then(|| refund_window_ends(&event, now));
+        self.revisions.entry(event_id).or_default().push(EventRevision {
+            event_id,
+            version: event.version,
+            changed_at: now,
+            changes,
+            refund_window_ends: refund_window,
+        });
+
+        for (platform, publication) in event.publications.iter_mut() {
+            publication.status = SyncStatus::Pending;
+            self.outbox.enqueue(event_id, platform.clone(), SyncOperation::Update);
+        }
+        let notice = refund_window.map(|refund_window_ends| EventChangeNotice {
+            event_id,
+            event_title: event.title.clone(),
+            version: event.version,
+            changes: material,
+            holder_wallets: self.holder_wallets(event_id),
+            refund_window_ends,
+        });
+        self.store_event(event);
+
+        if let Some(notice) = notice {
+            // The edit stands even if holders couldn't be told right away
+            if let Err(e) = self.holder_notifier.notify_event_changed(&notice).await {
+                log::warn!("Notifying holders of event {} failed: {}", event_id, e);
+            }
+        }
+
+        self.events.get(&event_id)
+            .cloned()
+            .ok_or_else(|| HKDError::EventNotFound(event_id))
+    }
+
+    pub fn get_event_revisions(&self, event_id: Uuid) -> &[EventRevision] {
+        self.revisions.get(&event_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Refunds a ticket its holder no longer wants after a material change,
+    /// while that change's refund window is open. The seat and the ticket go
+    /// back on sale.
+    pub async fn request_change_refund(&mut self, ticket_id: Uuid) -> Result<Ticket, HKDError> {
+        let ticket = self.tickets.get(&ticket_id)
+            .ok_or_else(|| HKDError::TicketNotFound(ticket_id))?;
+        ensure_allowed(ticket, &TicketStatus::Refunded)?;
+        if ticket.external_attendee_id.is_some() {
+            return Err(HKDError::RefundNotAllowed("Tickets sold on other platforms are refunded there".to_string()));
+        }
+        if ticket.refund_pending {
+            return Err(HKDError::RefundNotAllowed("The ticket is already being refunded".to_string()));
+        }
+        // A card refund can only go back to the original buyer's card, not to whoever holds the ticket now
+        let paid_by_card = ticket.payment.as_ref().is_some_and(|payment| payment.method != PaymentMethod::HkdStablecoin);
+        if paid_by_card && ticket.previous_ticket_id.is_some() {
+            return Err(HKDError::RefundNotAllowed("Transferred tickets paid by card can't be refunded to their new holder".to_string()));
+        }
+        let revisions = self.get_event_revisions(ticket.event_id);
+        if open_refund_window(revisions, ticket.purchase_date, chrono::Utc::now()).is_none() {
+            return Err(HKDError::RefundNotAllowed("No refund window is open for this ticket".to_string()));
+        }
+
+        let released = ticket.clone();
+        let event_id = released.event_id;
+        self.release_ticket_stock(&released);
+        if let Some(ticket) = self.tickets.get_mut(&ticket_id) {
+            ticket.refund_pending = true;
+        }
+
+        // A failed payout stays pending for the next `process_pending_refunds`
+        self.process_pending_refunds(event_id).await;
+        self.tickets.get(&ticket_id)
+            .cloned()
+            .ok_or_else(|| HKDError::TicketNotFound(ticket_id))
+    }
+
+    /// Moves an event to `to` if the lifecycle allows it, recording who did so.
+    /// Cancelling refunds the tickets sold here; completing closes resale.
+    pub async fn transition_event(
+        &mut self,
+        event_id: Uuid,
+        to: EventStatus,
+        actor: &str,
+        reason: Option<String>,
+    ) -> Result<Event, HKDError> {
+        let event = self.events.get(&event_id)
+            .ok_or_else(|| HKDError::EventNotFound(event_id))?;
+        check_transition(event, &to, chrono::Utc::now())?;
+
+        self.record_transition(event_id, to.clone(), actor, reason);
+        match to {
+            EventStatus::Cancelled => self.refund_cancelled_tickets(event_id).await,
+            EventStatus::Completed => {
+                self.close_resale(event_id);
+                // Failed releases are picked up by the next `settle_due_escrows`
+                if let Err(e) = self.settle_escrow(event_id, chrono::Utc::now()) {
+                    log::error!("Settling escrow for event {} failed: {}", event_id, e);
+                }
+            }
+            _ => {}
+        }
+
+        self.events.get(&event_id)
+            .cloned()
+            .ok_or_else(|| HKDError::EventNotFound(event_id))
+    }
+
+    pub fn get_status_history(&self, event_id: Uuid) -> &[StatusTransition] {
+        self.transitions.get(&event_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    // Applies an already checked transition and queues it for mirrored platforms
+    fn record_transition(&mut self, event_id: Uuid, to: EventStatus, actor: &str, reason: Option<String>) {
+        let event = match self.events.get_mut(&event_id) {
+            Some(event) => event,
+            None => return,
+        };
+        let now = chrono::Utc::now();
+        self.transitions.entry(event_id).or_default().push(StatusTransition {
+            event_id,
+            from: event.status.clone(),
+            to: to.clone(),
+            actor: actor.to_string(),
+            reason,
+            at: now,
+        });
+        event.status = to;
+        event.version += 1;
+        event.updated_at = now;
+
+        for (platform, publication) in event.publications.iter_mut() {
+            publication.status = SyncStatus::Pending;
+            self.outbox.enqueue(event_id, platform.clone(), SyncOperation::Update);
+        }
+    }
+
+    // Takes a ticket sold here out of circulation: withdraws its listing, frees
+    // its seat and puts it back into our stock, which reopens a sold-out event
+    fn release_ticket_stock(&mut self, ticket: &Ticket) {
+        if let Some(listing) = self.listings.values_mut()
+            .find(|listing| listing.ticket_id == ticket.id && matches!(listing.status, ResaleStatus::Listed))
+        {
+            listing.status = ResaleStatus::Cancelled;
+        }
+        let event = match self.events.get_mut(&ticket.event_id) {
+            Some(event) => event,
+            None => return,
+        };
+        if let Some(seat) = &ticket.seat {
+            event.sold_seats.remove(seat);
+        }
+        if let Some(ticket_type) = event.ticket_types.iter_mut().find(|tt| tt.id == ticket.ticket_type_id) {
+            let sold = channel_sold(ticket_type, &EventPlatform::Internal).saturating_sub(1);
+            record_channel_sales(ticket_type, EventPlatform::Internal, sold);
+        }
+        let back_on_sale = event.status == EventStatus::SoldOut
+            && check_transition(event, &EventStatus::OnSale, chrono::Utc::now()).is_ok();
+        if back_on_sale {
+            self.record_transition(ticket.event_id, EventStatus::OnSale, SYSTEM_ACTOR, None);
+        }
+    }
+
+    // Marks every live ticket sold here as owed a refund, then pays them out.
+    // Platforms refund their own buyers.
+    async fn refund_cancelled_tickets(&mut self, event_id: Uuid) {
+        if let Some(event) = self.events.get_mut(&event_id) {
+            event.sold_seats.clear();
+        }
+        self.withdraw_listings(event_id);
+        for ticket in self.tickets.values_mut() {
+            if ticket.event_id == event_id
+                && ticket.external_attendee_id.is_none()
+                && ticket.status == TicketStatus::Active
+            {
+                ticket.refund_pending = true;
+            }
+        }
+        self.process_pending_refunds(event_id).await;
+    }
+
+    /// Pays back tickets still owed a refund for the event, each on the rail it
+    /// was paid on (stablecoin to the current holder), and burns their NFTs.
+    /// Failed refunds stay pending for the next call; returns how many remain.
+    pub async fn process_pending_refunds(&mut self, event_id: Uuid) -> usize {
+        let event = match self.events.get(&event_id) {
+            Some(event) => event.clone(),
+            None => return 0,
+        };
+        let pending: Vec<Ticket> = self.tickets.values()
+            .filter(|ticket| ticket.event_id == event_id && ticket.refund_pending)
+            .cloned()
+            .collect();
+
+        let mut still_pending = 0;
+        for ticket in pending {
+            let refunded = match &ticket.payment {
+                Some(payment) if payment.method == PaymentMethod::HkdStablecoin => self.refund_from_escrow(&ticket, &event),
+                // Card rails can only refund the card that paid, even after a transfer
+                Some(payment) => self.refund_payment(payment, &event, ticket.purchase_price, "Ticket refund").await,
+                None => Err(HKDError::UnsupportedPaymentMethod(format!("No payment on record for ticket {}", ticket.id))),
+            };
+            if let Err(e) = refunded {
+                log::warn!("Refunding ticket {} for event {} failed: {}", ticket.id, event_id, e);
+                still_pending += 1;
+                continue;
+            }
+
+            if let Some(token_id) = &ticket.nft_token_id {
+                if let Err(e) = self.nft_minter.burn_nft(token_id).await {
+                    log::warn!("Burning NFT {} for refunded ticket {} failed: {}", token_id, ticket.id, e);
+                }
+            }
+            if let Some(ticket) = self.tickets.get_mut(&ticket.id) {
+                ticket.refund_pending = false;
+                ticket.transferable = false;
+                ticket.resale_allowed = false;
+                if let Err(e) = transition(ticket, TicketStatus::Refunded) {
+                    log::warn!("Refunded ticket {} couldn't be closed: {}", ticket.id, e);
+                }
+            }
+        }
+        still_pending
+    }
+
+    // Pays a stablecoin ticket back to whoever holds it now. Sales already released
+    // to the organizer are topped up from the platform's refund account first; the
+    // top-up is kept even if the refund fails, so a retry doesn't draw it twice.
+    fn refund_from_escrow(&mut self, ticket: &Ticket, event: &Event) -> Result<(), HKDError> {
+        let escrow = self.escrows.entry(event.id).or_insert_with(|| EventEscrow::new(event.id));
+        let metadata = |purpose: &str| Some(crate::models::TransactionMetadata {
+            reference: Some(format!("Ticket refund: {}", event.title)),
+            purpose: Some(purpose.to_string()),
+            regulatory_approval_id: None,
+        });
+
+        let shortfall = escrow.refund_shortfall(ticket.purchase_price);
+        if shortfall > Decimal::ZERO {
+            self.stablecoin_engine.transfer(&self.settlement.refund_account, &escrow.account, shortfall, metadata("refund_advance"))?;
+            escrow.refunds_advanced += shortfall;
+        }
+        self.stablecoin_engine.transfer(&escrow.account, &ticket.owner_wallet, ticket.purchase_price, metadata("event_refund"))?;
+        escrow.refunded += ticket.purchase_price;
+        Ok(())
+    }
+
+    /// Releases every escrow tranche that has come due, returning the releases made.
+    /// Meant to run periodically; completed events also settle on completion.
+    pub fn settle_due_escrows(&mut self) -> Vec<EscrowRelease> {
+        let now = chrono::Utc::now();
+        let event_ids: Vec<Uuid> = self.escrows.keys().copied().collect();
+        let mut releases = Vec::new();
+        for event_id in event_ids {
+            match self.settle_escrow(event_id, now) {
+                Ok(Some(release)) => releases.push(release),
+                Ok(None) => {}
+                Err(e) => log::error!("Settling escrow for event {} failed: {}", event_id, e),
+            }
+        }
+        releases
+    }
+
+    pub fn get_escrow(&self, event_id: Uuid) -> Option<&EventEscrow> {
+        self.escrows.get(&event_id)
+    }
+
+    // Pays the organizer the due tranches of net sales, less the platform fee.
+    // A cancelled event's escrow only ever pays refunds, so any refund top-up
+    // still outstanding there is owed by the organizer.
+    fn settle_escrow(&mut self, event_id: Uuid, now: chrono::DateTime<chrono::Utc>) -> Result<Option<EscrowRelease>, HKDError> {
+        self.settlement.validate()?;
+        let event = self.events.get(&event_id)
+            .ok_or_else(|| HKDError::EventNotFound(event_id))?;
+        let escrow = match self.escrows.get_mut(&event_id) {
+            Some(escrow) if event.status != EventStatus::Cancelled => escrow,
+            _ => return Ok(None),
+        };
+        let metadata = |purpose: &str| Some(crate::models::TransactionMetadata {
+            reference: Some(format!("Settlement for {}", event.title)),
+            purpose: Some(purpose.to_string()),
+            regulatory_approval_id: None,
+        });
+
+        // A fee left behind by an earlier release goes first
+        if escrow.fees_outstanding > Decimal::ZERO {
+            self.stablecoin_engine.transfer(&escrow.account, &self.settlement.fee_account, escrow.fees_outstanding, metadata("platform_fee"))?;
+            escrow.fees_paid += escrow.fees_outstanding;
+            escrow.fees_outstanding = Decimal::ZERO;
+        }
+        // Then whatever the platform advanced for refunds
+        let recovered = escrow.refunds_advanced.min(escrow.available());
+        if recovered > Decimal::ZERO {
+            self.stablecoin_engine.transfer(&escrow.account, &self.settlement.refund_account, recovered, metadata("refund_recovery"))?;
+            escrow.refunds_advanced -= recovered;
+        }
+
+        let due = due_tranches(&event.settlement, escrow, event.status == EventStatus::Completed, now);
+        if due.is_empty() {
+            return Ok(None);
+        }
+        let (gross, fee) = release_amounts(&event.settlement, escrow, &due, self.settlement.fee_rate);
+        let payout = gross - fee;
+
+        let organizer_tx = if payout > Decimal::ZERO {
+            Some(self.stablecoin_engine.transfer(&escrow.account, &event.organizer, payout, metadata("organizer_settlement"))?)
+        } else {
+            None
+        };
+        escrow.paid_to_organizer += payout;
+        escrow.tranches_released.extend(due.iter().copied());
+
+        // The organizer has been paid; a failed fee transfer is retried next time
+        let fee_tx = if fee > Decimal::ZERO {
+            match self.stablecoin_engine.transfer(&escrow.account, &self.settlement.fee_account, fee, metadata("platform_fee")) {
+                Ok(tx) => {
+                    escrow.fees_paid += fee;
+                    Some(tx)
+                }
+                Err(e) => {
+                    log::warn!("Platform fee transfer for event {} failed: {}", event_id, e);
+                    escrow.fees_outstanding += fee;
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let release = EscrowRelease {
+            tranches: due,
+            gross,
+            fee,
+            organizer_tx,
+            fee_tx,
+            released_at: now,
+        };
+        escrow.releases.push(release.clone());
+        Ok(Some(release))
+    }
+
+    // A finished event's tickets stay with their holders but can't be resold
+    fn close_resale(&mut self, event_id: Uuid) {
+        self.withdraw_listings(event_id);
+        for ticket in self.tickets.values_mut().filter(|ticket| ticket.event_id == event_id) {
+            ticket.resale_allowed = false;
+            ticket.resale_price = None;
+        }
+    }
+
+    // Wallets holding live tickets bought here; external platforms tell their own buyers
+    fn holder_wallets(&self, event_id: Uuid) -> Vec<String> {
+        let mut wallets: Vec<String> = self.tickets.values()
+            .filter(|ticket| ticket.event_id == event_id && ticket.external_attendee_id.is_none())
+            .filter(|ticket| matches!(ticket.status, TicketStatus::Active))
+            .map(|ticket| ticket.owner_wallet.clone())
+            .collect();
+        wallets.sort();
+        wallets.dedup();
+        wallets
+    }
+
+    /// Swaps a registered venue for its current details and fills in its time zone.
+    fn resolve_venue(&self, venue: &mut Venue) -> Result<(), HKDError> {
+        if let Some(venue_id) = venue.id {
+            *venue = self.venues.get(venue_id)
+                .cloned()
+                .ok_or_else(|| HKDError::VenueNotFound(venue_id))?;
+        }
+        if venue.timezone.is_none() {
+            venue.timezone = infer_timezone(venue);
+        }
+        Ok(())
+    }
+
+    pub fn register_venue(&mut self, venue: Venue) -> Result<Venue, HKDError> {
+        self.venues.register(venue)
+    }
+
+    /// Replaces a registered venue and refreshes the copy held by its events,
+    /// provided every one of them still fits.
+    pub fn update_venue(&mut self, venue_id: Uuid, venue: Venue) -> Result<Venue, HKDError> {
+        let event_ids: Vec<Uuid> = self.events.values()
+            .filter(|event| event.venue.id == Some(venue_id))
+            .map(|event| event.id)
+            .collect();
+        let violations: Vec<Violation> = event_ids.iter()
+            .filter_map(|event_id| self.events.get(event_id))
+            .flat_map(|event| {
+                capacity_violations(&venue, &event.ticket_types).into_iter().map(move |violation| {
+                    Violation::new(format!("events[{}].{}", event.id, violation.field), violation.message)
+                })
+            })
+            .collect();
+        if !violations.is_empty() {
+            return Err(HKDError::ValidationFailed(violations));
+        }
+
+        let venue = self.venues.update(venue_id, venue)?;
+        for event_id in event_ids {
+            if let Some(mut event) = self.events.remove(&event_id) {
+                event.venue = venue.clone();
+                event.updated_at = chrono::Utc::now();
+                self.store_event(event);
+            }
+        }
+        Ok(venue)
+    }
+
+    pub fn get_venue(&self, venue_id: Uuid) -> Option<&Venue> {
+        self.venues.get(venue_id)
+    }
+
+    pub fn get_venues(&self) -> Vec<&Venue> {
+        self.venues.list()
+    }
+
+    /// Prices a ticket type in the buyer's currency and holds that price for
+    /// the quote TTL. Passing the quote's id with the purchase pays exactly it.
+    pub async fn quote_price(&mut self, event_id: Uuid, ticket_type_id: Uuid, currency: &str) -> Result<PriceQuote, HKDError> {
+        if !SUPPORTED_CURRENCIES.contains(&currency) {
+            return Err(HKDError::UnsupportedCurrency(currency.to_string()));
+        }
+        let ticket_type = self.events.get(&event_id)
+            .ok_or_else(|| HKDError::EventNotFound(event_id))?
+            .ticket_types.iter()
+            .find(|tt| tt.id == ticket_type_id)
+            .ok_or_else(|| HKDError::TicketTypeNotFound(ticket_type_id))?;
+
+        let (price, rate) = convert_price(self.fx_provider.as_ref(), ticket_type.price, &ticket_type.currency, currency).await?;
+        let quoted_at = chrono::Utc::now();
+        let quote = PriceQuote {
+            id: Uuid::new_v4(),
+            event_id,
+            ticket_type_id,
+            base_price: ticket_type.price,
+            base_currency: ticket_type.currency.clone(),
+            price,
+            currency: currency.to_string(),
+            rate,
+            quoted_at,
+            expires_at: quoted_at + self.quote_ttl,
+        };
+        self.quotes.retain(|_, quote| !quote.is_expired(quoted_at));
+        self.quotes.insert(quote.id, quote.clone());
+        Ok(quote)
+    }
+
     pub async fn purchase_tickets(
         &mut self,
         request: TicketPurchaseRequest,
     ) -> Result<TicketPurchaseResponse, HKDError> {
         let event = self.events.get(&request.event_id)
             .ok_or_else(|| HKDError::EventNotFound(request.event_id))?;
+        if event.status != EventStatus::OnSale {
+            return Err(HKDError::EventNotOnSale(event.status.clone()));
+        }
 
         let ticket_type = event.ticket_types.iter()
             .find(|tt| tt.id == request.ticket_type_id)
             .ok_or_else(|| HKDError::TicketTypeNotFound(request.ticket_type_id))?;
 
-        // Check availability
-        if ticket_type.quantity_available < request.quantity {
+        // Check availability against our channel's share of the stock
+        if channel_available(ticket_type, &EventPlatform::Internal, venue_remaining(event)) < request.quantity {
+            return Err(HKDError::InsufficientTickets);
+        }
+        if session_availability(event, ticket_type).is_some_and(|left| left < request.quantity) {
             return Err(HKDError::InsufficientTickets);
         }
 
-        // Calculate total amount
-        let total_amount = ticket_type.price * Decimal::from(request.quantity);
+        // Reserved seating: settle on the seats before taking payment
+        let seats = select_seats(event, ticket_type, &request.seats, request.quantity)?;
 
-        // Process payment through stablecoin engine
-        let payment_tx = if request.payment_currency == "HKD" {
-            // Use HKD stablecoin for payment
-            self.stablecoin_engine.transfer(
-                &request.buyer_wallet,
-                &event.organizer, // Assuming organizer is a wallet address
-                total_amount,
-                Some(crate::models::TransactionMetadata {
-                    reference: Some(format!("Ticket purchase for {}", event.title)),
-                    purpose: Some("event_tickets".to_string()),
-                    regulatory_approval_id: None,
-                }),
-            )?
-        } else {
-            // For other currencies, we'd integrate with traditional payment processors
-            // This is a simplified implementation
-            "external_payment_tx".to_string()
+        // Price in the buyer's currency: as quoted, or at the current rate
+        if !SUPPORTED_CURRENCIES.contains(&request.payment_currency.as_str()) {
+            return Err(HKDError::UnsupportedCurrency(request.payment_currency.clone()));
+        }
+        let method = payment_method_for(&request)?;
+        let (unit_price, fx_rate) = match request.quote_id {
+            Some(quote_id) => {
+                // A quote for another ticket type or currency doesn't apply here
+                let quote = self.quotes.get(&quote_id)
+                    .filter(|quote| quote.event_id == event.id && quote.ticket_type_id == ticket_type.id)
+                    .filter(|quote| quote.currency == request.payment_currency)
+                    .ok_or(HKDError::QuoteNotFound(quote_id))?;
+                if quote.is_expired(chrono::Utc::now()) {
+                    return Err(HKDError::QuoteExpired(quote_id));
+                }
+                (quote.price, quote.rate)
+            }
+            None => convert_price(
+                self.fx_provider.as_ref(),
+                ticket_type.price,
+                &ticket_type.currency,
+                &request.payment_currency,
+            ).await?,
         };
+        let total_amount = unit_price * Decimal::from(request.quantity);
 
-        // Create tickets
+        // Take payment on the buyer's rail. Stablecoin moves straight into the
+        // event's escrow; other rails are authorized now and captured once the tickets exist.
+        let payment = PaymentReference {
+            payment_id: self.authorize_payment(&request, &method, event, total_amount).await?,
+            method,
+            payer: request.buyer_wallet.clone(),
+        };
+
+        // Create tickets. A type whose transfer lock has already passed sells locked tickets
+        let transferable = ticket_type.transfer_policy.allows_transfer_at(chrono::Utc::now());
         let mut tickets = Vec::new();
         let mut nft_transactions = Vec::new();
 
@@ -115,50 +737,204 @@ impl TicketingService {
                 event_id: event.id,
                 ticket_type_id: ticket_type.id,
                 owner_wallet: request.buyer_wallet.clone(),
-                purchase_price: ticket_type.price,
+                purchase_price: unit_price,
                 purchase_currency: request.payment_currency.clone(),
                 purchase_date: chrono::Utc::now(),
                 status: TicketStatus::Active,
                 nft_token_id: None,
                 qr_code: format!("TICKET_{}_{}", event.id, ticket_id),
-                transferable: true,
-                resale_allowed: true,
+                transferable,
+                resale_allowed: transferable,
                 resale_price: None,
+                external_attendee_id: None,
+                seat: seats.get(i as usize).cloned(),
+                checked_in_sessions: Vec::new(),
+                previous_ticket_id: None,
+                refund_pending: false,
+                fx_rate: Some(fx_rate),
+                payment: Some(payment.clone()),
             };
 
             // Mint NFT if configured
             if let Some(nft_metadata) = &ticket_type.nft_metadata {
-                let token_id = self.nft_minter.mint_ticket_nft(&ticket, event, nft_metadata).await?;
+                let mut nft_metadata = nft_metadata.clone();
+                nft_metadata.attributes.extend(schedule_attributes(event));
+                if let Some(seat) = &ticket.seat {
+                    nft_metadata.attributes.extend(seat_attributes(seat));
+                }
+                let minted = self.nft_minter
+                    .mint_ticket_nft(&ticket, event, &nft_metadata, &ticket_type.transfer_policy)
+                    .await;
+                let token_id = match minted {
+                    Ok(token_id) => token_id,
+                    Err(e) => {
+                        self.abandon_purchase(&payment, event, total_amount, &nft_transactions).await;
+                        return Err(e);
+                    }
+                };
                 ticket.nft_token_id = Some(token_id.clone());
                 nft_transactions.push(token_id);
             }
 
-            tickets.push(ticket.clone());
-            self.tickets.insert(ticket_id, ticket);
+            tickets.push(ticket);
+        }
+
+        if let Err(e) = self.capture_payment(&payment).await {
+            self.abandon_purchase(&payment, event, total_amount, &nft_transactions).await;
+            return Err(e);
+        }
+        for ticket in &tickets {
+            self.tickets.insert(ticket.id, ticket.clone());
+        }
+        if payment.method == PaymentMethod::HkdStablecoin {
+            self.escrows.entry(request.event_id)
+                .or_insert_with(|| EventEscrow::new(request.event_id))
+                .collected += total_amount;
         }
 
-        // Update ticket type availability
-        // In a real implementation, we'd need to update the event's ticket types
+        // Each quote pays for one purchase
+        if let Some(quote_id) = request.quote_id {
+            self.quotes.remove(&quote_id);
+        }
+
+        // Update ticket type availability and hold the assigned seats
+        if let Some(event) = self.events.get_mut(&request.event_id) {
+            event.sold_seats.extend(seats);
+            if let Some(ticket_type) = event.ticket_types.iter_mut().find(|tt| tt.id == request.ticket_type_id) {
+                let sold = channel_sold(ticket_type, &EventPlatform::Internal) + request.quantity;
+                record_channel_sales(ticket_type, EventPlatform::Internal, sold);
+            }
+        }
+
+        // The last ticket sold takes the event off sale
+        let sold_out = self.events.get(&request.event_id).is_some_and(|event| remaining(event) == 0);
+        if sold_out {
+            self.record_transition(request.event_id, EventStatus::SoldOut, SYSTEM_ACTOR, None);
+        }
 
         Ok(TicketPurchaseResponse {
             purchase_id: Uuid::new_v4(),
             tickets,
             total_amount,
-            transaction_hash: Some(payment_tx),
+            currency: request.payment_currency,
+            transaction_hash: Some(payment.payment_id),
             nft_mint_transactions: nft_transactions,
         })
     }
 
+    // Charges the buyer, returning the transaction hash or provider payment id
+    async fn authorize_payment(
+        &self,
+        request: &TicketPurchaseRequest,
+        method: &PaymentMethod,
+        event: &Event,
+        amount: Decimal,
+    ) -> Result<String, HKDError> {
+        let reference = format!("Ticket purchase for {}", event.title);
+        match method {
+            PaymentMethod::HkdStablecoin => {
+                if request.payment_currency != "HKD" {
+                    return Err(HKDError::UnsupportedPaymentMethod(format!(
+                        "The HKD stablecoin can't pay in {}", request.payment_currency
+                    )));
+                }
+                // Held in the event's escrow until settlement or refund
+                self.stablecoin_engine.transfer(
+                    &request.buyer_wallet,
+                    &escrow_account(event.id),
+                    amount,
+                    Some(crate::models::TransactionMetadata {
+                        reference: Some(reference),
+                        purpose: Some("event_tickets".to_string()),
+                        regulatory_approval_id: None,
+                    }),
+                )
+            }
+            method => {
+                let payment_request = PaymentRequest {
+                    method: method.clone(),
+                    amount,
+                    currency: request.payment_currency.clone(),
+                    payer: request.buyer_wallet.clone(),
+                    reference,
+                };
+                self.payment_provider(method)?.authorize(&payment_request).await
+            }
+        }
+    }
+
+    async fn capture_payment(&self, payment: &PaymentReference) -> Result<(), HKDError> {
+        match &payment.method {
+            PaymentMethod::HkdStablecoin => Ok(()),
+            method => self.payment_provider(method)?.capture(&payment.payment_id).await,
+        }
+    }
+
+    // Undoes a purchase that failed after payment was taken: releases or
+    // returns the money and burns any NFTs already minted
+    async fn abandon_purchase(&self, payment: &PaymentReference, event: &Event, amount: Decimal, minted: &[String]) {
+        let released = match &payment.method {
+            PaymentMethod::HkdStablecoin => self.refund_payment(payment, event, amount, "Failed purchase").await,
+            method => match self.payment_provider(method) {
+                Ok(provider) => provider.void(&payment.payment_id).await,
+                Err(e) => Err(e),
+            },
+        };
+        if let Err(e) = released {
+            log::error!("Releasing payment {} for a failed purchase failed: {}", payment.payment_id, e);
+        }
+        for token_id in minted {
+            if let Err(e) = self.nft_minter.burn_nft(token_id).await {
+                log::warn!("Burning NFT {} from a failed purchase failed: {}", token_id, e);
+            }
+        }
+    }
+
+    // Sends `amount` back along the rail the payment came in on
+    async fn refund_payment(
+        &self,
+        payment: &PaymentReference,
+        event: &Event,
+        amount: Decimal,
+        reason: &str,
+    ) -> Result<(), HKDError> {
+        match &payment.method {
+            PaymentMethod::HkdStablecoin => self.stablecoin_engine.transfer(
+                &escrow_account(event.id),
+                &payment.payer,
+                amount,
+                Some(crate::models::TransactionMetadata {
+                    reference: Some(format!("{}: {}", reason, event.title)),
+                    purpose: Some("event_refund".to_string()),
+                    regulatory_approval_id: None,
+                }),
+            ).map(|_| ()),
+            method => self.payment_provider(method)?.refund(&payment.payment_id, amount).await.map(|_| ()),
+        }
+    }
+
+    fn payment_provider(&self, method: &PaymentMethod) -> Result<&Arc<dyn PaymentProvider + Send + Sync>, HKDError> {
+        self.payment_providers.get(method)
+            .ok_or_else(|| HKDError::UnsupportedPaymentMethod(format!("No provider registered for {:?}", method)))
+    }
+
     pub async fn list_ticket_for_resale(
         &mut self,
         ticket_id: Uuid,
         asking_price: Decimal,
         currency: String,
     ) -> Result<ResaleListing, HKDError> {
-        let ticket = self.tickets.get_mut(&ticket_id)
+        let ticket = self.tickets.get(&ticket_id)
             .ok_or_else(|| HKDError::TicketNotFound(ticket_id))?;
+        ensure_allowed(ticket, &TicketStatus::Resold)?;
 
-        if !ticket.resale_allowed {
+        let policy = transfer_policy_for(&self.events, ticket)?;
+        let event_over = self.events.get(&ticket.event_id)
+            .is_none_or(|event| matches!(event.status, EventStatus::Completed | EventStatus::Cancelled));
+        if !ticket.resale_allowed || event_over || !policy.allows_transfer_at(chrono::Utc::now()) {
+            return Err(HKDError::ResaleNotAllowed);
+        }
+        if ticket.refund_pending || self.open_listing(ticket_id).is_some() {
             return Err(HKDError::ResaleNotAllowed);
         }
 
@@ -171,39 +947,524 @@ impl TicketingService {
             listed_at: chrono::Utc::now(),
             status: ResaleStatus::Listed,
         };
+        self.listings.insert(listing.id, listing.clone());
 
         Ok(listing)
     }
 
+    /// Withdraws a listing that hasn't sold, freeing the ticket to move again.
+    pub fn cancel_resale_listing(&mut self, listing_id: Uuid) -> Result<ResaleListing, HKDError> {
+        let listing = self.listings.get_mut(&listing_id)
+            .filter(|listing| matches!(listing.status, ResaleStatus::Listed))
+            .ok_or(HKDError::ListingNotFound(listing_id))?;
+        listing.status = ResaleStatus::Cancelled;
+        Ok(listing.clone())
+    }
+
+    // The ticket's listing that is still up for sale, if any
+    fn open_listing(&self, ticket_id: Uuid) -> Option<&ResaleListing> {
+        self.listings.values()
+            .find(|listing| listing.ticket_id == ticket_id && matches!(listing.status, ResaleStatus::Listed))
+    }
+
+    // Takes down every open listing for the event's tickets
+    fn withdraw_listings(&mut self, event_id: Uuid) {
+        let tickets = &self.tickets;
+        for listing in self.listings.values_mut() {
+            let for_event = tickets.get(&listing.ticket_id).is_some_and(|ticket| ticket.event_id == event_id);
+            if for_event && matches!(listing.status, ResaleStatus::Listed) {
+                listing.status = ResaleStatus::Cancelled;
+            }
+        }
+    }
+
+    /// Hands a ticket to another wallet. The original is marked `Transferred`
+    /// and the recipient gets a reissued ticket with a fresh QR code.
+    pub async fn transfer_ticket(
+        &mut self,
+        ticket_id: Uuid,
+        to_wallet: String,
+    ) -> Result<Ticket, HKDError> {
+        let ticket = self.tickets.get(&ticket_id)
+            .ok_or_else(|| HKDError::TicketNotFound(ticket_id))?;
+        ensure_allowed(ticket, &TicketStatus::Transferred)?;
+
+        let policy = transfer_policy_for(&self.events, ticket)?;
+        if !ticket.transferable || !policy.allows_transfer_at(chrono::Utc::now()) {
+            return Err(HKDError::TransferNotAllowed);
+        }
+        // A listed ticket could be sold from under the recipient, and one owed a refund is being paid back
+        if ticket.refund_pending || self.open_listing(ticket_id).is_some() {
+            return Err(HKDError::TransferNotAllowed);
+        }
+
+        if let Some(token_id) = &ticket.nft_token_id {
+            self.nft_minter.transfer_nft(token_id, &to_wallet).await?;
+        }
+
+        let reissued = reissue(ticket, to_wallet);
+        if let Some(ticket) = self.tickets.get_mut(&ticket_id) {
+            transition(ticket, TicketStatus::Transferred)?;
+        }
+        self.tickets.insert(reissued.id, reissued.clone());
+        Ok(reissued)
+    }
+
     pub async fn purchase_resale_ticket(
         &mut self,
-        listing_id: Uuid,
-        buyer_wallet: String,
+        _listing_id: Uuid,
+        _buyer_wallet: String,
     ) -> Result<Ticket, HKDError> {
         // Implementation for resale purchase
         // This would handle the transfer of funds and NFT
         Err(HKDError::ExternalApiError("Not implemented".to_string()))
     }
 
-    async fn sync_event_to_external_platform(&self, event: &Event) -> Result<(), HKDError> {
-        match event.platform {
-            EventPlatform::Eventbrite => {
-                if let Some(client) = &self.eventbrite_client {
-                    client.create_eventbrite_event(event).await?;
-                }
+    /// Admits a ticket to the session running at `at`, marking it used once
+    /// every session it covers has been attended.
+    pub fn check_in_ticket(&mut self, ticket_id: Uuid, at: chrono::DateTime<chrono::Utc>) -> Result<Ticket, HKDError> {
+        let ticket = self.tickets.get_mut(&ticket_id)
+            .ok_or_else(|| HKDError::TicketNotFound(ticket_id))?;
+        let event = self.events.get(&ticket.event_id)
+            .ok_or_else(|| HKDError::EventNotFound(ticket.event_id))?;
+        let ticket_type = event.ticket_types.iter()
+            .find(|tt| tt.id == ticket.ticket_type_id)
+            .ok_or_else(|| HKDError::TicketTypeNotFound(ticket.ticket_type_id))?;
+
+        let session = session_for_check_in(event, ticket_type, ticket, at)?;
+        ticket.checked_in_sessions.push(session.id);
+        if is_fully_used(event, ticket_type, ticket) {
+            transition(ticket, TicketStatus::Used)?;
+        }
+        Ok(ticket.clone())
+    }
+
+    /// Mirrors an existing event to additional platforms.
+    pub async fn publish_event(&mut self, event_id: Uuid, platforms: &[EventPlatform]) -> Result<Event, HKDError> {
+        let event = self.events.get_mut(&event_id)
+            .ok_or_else(|| HKDError::EventNotFound(event_id))?;
+
+        for platform in platforms {
+            if *platform == EventPlatform::Internal || event.publications.contains_key(platform) {
+                continue;
+            }
+            event.publications.insert(platform.clone(), PlatformSync::pending(platform.clone()));
+            self.outbox.enqueue(event_id, platform.clone(), SyncOperation::Create);
+        }
+        Ok(event.clone())
+    }
+
+    /// Runs every due outbox job, recording the outcome on the event.
+    /// Returns how many jobs were attempted. Requests only queue jobs;
+    /// `run_sync_outbox_job` delivers them.
+    pub async fn process_sync_outbox(&mut self) -> usize {
+        let now = chrono::Utc::now();
+        let work = self.due_sync_work(now);
+        let attempted = work.len();
+        for mut item in work {
+            let result = push_event(&mut item).await;
+            self.finish_sync_job(item, result, now);
+        }
+        attempted
+    }
+
+    /// Due outbox jobs, each with a copy of its event and the client to push it with.
+    pub fn due_sync_work(&self, now: chrono::DateTime<chrono::Utc>) -> Vec<SyncWork> {
+        self.outbox.due_jobs(now)
+            .into_iter()
+            .filter_map(|job| Some(SyncWork {
+                event: self.events.get(&job.event_id)?.clone(),
+                client: self.platforms.get(&job.platform).cloned(),
+                job,
+            }))
+            .collect()
+    }
+
+    /// Records the outcome of a push on the outbox and the event's publication.
+    pub fn finish_sync_job(&mut self, work: SyncWork, result: Result<(), HKDError>, now: chrono::DateTime<chrono::Utc>) {
+        let SyncWork { job, event: pushed, .. } = work;
+        let (status, error) = match result {
+            Ok(()) if self.outbox.complete(&job) => (SyncStatus::Synced, None),
+            // Edited while the push was in flight; the newer version is still queued
+            Ok(()) => (SyncStatus::Pending, None),
+            Err(e) => {
+                let error = e.to_string();
+                (self.outbox.fail(job.id, error.clone(), now), Some(error))
+            }
+        };
+
+        if let Some(event) = self.events.get_mut(&job.event_id) {
+            // Keep whatever the platform already acknowledged, even if a later step failed
+            merge_acknowledged(event, &pushed, &job.platform);
+            if let Some(sync) = event.publications.get_mut(&job.platform) {
+                sync.status = status;
+                sync.attempts += 1;
+                sync.last_error = error;
+                sync.last_attempt_at = Some(now);
+            }
+        }
+    }
+
+    pub fn get_sync_status(&self, event_id: Uuid, platform: &EventPlatform) -> Option<&PlatformSync> {
+        self.events.get(&event_id).and_then(|event| event.publications.get(platform))
+    }
+
+    pub fn get_publications(&self, event_id: Uuid) -> Option<&HashMap<EventPlatform, PlatformSync>> {
+        self.events.get(&event_id).map(|event| &event.publications)
+    }
+
+    pub fn pending_sync_jobs(&self) -> Vec<&SyncJob> {
+        self.outbox.pending_jobs()
+    }
+
+    /// Re-queues a publication whose sync gave up after exhausting its retries.
+    pub fn retry_event_sync(&mut self, event_id: Uuid, platform: &EventPlatform) -> Result<(), HKDError> {
+        let event = self.events.get_mut(&event_id)
+            .ok_or_else(|| HKDError::EventNotFound(event_id))?;
+        let sync = match event.publications.get_mut(platform) {
+            Some(sync) => sync,
+            None => return Ok(()),
+        };
+
+        let operation = if sync.external_id.is_some() {
+            SyncOperation::Update
+        } else {
+            SyncOperation::Create
+        };
+        sync.status = SyncStatus::Pending;
+        sync.attempts = 0;
+        self.outbox.enqueue(event_id, sync.platform.clone(), operation);
+        Ok(())
+    }
+
+    pub fn externally_synced_event_ids(&self) -> Vec<Uuid> {
+        self.events.values()
+            .filter(|event| {
+                event.external_event_id.is_some()
+                    || event.publications.values().any(|publication| publication.external_id.is_some())
+            })
+            .map(|event| event.id)
+            .collect()
+    }
+
+    /// Pulls each external channel's sales into `channel_sales`, then pushes
+    /// every channel's remaining allowance back out so combined sales stay
+    /// within both the ticket type's stock and the venue capacity.
+    pub async fn sync_inventory(&mut self, event_id: Uuid) -> Result<InventorySyncReport, HKDError> {
+        let channels = self.inventory_channels(event_id)?;
+        let sales = pull_channel_sales(&channels).await?;
+        let (mut report, limits) = self.apply_channel_sales(event_id, &channels, &sales)?;
+        report.pushed = push_channel_limits(&limits).await?;
+        Ok(report)
+    }
+
+    /// The event's ticket types listed on registered platforms.
+    pub fn inventory_channels(&self, event_id: Uuid) -> Result<Vec<InventoryChannel>, HKDError> {
+        let event = self.events.get(&event_id)
+            .ok_or_else(|| HKDError::EventNotFound(event_id))?;
+
+        let mut channels = Vec::new();
+        for ticket_type in &event.ticket_types {
+            for (platform, external_ticket_id) in &ticket_type.external_ids {
+                let (client, external_event_id) = match (self.platforms.get(platform), event.external_id_on(platform)) {
+                    (Some(client), Some(external_event_id)) => (client, external_event_id),
+                    _ => continue,
+                };
+                channels.push(InventoryChannel {
+                    ticket_type_id: ticket_type.id,
+                    platform: platform.clone(),
+                    external_event_id: external_event_id.to_string(),
+                    external_ticket_id: external_ticket_id.clone(),
+                    client: client.clone(),
+                });
             }
-            EventPlatform::Ticketmaster => {
-                if let Some(client) = &self.ticketmaster_client {
-                    client.create_ticketmaster_event(event).await?;
+        }
+        Ok(channels)
+    }
+
+    /// Records the sales pulled from `channels` and works out the total each
+    /// may now sell; oversold channels are capped at what they sold.
+    pub fn apply_channel_sales(
+        &mut self,
+        event_id: Uuid,
+        channels: &[InventoryChannel],
+        sales: &[u32],
+    ) -> Result<(InventorySyncReport, Vec<(InventoryChannel, u32)>), HKDError> {
+        let event = self.events.get_mut(&event_id)
+            .ok_or_else(|| HKDError::EventNotFound(event_id))?;
+        let mut report = InventorySyncReport::new(event_id);
+
+        for (channel, sold) in channels.iter().zip(sales) {
+            if let Some(ticket_type) = event.ticket_types.iter_mut().find(|tt| tt.id == channel.ticket_type_id) {
+                if record_channel_sales(ticket_type, channel.platform.clone(), *sold) {
+                    report.pulled += 1;
                 }
             }
-            EventPlatform::Cvent => {
-                if let Some(client) = &self.cvent_client {
-                    client.create_cvent_event(event).await?;
+        }
+
+        for ticket_type in &event.ticket_types {
+            if ticket_type.quantity_sold > ticket_type.quantity_available {
+                report.conflicts.push(InventoryConflict {
+                    ticket_type_id: Some(ticket_type.id),
+                    oversold: ticket_type.quantity_sold - ticket_type.quantity_available,
+                });
+            }
+        }
+        let sold = total_sold(event);
+        if sold > u64::from(event.venue.capacity) {
+            report.conflicts.push(InventoryConflict {
+                ticket_type_id: None,
+                oversold: u32::try_from(sold - u64::from(event.venue.capacity)).unwrap_or(u32::MAX),
+            });
+        }
+
+        let remaining = venue_remaining(event);
+        let limits = channels.iter()
+            .filter_map(|channel| {
+                let ticket_type = event.ticket_types.iter().find(|tt| tt.id == channel.ticket_type_id)?;
+                Some((channel.clone(), channel_limit(ticket_type, &channel.platform, remaining)))
+            })
+            .collect();
+        Ok((report, limits))
+    }
+
+    pub fn registered_platforms(&self) -> Vec<EventPlatform> {
+        self.platforms.keys().cloned().collect()
+    }
+
+    /// Registered clients, for jobs that call the platforms without holding the service.
+    pub fn platform_clients(&self) -> Vec<(EventPlatform, Arc<dyn TicketingPlatform>)> {
+        self.platforms.iter()
+            .map(|(platform, client)| (platform.clone(), client.clone()))
+            .collect()
+    }
+
+    fn platform_client(&self, platform: &EventPlatform) -> Result<Arc<dyn TicketingPlatform>, HKDError> {
+        self.platforms.get(platform)
+            .cloned()
+            .ok_or_else(|| HKDError::ExternalApiError(format!("No client registered for {:?}", platform)))
+    }
+
+    /// Pulls events organizers created directly on an external platform,
+    /// upserting them by `external_event_id`.
+    pub async fn import_events(
+        &mut self,
+        platform: EventPlatform,
+        filters: EventFilters,
+    ) -> Result<ImportReport, HKDError> {
+        let external_events = self.platform_client(&platform)?.list_events(filters).await?;
+        Ok(self.apply_imported_events(platform, external_events).await)
+    }
+
+    /// Upserts events already fetched from `platform`.
+    pub async fn apply_imported_events(&mut self, platform: EventPlatform, external_events: Vec<Event>) -> ImportReport {
+        let mut report = ImportReport::new(platform);
+        for imported in external_events {
+            // Rejected events are listed on the report; the rest still import
+            let _ = self.upsert_imported_event(imported, &mut report).await;
+        }
+        report
+    }
+
+    /// Stores an event fetched from an external platform, returning its internal id.
+    async fn upsert_imported_event(&mut self, imported: Event, report: &mut ImportReport) -> Result<Uuid, HKDError> {
+        let existing = imported.external_event_id.as_deref().and_then(|external_event_id| {
+            self.events.values().find(|event| event.external_id_on(&imported.platform) == Some(external_event_id))
+        });
+
+        let previous_status = existing.map(|event| event.status.clone());
+        let mut event = match reconcile_imported_event(existing, imported) {
+            ImportOutcome::Unchanged(event_id) => {
+                report.unchanged += 1;
+                return Ok(event_id);
+            }
+            ImportOutcome::Created(event) | ImportOutcome::Updated(event) => event,
+        };
+
+        // Held to the same rules as local events; the stored copy stays as it was
+        let violations = validate_event(&event);
+        if !violations.is_empty() {
+            report.rejected.push(ImportRejection {
+                external_event_id: event.external_event_id.clone(),
+                violations: violations.clone(),
+            });
+            return Err(HKDError::ValidationFailed(violations));
+        }
+
+        if existing.is_some() {
+            report.updated += 1;
+        } else {
+            report.created += 1;
+        }
+        event.version += 1;
+        let event_id = event.id;
+
+        // Status changes go through the lifecycle like local ones, side effects included
+        let status_change = previous_status.and_then(|from| {
+            let to = std::mem::replace(&mut event.status, from.clone());
+            (to != from).then_some(to)
+        });
+        self.store_event(event);
+        if let Some(to) = status_change {
+            let actor = format!("{:?} import", report.platform);
+            if let Err(e) = self.transition_event(event_id, to, &actor, None).await {
+                log::warn!("Ignoring {:?} status change on event {}: {}", report.platform, event_id, e);
+            }
+        }
+        Ok(event_id)
+    }
+
+    fn find_external_event(&self, platform: &EventPlatform, external_event_id: &str) -> Option<Uuid> {
+        self.events.values()
+            .find(|event| event.external_id_on(platform) == Some(external_event_id))
+            .map(|event| event.id)
+    }
+
+    /// Applies a verified platform notification to local state.
+    pub async fn apply_webhook_event(&mut self, platform: EventPlatform, event: WebhookEvent) -> Result<(), HKDError> {
+        let client = self.platforms.get(&platform)
+            .ok_or_else(|| HKDError::ExternalApiError(format!("No client registered for {:?}", platform)))?;
+
+        let event = match event {
+            WebhookEvent::OrderReference { order_id } => {
+                WebhookEvent::OrderPlaced(client.get_order(&order_id).await?)
+            }
+            WebhookEvent::AttendeeReference { event_id, attendee_id } => {
+                WebhookEvent::AttendeeUpdated(client.get_attendee(&event_id, &attendee_id).await?)
+            }
+            other => other,
+        };
+
+        match event {
+            WebhookEvent::OrderPlaced(order) => self.apply_external_order(platform, order).await,
+            WebhookEvent::EventUpdated { external_event_id } => {
+                self.refresh_external_event(platform, &external_event_id).await.map(|_| ())
+            }
+            WebhookEvent::AttendeeUpdated(attendee) => self.apply_attendee_update(platform, attendee).await,
+            _ => Ok(()),
+        }
+    }
+
+    async fn refresh_external_event(&mut self, platform: EventPlatform, external_event_id: &str) -> Result<Uuid, HKDError> {
+        let client = self.platforms.get(&platform)
+            .ok_or_else(|| HKDError::ExternalApiError(format!("No client registered for {:?}", platform)))?;
+        let imported = client.get_event(external_event_id).await?;
+
+        let mut report = ImportReport::new(platform);
+        self.upsert_imported_event(imported, &mut report).await
+    }
+
+    /// Turns an order placed on an external platform into local tickets.
+    /// Attendees that already have a ticket are skipped, so redeliveries are harmless.
+    async fn apply_external_order(&mut self, platform: EventPlatform, order: ExternalOrder) -> Result<(), HKDError> {
+        let event_id = match self.find_external_event(&platform, &order.external_event_id) {
+            Some(event_id) => event_id,
+            None => self.refresh_external_event(platform.clone(), &order.external_event_id).await?,
+        };
+        let event = self.events.get_mut(&event_id)
+            .ok_or_else(|| HKDError::EventNotFound(event_id))?;
+
+        for attendee in order.attendees {
+            let already_issued = self.tickets.values()
+                .any(|ticket| ticket.external_attendee_id.as_ref() == Some(&attendee.external_attendee_id));
+            if already_issued {
+                continue;
+            }
+
+            let ticket_type = event.ticket_types.iter_mut()
+                .find(|tt| tt.external_ids.get(&platform) == Some(&attendee.external_ticket_type_id))
+                .ok_or_else(|| HKDError::ExternalApiError(format!(
+                    "Unknown {:?} ticket type {}", platform, attendee.external_ticket_type_id
+                )))?;
+
+            let transferable = ticket_type.transfer_policy.allows_transfer_at(chrono::Utc::now());
+            let ticket_id = Uuid::new_v4();
+            let ticket = Ticket {
+                id: ticket_id,
+                event_id,
+                ticket_type_id: ticket_type.id,
+                owner_wallet: format!("external:{:?}:{}", platform, attendee.buyer_reference),
+                purchase_price: ticket_type.price,
+                purchase_currency: ticket_type.currency.clone(),
+                purchase_date: chrono::Utc::now(),
+                status: ticket_status_for(&attendee.status),
+                nft_token_id: None,
+                qr_code: format!("TICKET_{}_{}", event_id, ticket_id),
+                transferable,
+                resale_allowed: transferable,
+                resale_price: None,
+                external_attendee_id: Some(attendee.external_attendee_id),
+                seat: None,
+                checked_in_sessions: Vec::new(),
+                previous_ticket_id: None,
+                refund_pending: false,
+                fx_rate: None,
+                payment: None,
+            };
+            self.tickets.insert(ticket_id, ticket);
+
+            let sold = channel_sold(ticket_type, &platform) + 1;
+            record_channel_sales(ticket_type, platform.clone(), sold);
+        }
+
+        Ok(())
+    }
+
+    async fn apply_attendee_update(&mut self, platform: EventPlatform, attendee: ExternalAttendee) -> Result<(), HKDError> {
+        let ticket = self.tickets.values_mut()
+            .find(|ticket| ticket.external_attendee_id.as_ref() == Some(&attendee.external_attendee_id));
+
+        match ticket {
+            Some(ticket) => {
+                let status = ticket_status_for(&attendee.status);
+                if ticket.status != status {
+                    // Late or replayed notifications can't revive a closed ticket
+                    if let Err(e) = transition(ticket, status) {
+                        log::warn!("Ignoring {:?} attendee update: {}", platform, e);
+                    }
                 }
+                Ok(())
+            }
+            // The update overtook the order delivery
+            None => {
+                let order = ExternalOrder {
+                    external_order_id: attendee.external_attendee_id.clone(),
+                    external_event_id: attendee.external_event_id.clone(),
+                    attendees: vec![attendee],
+                };
+                self.apply_external_order(platform, order).await
+            }
+        }
+    }
+
+    /// Applies a payment provider notification. Refunds and disputes raised on
+    /// the provider's side close the live tickets the payment bought and put
+    /// them back into stock.
+    pub async fn apply_payment_webhook(
+        &mut self,
+        method: &PaymentMethod,
+        headers: &HashMap<String, String>,
+        body: &[u8],
+    ) -> Result<(), HKDError> {
+        let (payment_id, status) = match self.payment_provider(method)?.parse_webhook(headers, body)? {
+            PaymentWebhookEvent::Refunded { payment_id } => (payment_id, TicketStatus::Refunded),
+            PaymentWebhookEvent::Disputed { payment_id } => (payment_id, TicketStatus::Cancelled),
+            PaymentWebhookEvent::Captured { .. } | PaymentWebhookEvent::Ignored { .. } => return Ok(()),
+        };
+
+        let paid_by = |ticket: &Ticket| ticket.payment.as_ref().is_some_and(|payment| payment.payment_id == payment_id);
+        let settled: Vec<Ticket> = self.tickets.values()
+            .filter(|ticket| paid_by(ticket) && ticket.status == TicketStatus::Active)
+            .cloned()
+            .collect();
+        for ticket in settled {
+            // A refund we asked for released its stock when it was requested
+            if !ticket.refund_pending {
+                self.release_ticket_stock(&ticket);
             }
-            EventPlatform::Internal => {
-                // No external sync needed
+            if let Some(ticket) = self.tickets.get_mut(&ticket.id) {
+                ticket.refund_pending = false;
+                transition(ticket, status.clone())?;
             }
         }
         Ok(())
@@ -213,8 +1474,39 @@ impl TicketingService {
         self.events.get(&event_id)
     }
 
+    /// Every event, soonest first.
     pub fn get_events(&self) -> Vec<&Event> {
-        self.events.values().collect()
+        let mut events: Vec<&Event> = self.events.values().collect();
+        events.sort_by(|a, b| a.event_date.cmp(&b.event_date).then_with(|| a.id.cmp(&b.id)));
+        events
+    }
+
+    pub fn search_events(&self, query: &EventQuery) -> EventSearchResults<'_> {
+        search_events(self.events.values(), query, chrono::Utc::now())
+    }
+
+    /// Unsold seats for a reserved seating ticket type, best first.
+    pub fn get_available_seats(&self, event_id: Uuid, ticket_type_id: Uuid) -> Result<Vec<SeatAssignment>, HKDError> {
+        let event = self.events.get(&event_id)
+            .ok_or_else(|| HKDError::EventNotFound(event_id))?;
+        let ticket_type = event.ticket_types.iter()
+            .find(|tt| tt.id == ticket_type_id)
+            .ok_or_else(|| HKDError::TicketTypeNotFound(ticket_type_id))?;
+
+        Ok(ticket_type.price_zone.as_deref()
+            .map(|zone| open_seats(event, zone))
+            .unwrap_or_default())
+    }
+
+    pub fn events_near(&self, query: &NearbyQuery) -> Result<Vec<NearbyEvent<'_>>, HKDError> {
+        events_near(&self.geo_index, &self.events, query, chrono::Utc::now())
+    }
+
+    /// Inserts or replaces an event, keeping the venue index and local times in step.
+    fn store_event(&mut self, mut event: Event) {
+        event.local_times = Some(local_times(&event));
+        self.geo_index.upsert(event.id, event.venue.coordinates.as_ref());
+        self.events.insert(event.id, event);
     }
 
     pub fn get_user_tickets(&self, wallet_address: &str) -> Vec<&Ticket> {
@@ -223,3 +1515,284 @@ impl TicketingService {
             .collect()
     }
 }
+
+fn transfer_policy_for<'a>(
+    events: &'a HashMap<Uuid, Event>,
+    ticket: &Ticket,
+) -> Result<&'a TransferPolicy, HKDError> {
+    let event = events.get(&ticket.event_id)
+        .ok_or_else(|| HKDError::EventNotFound(ticket.event_id))?;
+
+    event.ticket_types.iter()
+        .find(|tt| tt.id == ticket.ticket_type_id)
+        .map(|tt| &tt.transfer_policy)
+        .ok_or_else(|| HKDError::TicketTypeNotFound(ticket.ticket_type_id))
+}
+
+// Only an HKD purchase can leave the rail out; other currencies have no obvious one
+fn payment_method_for(request: &TicketPurchaseRequest) -> Result<PaymentMethod, HKDError> {
+    match &request.payment_method {
+        Some(method) => Ok(method.clone()),
+        None if request.payment_currency == "HKD" => Ok(PaymentMethod::HkdStablecoin),
+        None => Err(HKDError::ValidationFailed(vec![Violation::new(
+            "payment_method",
+            format!("is required when paying in {}", request.payment_currency),
+        )])),
+    }
+}
+
+fn ticket_status_for(status: &AttendeeStatus) -> TicketStatus {
+    match status {
+        AttendeeStatus::Active => TicketStatus::Active,
+        AttendeeStatus::CheckedIn => TicketStatus::Used,
+        AttendeeStatus::Cancelled => TicketStatus::Cancelled,
+        AttendeeStatus::Refunded => TicketStatus::Refunded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use crate::services::fake_platform::FakePlatform;
+    use crate::services::payments::FakePaymentProvider;
+
+    // An Eventbrite-listed event with one ticket type also sold on Eventbrite
+    fn mirrored_service(eventbrite: &FakePlatform, quantity: u32, sold_here: u32) -> (TicketingService, Uuid) {
+        let mut event = fixtures::event();
+        event.platform = EventPlatform::Eventbrite;
+        event.external_event_id = Some("eb-1001".to_string());
+        let ticket_type = &mut event.ticket_types[0];
+        ticket_type.quantity_available = quantity;
+        ticket_type.external_ids.insert(EventPlatform::Eventbrite, "tc-1".to_string());
+        record_channel_sales(ticket_type, EventPlatform::Internal, sold_here);
+
+        let mut service = TicketingService::new(Arc::new(HKDEngine::new()))
+            .with_platform(Box::new(eventbrite.clone()));
+        let event_id = event.id;
+        service.events.insert(event_id, event);
+        (service, event_id)
+    }
+
+    #[tokio::test]
+    async fn sync_pulls_external_sales_and_pushes_what_is_left() {
+        let eventbrite = FakePlatform::new(EventPlatform::Eventbrite);
+        eventbrite.set_sold("tc-1", 30);
+        let (mut service, event_id) = mirrored_service(&eventbrite, 100, 10);
+
+        let report = service.sync_inventory(event_id).await.unwrap();
+        assert_eq!((report.pulled, report.pushed), (1, 1));
+        assert!(report.conflicts.is_empty());
+
+        let ticket_type = &service.get_event(event_id).unwrap().ticket_types[0];
+        assert_eq!(ticket_type.quantity_sold, 40);
+        // Eventbrite keeps its 30 and may sell the 60 nobody has sold yet
+        assert_eq!(eventbrite.quantity_total("tc-1"), Some(90));
+
+        // Nothing moved, so nothing is pulled the second time round
+        let report = service.sync_inventory(event_id).await.unwrap();
+        assert_eq!((report.pulled, report.pushed), (0, 1));
+    }
+
+    #[tokio::test]
+    async fn oversold_channels_are_reported_and_closed() {
+        let eventbrite = FakePlatform::new(EventPlatform::Eventbrite);
+        eventbrite.set_sold("tc-1", 95);
+        let (mut service, event_id) = mirrored_service(&eventbrite, 100, 10);
+
+        let report = service.sync_inventory(event_id).await.unwrap();
+        assert_eq!(report.conflicts.len(), 1);
+        assert_eq!(report.conflicts[0].oversold, 5);
+        assert_eq!(eventbrite.quantity_total("tc-1"), Some(95));
+    }
+
+    #[test]
+    fn only_hkd_purchases_may_leave_out_the_payment_method() {
+        let event = fixtures::event();
+        let request = |currency: &str, payment_method: Option<PaymentMethod>| TicketPurchaseRequest {
+            event_id: event.id,
+            ticket_type_id: event.ticket_types[0].id,
+            quantity: 1,
+            buyer_wallet: "wallet:buyer".to_string(),
+            payment_currency: currency.to_string(),
+            seats: SeatSelection::default(),
+            quote_id: None,
+            payment_method,
+        };
+
+        assert_eq!(payment_method_for(&request("HKD", None)).unwrap(), PaymentMethod::HkdStablecoin);
+        assert_eq!(payment_method_for(&request("USD", Some(PaymentMethod::Card))).unwrap(), PaymentMethod::Card);
+        match payment_method_for(&request("USD", None)) {
+            Err(HKDError::ValidationFailed(violations)) => assert_eq!(violations[0].field, "payment_method"),
+            other => panic!("expected a missing payment method to be rejected, got {:?}", other),
+        }
+    }
+
+    // An on-sale event whose only ticket has been sold, and that ticket
+    fn sold_out_service() -> (TicketingService, Uuid, Uuid) {
+        let engine = Arc::new(HKDEngine::new());
+        let mut event = fixtures::event();
+        event.status = EventStatus::SoldOut;
+        let ticket_type = &mut event.ticket_types[0];
+        ticket_type.quantity_available = 1;
+        record_channel_sales(ticket_type, EventPlatform::Internal, 1);
+        let mut ticket = fixtures::ticket(&event);
+        ticket.purchase_date = chrono::Utc::now() - chrono::Duration::days(1);
+        engine.mint(&escrow_account(event.id), ticket.purchase_price).unwrap();
+
+        let mut service = TicketingService::new(engine);
+        let (event_id, ticket_id) = (event.id, ticket.id);
+        service.revisions.insert(event_id, vec![EventRevision {
+            event_id,
+            version: 2,
+            changed_at: chrono::Utc::now(),
+            changes: Vec::new(),
+            refund_window_ends: Some(chrono::Utc::now() + chrono::Duration::days(7)),
+        }]);
+        service.events.insert(event_id, event);
+        service.tickets.insert(ticket_id, ticket);
+        (service, event_id, ticket_id)
+    }
+
+    #[tokio::test]
+    async fn a_refund_puts_a_sold_out_event_back_on_sale() {
+        let (mut service, event_id, ticket_id) = sold_out_service();
+
+        service.request_change_refund(ticket_id).await.unwrap();
+        let event = service.get_event(event_id).unwrap();
+        assert_eq!(event.ticket_types[0].quantity_sold, 0);
+        assert_eq!(event.status, EventStatus::OnSale);
+        assert_eq!(service.get_status_history(event_id).last().unwrap().actor, SYSTEM_ACTOR);
+    }
+
+    #[tokio::test]
+    async fn a_chargeback_releases_the_ticket_back_into_stock() {
+        let (service, event_id, ticket_id) = sold_out_service();
+        let mut service = service.with_payment_provider(Arc::new(FakePaymentProvider::new(vec![PaymentMethod::Card])));
+        let payment = service.tickets.get_mut(&ticket_id).unwrap().payment.as_mut().unwrap();
+        payment.method = PaymentMethod::Card;
+        payment.payment_id = "fake_pay_1".to_string();
+
+        let body = br#"{"type": "payment.disputed", "payment_id": "fake_pay_1"}"#;
+        service.apply_payment_webhook(&PaymentMethod::Card, &HashMap::new(), body).await.unwrap();
+        // Redelivered, it finds nothing left to release
+        service.apply_payment_webhook(&PaymentMethod::Card, &HashMap::new(), body).await.unwrap();
+
+        assert_eq!(service.tickets[&ticket_id].status, TicketStatus::Cancelled);
+        let event = service.get_event(event_id).unwrap();
+        assert_eq!(event.ticket_types[0].quantity_sold, 0);
+        assert_eq!(channel_sold(&event.ticket_types[0], &EventPlatform::Internal), 0);
+        assert_eq!(event.status, EventStatus::OnSale);
+    }
+
+    #[tokio::test]
+    async fn partial_publish_failures_only_retry_the_failed_platform() {
+        let eventbrite = FakePlatform::new(EventPlatform::Eventbrite);
+        let cvent = FakePlatform::new(EventPlatform::Cvent);
+        cvent.fail_with("503 Service Unavailable");
+        let mut service = TicketingService::new(Arc::new(HKDEngine::new()))
+            .with_platform(Box::new(eventbrite.clone()))
+            .with_platform(Box::new(cvent.clone()))
+            .with_retry_policy(RetryPolicy { max_attempts: 5, base_delay_secs: 0, max_delay_secs: 0 });
+
+        let event = service.create_event(fixtures::event()).await.unwrap();
+        service.publish_event(event.id, &[EventPlatform::Eventbrite, EventPlatform::Cvent]).await.unwrap();
+        // Publishing only queues the pushes; the outbox delivers them
+        assert_eq!(service.process_sync_outbox().await, 2);
+        let event = service.get_event(event.id).unwrap().clone();
+
+        let published = &event.publications[&EventPlatform::Eventbrite];
+        assert_eq!(published.status, SyncStatus::Synced);
+        assert_eq!(published.external_id, Some(format!("fake-{}", event.id)));
+        assert!(event.ticket_types[0].external_ids.contains_key(&EventPlatform::Eventbrite));
+
+        let failed = &event.publications[&EventPlatform::Cvent];
+        assert_eq!(failed.status, SyncStatus::Pending);
+        assert_eq!(failed.external_id, None);
+        assert!(failed.last_error.as_deref().unwrap().contains("503"));
+
+        let queued: Vec<EventPlatform> = service.outbox.jobs_for_event(event.id).iter()
+            .map(|job| job.platform.clone())
+            .collect();
+        assert_eq!(queued, vec![EventPlatform::Cvent]);
+
+        // The retry only goes to Cvent
+        cvent.recover();
+        assert_eq!(service.process_sync_outbox().await, 1);
+        let event = service.get_event(event.id).unwrap();
+        assert_eq!(event.publications[&EventPlatform::Cvent].status, SyncStatus::Synced);
+        assert_eq!(eventbrite.created(), vec![event.id]);
+        assert_eq!(cvent.created(), vec![event.id]);
+        assert!(service.outbox.jobs_for_event(event.id).is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_create_job_for_an_event_the_platform_has_updates_it() {
+        let eventbrite = FakePlatform::new(EventPlatform::Eventbrite);
+        let mut service = TicketingService::new(Arc::new(HKDEngine::new()))
+            .with_platform(Box::new(eventbrite.clone()));
+        let event = service.create_event(fixtures::event()).await.unwrap();
+        service.publish_event(event.id, &[EventPlatform::Eventbrite]).await.unwrap();
+        service.process_sync_outbox().await;
+
+        // An update folded into a retried `Create` after the event went up
+        service.outbox.enqueue(event.id, EventPlatform::Eventbrite, SyncOperation::Create);
+        assert_eq!(service.process_sync_outbox().await, 1);
+        assert_eq!(eventbrite.created(), vec![event.id]);
+        assert_eq!(eventbrite.updated(), vec![format!("fake-{}", event.id)]);
+        assert!(service.outbox.jobs_for_event(event.id).is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_local_event_published_and_imported_back_stays_ours() {
+        let eventbrite = FakePlatform::new(EventPlatform::Eventbrite);
+        let mut service = TicketingService::new(Arc::new(HKDEngine::new()))
+            .with_platform(Box::new(eventbrite.clone()));
+        let mut draft = fixtures::event();
+        // Local events originate here whatever they claim; mirrors are asked for explicitly
+        draft.platform = EventPlatform::Eventbrite;
+        let event = service.create_event(draft).await.unwrap();
+        assert_eq!(event.platform, EventPlatform::Internal);
+        assert!(event.publications.is_empty());
+        service.publish_event(event.id, &[EventPlatform::Eventbrite]).await.unwrap();
+        service.process_sync_outbox().await;
+        let event = service.get_event(event.id).unwrap().clone();
+
+        // The platform lists it back with its own title and some sales of its own
+        let external_id = event.external_id_on(&EventPlatform::Eventbrite).unwrap().to_string();
+        let mut listed = event.clone();
+        listed.id = Uuid::new_v4();
+        listed.platform = EventPlatform::Eventbrite;
+        listed.external_event_id = Some(external_id);
+        listed.title = "Harbour Lights".to_string();
+        listed.publications.clear();
+        listed.ticket_types[0].channel_sales.insert(EventPlatform::Eventbrite, 12);
+
+        let report = service.apply_imported_events(EventPlatform::Eventbrite, vec![listed]).await;
+        assert_eq!((report.created, report.updated), (0, 1));
+        let imported = service.get_event(event.id).unwrap();
+        assert_eq!(imported.title, event.title);
+        assert_eq!(imported.platform, EventPlatform::Internal);
+        assert_eq!(imported.organizer, event.organizer);
+        assert_eq!(imported.ticket_types[0].quantity_sold, 12);
+    }
+
+    #[tokio::test]
+    async fn imports_create_then_leave_unchanged_events_alone() {
+        let eventbrite = FakePlatform::new(EventPlatform::Eventbrite);
+        let mut listed = fixtures::event();
+        listed.platform = EventPlatform::Eventbrite;
+        listed.external_event_id = Some("eb-2002".to_string());
+        eventbrite.state.lock().unwrap().listed.push(listed);
+        let mut service = TicketingService::new(Arc::new(HKDEngine::new()))
+            .with_platform(Box::new(eventbrite.clone()));
+
+        let report = service.import_events(EventPlatform::Eventbrite, EventFilters::default()).await.unwrap();
+        assert_eq!((report.created, report.updated, report.unchanged), (1, 0, 0));
+
+        // A job that listed the events without the service gets the same outcome
+        let external_events = eventbrite.list_events(EventFilters::default()).await.unwrap();
+        let report = service.apply_imported_events(EventPlatform::Eventbrite, external_events).await;
+        assert_eq!((report.created, report.updated, report.unchanged), (0, 0, 1));
+    }
+}
@@ -0,0 +1,268 @@
+// src/services/venue_registry.rs
+use crate::models::ticketing::*;
+use crate::error::HKDError;
+use crate::services::event_validation::Violation;
+use crate::services::schedule::infer_timezone;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Venues shared between events, keyed by the id assigned on registration.
+#[derive(Debug, Default)]
+pub struct VenueRegistry {
+    venues: HashMap<Uuid, Venue>,
+}
+
+impl VenueRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, venue: Venue) -> Result<Venue, HKDError> {
+        self.store(Uuid::new_v4(), venue)
+    }
+
+    pub fn update(&mut self, venue_id: Uuid, venue: Venue) -> Result<Venue, HKDError> {
+        if !self.venues.contains_key(&venue_id) {
+            return Err(HKDError::VenueNotFound(venue_id));
+        }
+        self.store(venue_id, venue)
+    }
+
+    pub fn get(&self, venue_id: Uuid) -> Option<&Venue> {
+        self.venues.get(&venue_id)
+    }
+
+    pub fn list(&self) -> Vec<&Venue> {
+        let mut venues: Vec<&Venue> = self.venues.values().collect();
+        venues.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+        venues
+    }
+
+    fn store(&mut self, venue_id: Uuid, mut venue: Venue) -> Result<Venue, HKDError> {
+        let violations = venue_violations(&venue);
+        if !violations.is_empty() {
+            return Err(HKDError::ValidationFailed(violations));
+        }
+        venue.id = Some(venue_id);
+        if venue.timezone.is_none() {
+            venue.timezone = infer_timezone(&venue);
+        }
+        self.venues.insert(venue_id, venue.clone());
+        Ok(venue)
+    }
+}
+
+// Areas and seating zones must fit inside the venue
+fn venue_violations(venue: &Venue) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    if venue.name.trim().is_empty() {
+        violations.push(Violation::new("name", "must not be empty"));
+    }
+
+    let area_capacity: u64 = venue.areas.iter().map(|area| u64::from(area.capacity)).sum();
+    if area_capacity > u64::from(venue.capacity) {
+        violations.push(Violation::new(
+            "areas",
+            format!("areas hold {} but the venue only {}", area_capacity, venue.capacity),
+        ));
+    }
+    for (index, area) in venue.areas.iter().enumerate() {
+        if venue.areas[..index].iter().any(|other| other.name == area.name) {
+            violations.push(Violation::new(format!("areas[{}].name", index), format!("{} is listed twice", area.name)));
+        }
+    }
+
+    if let Some(seat_map) = &venue.seat_map {
+        let seats = seat_map.sections.iter()
+            .flat_map(|section| &section.rows)
+            .map(|row| row.seats.len() as u64)
+            .sum::<u64>();
+        if seats > u64::from(venue.capacity) {
+            violations.push(Violation::new(
+                "seat_map",
+                format!("{} seats but the venue only holds {}", seats, venue.capacity),
+            ));
+        }
+    }
+    violations
+}
+
+/// Checks an event's ticket allocation against its venue: the total, each area
+/// of a multi-area venue, and each reserved seating zone.
+pub fn capacity_violations(venue: &Venue, ticket_types: &[TicketType]) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    // Summed as u64 so oversized allocations are reported rather than wrapping
+    let total: u64 = ticket_types.iter().map(|tt| u64::from(tt.quantity_available)).sum();
+    if total > u64::from(venue.capacity) {
+        violations.push(Violation::new(
+            "ticket_types",
+            format!("{} tickets on sale but {} holds {}", total, venue.name, venue.capacity),
+        ));
+    }
+
+    if !venue.areas.is_empty() {
+        let mut per_area: HashMap<&str, u64> = HashMap::new();
+        for (index, ticket_type) in ticket_types.iter().enumerate() {
+            match ticket_type.area.as_deref().and_then(|name| venue.area(name)) {
+                Some(area) => *per_area.entry(area.name.as_str()).or_default() += u64::from(ticket_type.quantity_available),
+                None => violations.push(Violation::new(
+                    format!("ticket_types[{}].area", index),
+                    format!("must name one of the areas of {}", venue.name),
+                )),
+            }
+        }
+        for area in &venue.areas {
+            let allocated = per_area.get(area.name.as_str()).copied().unwrap_or(0);
+            if allocated > u64::from(area.capacity) {
+                violations.push(Violation::new(
+                    "ticket_types",
+                    format!("{} tickets for {} which holds {}", allocated, area.name, area.capacity),
+                ));
+            }
+        }
+    }
+
+    let mut per_zone: Vec<(&str, u64)> = Vec::new();
+    for ticket_type in ticket_types {
+        if let Some(zone) = &ticket_type.price_zone {
+            match per_zone.iter_mut().find(|(name, _)| name == zone) {
+                Some((_, allocated)) => *allocated += u64::from(ticket_type.quantity_available),
+                None => per_zone.push((zone.as_str(), u64::from(ticket_type.quantity_available))),
+            }
+        }
+    }
+    for (zone, allocated) in per_zone {
+        let seats = venue.seat_map.as_ref().map_or(0, |seat_map| seat_map.zone_capacity(zone));
+        if allocated > u64::from(seats) {
+            violations.push(Violation::new(
+                "ticket_types",
+                format!("{} tickets for zone {} which has {} seats", allocated, zone, seats),
+            ));
+        }
+    }
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use rust_decimal::Decimal;
+
+    fn arena() -> Venue {
+        let mut venue = fixtures::event().venue;
+        venue.capacity = 1_000;
+        venue.areas = vec![
+            VenueArea { name: "Floor".to_string(), capacity: 600 },
+            VenueArea { name: "Stands".to_string(), capacity: 400 },
+        ];
+        venue
+    }
+
+    fn fields(violations: Vec<Violation>) -> Vec<String> {
+        violations.into_iter().map(|violation| violation.field).collect()
+    }
+
+    fn in_area(name: &str, area: &str, quantity: u32) -> TicketType {
+        let mut ticket_type = fixtures::ticket_type(name, Decimal::new(50000, 2), quantity);
+        ticket_type.area = Some(area.to_string());
+        ticket_type
+    }
+
+    #[test]
+    fn registered_venues_get_an_id() {
+        let mut registry = VenueRegistry::new();
+
+        let registered = registry.register(arena()).unwrap();
+        let venue_id = registered.id.expect("registered venues have an id");
+        assert_eq!(registry.get(venue_id), Some(&registered));
+
+        let mut renamed = arena();
+        renamed.name = "AsiaWorld-Expo Hall 10".to_string();
+        assert_eq!(registry.update(venue_id, renamed).unwrap().id, Some(venue_id));
+        assert_eq!(registry.list().len(), 1);
+    }
+
+    #[test]
+    fn areas_must_fit_inside_the_venue() {
+        let mut registry = VenueRegistry::new();
+        let mut venue = arena();
+        venue.areas[0].capacity = 601;
+
+        match registry.register(venue) {
+            Err(HKDError::ValidationFailed(violations)) => assert_eq!(fields(violations), vec!["areas"]),
+            other => panic!("expected ValidationFailed, got {:?}", other),
+        }
+        assert!(registry.list().is_empty());
+    }
+
+    #[test]
+    fn area_names_must_be_distinct() {
+        let mut venue = arena();
+        venue.areas[1].name = "Floor".to_string();
+        venue.areas[1].capacity = 100;
+        match VenueRegistry::new().register(venue) {
+            Err(HKDError::ValidationFailed(violations)) => assert_eq!(fields(violations), vec!["areas[1].name"]),
+            other => panic!("expected ValidationFailed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn updating_an_unknown_venue_fails() {
+        let mut registry = VenueRegistry::new();
+        let venue_id = Uuid::new_v4();
+        assert!(matches!(registry.update(venue_id, arena()), Err(HKDError::VenueNotFound(id)) if id == venue_id));
+    }
+
+    #[test]
+    fn allocations_are_checked_per_venue_and_per_area() {
+        let venue = arena();
+        assert!(capacity_violations(&venue, &[in_area("Floor", "Floor", 600), in_area("Seated", "Stands", 400)]).is_empty());
+
+        let over_area = capacity_violations(&venue, &[in_area("Floor", "Floor", 601), in_area("Seated", "Stands", 300)]);
+        assert_eq!(over_area.len(), 1);
+        assert_eq!(over_area[0].message, "601 tickets for Floor which holds 600");
+
+        let over_venue = capacity_violations(&venue, &[in_area("Floor", "Floor", 600), in_area("Seated", "Stands", 401)]);
+        assert_eq!(over_venue[0].message, "1001 tickets on sale but AsiaWorld-Arena holds 1000");
+
+        assert_eq!(
+            fields(capacity_violations(&venue, &[in_area("Floor", "Mezzanine", 10)])),
+            vec!["ticket_types[0].area"]
+        );
+    }
+
+    #[test]
+    fn allocations_past_u32_are_reported_not_wrapped() {
+        let mut venue = arena();
+        venue.areas.clear();
+        let huge = [in_area("Floor", "Floor", u32::MAX), in_area("Seated", "Stands", 2)];
+
+        let violations = capacity_violations(&venue, &huge);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].message, "4294967297 tickets on sale but AsiaWorld-Arena holds 1000");
+    }
+
+    #[test]
+    fn zone_allocations_need_enough_seats() {
+        let mut venue = arena();
+        venue.areas.clear();
+        venue.seat_map = Some(SeatMap {
+            sections: vec![Section {
+                name: "Lower".to_string(),
+                rows: vec![SeatRow {
+                    label: "A".to_string(),
+                    seats: (1..=3)
+                        .map(|number| Seat { number: number.to_string(), zone: "Gold".to_string(), accessibility: Vec::new() })
+                        .collect(),
+                }],
+            }],
+        });
+        let mut gold = fixtures::ticket_type("Gold", Decimal::new(120000, 2), 4);
+        gold.price_zone = Some("Gold".to_string());
+
+        let violations = capacity_violations(&venue, &[gold]);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].message, "4 tickets for zone Gold which has 3 seats");
+    }
+}
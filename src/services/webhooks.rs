@@ -0,0 +1,505 @@
+// src/services/webhooks.rs
+use crate::models::ticketing::EventPlatform;
+use crate::services::external_apis::{AttendeeStatus, ExternalAttendee, ExternalOrder};
+use crate::services::ticketing_service::TicketingService;
+use crate::error::HKDError;
+use base64::Engine;
+use crypto::hmac::Hmac;
+use crypto::mac::{Mac, MacResult};
+use crypto::sha2::Sha256;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// How many delivery ids to remember for de-duplication
+const MAX_REMEMBERED_DELIVERIES: usize = 10_000;
+
+// Cvent signs a timestamp; reject deliveries older than this to stop replays
+const CVENT_SIGNATURE_TOLERANCE_SECS: i64 = 300;
+
+/// A platform notification, normalized across Eventbrite, Ticketmaster and Cvent.
+#[derive(Debug, Clone)]
+pub enum WebhookEvent {
+    OrderPlaced(ExternalOrder),
+    EventUpdated { external_event_id: String },
+    AttendeeUpdated(ExternalAttendee),
+    // Eventbrite only sends resource URLs; these are resolved through the client
+    OrderReference { order_id: String },
+    AttendeeReference { event_id: String, attendee_id: String },
+    Ignored { action: String },
+}
+
+#[derive(Default)]
+pub struct WebhookReceiver {
+    secrets: HashMap<EventPlatform, String>,
+    seen_deliveries: HashSet<String>,
+    delivery_order: VecDeque<String>,
+}
+
+impl WebhookReceiver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_secret(mut self, platform: EventPlatform, secret: String) -> Self {
+        self.secrets.insert(platform, secret);
+        self
+    }
+
+    /// Verifies, de-duplicates, parses and applies a delivery. Returns `false`
+    /// for deliveries that were already processed. Header names must be lowercase.
+    pub async fn handle(
+        &mut self,
+        service: &mut TicketingService,
+        platform: EventPlatform,
+        headers: &HashMap<String, String>,
+        body: &[u8],
+    ) -> Result<bool, HKDError> {
+        self.verify_signature(&platform, headers, body)?;
+
+        let delivery_id = delivery_id(&platform, headers);
+        if delivery_id.as_ref().is_some_and(|id| self.seen_deliveries.contains(id)) {
+            return Ok(false);
+        }
+
+        let event = parse_webhook(&platform, body)?;
+        service.apply_webhook_event(platform, event).await?;
+
+        // Only remember deliveries that were applied, so failures are retried
+        if let Some(delivery_id) = delivery_id {
+            self.remember_delivery(delivery_id);
+        }
+        Ok(true)
+    }
+
+    fn remember_delivery(&mut self, delivery_id: String) {
+        if self.delivery_order.len() >= MAX_REMEMBERED_DELIVERIES {
+            if let Some(oldest) = self.delivery_order.pop_front() {
+                self.seen_deliveries.remove(&oldest);
+            }
+        }
+        self.seen_deliveries.insert(delivery_id.clone());
+        self.delivery_order.push_back(delivery_id);
+    }
+
+    pub fn verify_signature(
+        &self,
+        platform: &EventPlatform,
+        headers: &HashMap<String, String>,
+        body: &[u8],
+    ) -> Result<(), HKDError> {
+        let secret = self.secrets.get(platform)
+            .ok_or_else(|| HKDError::InvalidWebhookSignature(format!("No webhook secret for {:?}", platform)))?;
+        let header = |name: &str| {
+            headers.get(name)
+                .ok_or_else(|| HKDError::InvalidWebhookSignature(format!("Missing {} header", name)))
+        };
+
+        let (signature, expected) = match platform {
+            // Hex HMAC-SHA256 of the raw body
+            EventPlatform::Eventbrite => {
+                let signature = hex::decode(header("x-eventbrite-signature")?)
+                    .map_err(|_| HKDError::InvalidWebhookSignature("Malformed signature".to_string()))?;
+                (signature, hmac_sha256(secret, body))
+            }
+            // Base64 HMAC-SHA256 of the raw body
+            EventPlatform::Ticketmaster => {
+                let signature = base64::engine::general_purpose::STANDARD
+                    .decode(header("x-tm-signature")?)
+                    .map_err(|_| HKDError::InvalidWebhookSignature("Malformed signature".to_string()))?;
+                (signature, hmac_sha256(secret, body))
+            }
+            // Hex HMAC-SHA256 of "{timestamp}.{body}", with a freshness window
+            EventPlatform::Cvent => {
+                let timestamp: i64 = header("cvent-timestamp")?
+                    .parse()
+                    .map_err(|_| HKDError::InvalidWebhookSignature("Malformed timestamp".to_string()))?;
+                if (chrono::Utc::now().timestamp() - timestamp).abs() > CVENT_SIGNATURE_TOLERANCE_SECS {
+                    return Err(HKDError::InvalidWebhookSignature("Stale delivery".to_string()));
+                }
+                let signature = hex::decode(header("cvent-signature")?)
+                    .map_err(|_| HKDError::InvalidWebhookSignature("Malformed signature".to_string()))?;
+                let mut signed = format!("{}.", timestamp).into_bytes();
+                signed.extend_from_slice(body);
+                (signature, hmac_sha256(secret, &signed))
+            }
+            EventPlatform::Internal => {
+                return Err(HKDError::InvalidWebhookSignature("Internal events have no webhooks".to_string()));
+            }
+        };
+
+        // MacResult compares in constant time
+        if MacResult::new_from_owned(signature) == expected {
+            Ok(())
+        } else {
+            Err(HKDError::InvalidWebhookSignature("Signature mismatch".to_string()))
+        }
+    }
+}
+
+fn hmac_sha256(secret: &str, message: &[u8]) -> MacResult {
+    let mut mac = Hmac::new(Sha256::new(), secret.as_bytes());
+    mac.input(message);
+    mac.result()
+}
+
+/// Platform delivery id header. Without one a delivery can't be told apart from a
+/// repeat notification with the same body (Eventbrite only sends a resource URL),
+/// so it is applied again; applying is idempotent.
+fn delivery_id(platform: &EventPlatform, headers: &HashMap<String, String>) -> Option<String> {
+    let header = match platform {
+        EventPlatform::Eventbrite => "x-eventbrite-delivery",
+        EventPlatform::Ticketmaster => "x-tm-delivery-id",
+        EventPlatform::Cvent => "cvent-message-id",
+        EventPlatform::Internal => "",
+    };
+    headers.get(header).map(|id| format!("{:?}:{}", platform, id))
+}
+
+pub fn parse_webhook(platform: &EventPlatform, body: &[u8]) -> Result<WebhookEvent, HKDError> {
+    let parse_error = |e: serde_json::Error| HKDError::ExternalApiError(format!("Invalid {:?} webhook payload: {}", platform, e));
+    match platform {
+        EventPlatform::Eventbrite => serde_json::from_slice::<EventbriteWebhook>(body)
+            .map_err(parse_error)
+            .map(EventbriteWebhook::into_event),
+        EventPlatform::Ticketmaster => serde_json::from_slice::<TicketmasterWebhook>(body)
+            .map_err(parse_error)
+            .map(TicketmasterWebhook::into_event),
+        EventPlatform::Cvent => serde_json::from_slice::<CventWebhook>(body)
+            .map_err(parse_error)
+            .map(CventWebhook::into_event),
+        EventPlatform::Internal => Err(HKDError::ExternalApiError("Internal events have no webhooks".to_string())),
+    }
+}
+
+// Eventbrite: {"config": {"action": "order.placed"}, "api_url": ".../v3/orders/123/"}
+#[derive(Debug, Deserialize)]
+struct EventbriteWebhook {
+    config: EventbriteWebhookConfig,
+    api_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct EventbriteWebhookConfig {
+    action: String,
+}
+
+impl EventbriteWebhook {
+    fn into_event(self) -> WebhookEvent {
+        let path: Vec<&str> = self.api_url
+            .split("/v3/")
+            .nth(1)
+            .unwrap_or_default()
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect();
+
+        match (self.config.action.as_str(), path.as_slice()) {
+            ("order.placed", ["orders", order_id]) => WebhookEvent::OrderReference {
+                order_id: order_id.to_string(),
+            },
+            ("event.updated", ["events", event_id]) | ("event.published", ["events", event_id]) => {
+                WebhookEvent::EventUpdated { external_event_id: event_id.to_string() }
+            }
+            ("attendee.updated", ["events", event_id, "attendees", attendee_id])
+            | ("attendee.checked_in", ["events", event_id, "attendees", attendee_id]) => {
+                WebhookEvent::AttendeeReference {
+                    event_id: event_id.to_string(),
+                    attendee_id: attendee_id.to_string(),
+                }
+            }
+            _ => WebhookEvent::Ignored { action: self.config.action },
+        }
+    }
+}
+
+// Ticketmaster: {"type": "order.placed", "data": {...}}
+#[derive(Debug, Deserialize)]
+struct TicketmasterWebhook {
+    #[serde(rename = "type")]
+    event_type: String,
+    data: TicketmasterWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterWebhookData {
+    event_id: String,
+    order_id: Option<String>,
+    tickets: Option<Vec<TicketmasterWebhookTicket>>,
+    customer: Option<TicketmasterWebhookCustomer>,
+    // Present on ticket.updated
+    ticket_id: Option<String>,
+    offer_id: Option<String>,
+    status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TicketmasterWebhookTicket {
+    ticket_id: String,
+    offer_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TicketmasterWebhookCustomer {
+    email: String,
+}
+
+fn ticketmaster_ticket_status(status: &str) -> AttendeeStatus {
+    match status {
+        "scanned" => AttendeeStatus::CheckedIn,
+        "refunded" => AttendeeStatus::Refunded,
+        "cancelled" | "voided" => AttendeeStatus::Cancelled,
+        _ => AttendeeStatus::Active,
+    }
+}
+
+impl TicketmasterWebhook {
+    fn into_event(self) -> WebhookEvent {
+        let data = self.data;
+        match self.event_type.as_str() {
+            "order.placed" => {
+                let buyer_reference = data.customer.map(|customer| customer.email).unwrap_or_default();
+                let attendees = data.tickets
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|ticket| ExternalAttendee {
+                        external_attendee_id: ticket.ticket_id,
+                        external_event_id: data.event_id.clone(),
+                        external_ticket_type_id: ticket.offer_id,
+                        buyer_reference: buyer_reference.clone(),
+                        status: AttendeeStatus::Active,
+                    })
+                    .collect();
+                WebhookEvent::OrderPlaced(ExternalOrder {
+                    external_order_id: data.order_id.unwrap_or_default(),
+                    external_event_id: data.event_id,
+                    attendees,
+                })
+            }
+            "event.updated" => WebhookEvent::EventUpdated { external_event_id: data.event_id },
+            "ticket.updated" => match (data.ticket_id, data.offer_id) {
+                (Some(ticket_id), Some(offer_id)) => WebhookEvent::AttendeeUpdated(ExternalAttendee {
+                    external_attendee_id: ticket_id,
+                    external_event_id: data.event_id,
+                    external_ticket_type_id: offer_id,
+                    buyer_reference: data.customer.map(|customer| customer.email).unwrap_or_default(),
+                    status: ticketmaster_ticket_status(data.status.as_deref().unwrap_or_default()),
+                }),
+                _ => WebhookEvent::Ignored { action: self.event_type },
+            },
+            _ => WebhookEvent::Ignored { action: self.event_type },
+        }
+    }
+}
+
+// Cvent: {"eventType": "InviteeOrGuestAccepted", "message": {...}}
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventWebhook {
+    event_type: String,
+    message: CventWebhookMessage,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CventWebhookMessage {
+    event_id: String,
+    attendee_id: Option<String>,
+    admission_item_id: Option<String>,
+    email: Option<String>,
+    registration_id: Option<String>,
+}
+
+impl CventWebhook {
+    fn into_event(self) -> WebhookEvent {
+        let message = self.message;
+        let status = match self.event_type.as_str() {
+            "EventUpdate" => {
+                return WebhookEvent::EventUpdated { external_event_id: message.event_id };
+            }
+            "InviteeOrGuestAccepted" | "InviteeOrGuestModified" => AttendeeStatus::Active,
+            "InviteeCheckedIn" => AttendeeStatus::CheckedIn,
+            "InviteeCancelled" => AttendeeStatus::Cancelled,
+            _ => return WebhookEvent::Ignored { action: self.event_type },
+        };
+
+        let (attendee_id, admission_item_id) = match (message.attendee_id, message.admission_item_id) {
+            (Some(attendee_id), Some(admission_item_id)) => (attendee_id, admission_item_id),
+            _ => return WebhookEvent::Ignored { action: self.event_type },
+        };
+        let attendee = ExternalAttendee {
+            external_attendee_id: attendee_id.clone(),
+            external_event_id: message.event_id.clone(),
+            external_ticket_type_id: admission_item_id,
+            buyer_reference: message.email.unwrap_or_else(|| attendee_id.clone()),
+            status,
+        };
+
+        // Cvent registers one attendee per acceptance; treat it as a single-ticket order
+        if self.event_type == "InviteeOrGuestAccepted" {
+            WebhookEvent::OrderPlaced(ExternalOrder {
+                external_order_id: message.registration_id.unwrap_or(attendee_id),
+                external_event_id: message.event_id,
+                attendees: vec![attendee],
+            })
+        } else {
+            WebhookEvent::AttendeeUpdated(attendee)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::HKDEngine;
+    use crate::services::fake_platform::FakePlatform;
+    use std::sync::Arc;
+
+    const SECRET: &str = "whsec_test";
+    const EVENTBRITE_BODY: &[u8] = br#"{"config":{"action":"test"},"api_url":"https://www.eventbriteapi.com/v3/webhooks/1/"}"#;
+    const TICKETMASTER_BODY: &[u8] = br#"{"type":"event.updated","data":{"eventId":"tm-1"}}"#;
+    const CVENT_BODY: &[u8] = br#"{"eventType":"EventUpdate","message":{"eventId":"cv-1"}}"#;
+
+    fn receiver() -> WebhookReceiver {
+        WebhookReceiver::new()
+            .with_secret(EventPlatform::Eventbrite, SECRET.to_string())
+            .with_secret(EventPlatform::Ticketmaster, SECRET.to_string())
+            .with_secret(EventPlatform::Cvent, SECRET.to_string())
+    }
+
+    fn sign(secret: &str, message: &[u8]) -> Vec<u8> {
+        hmac_sha256(secret, message).code().to_vec()
+    }
+
+    fn headers(pairs: &[(&str, String)]) -> HashMap<String, String> {
+        pairs.iter().map(|(name, value)| (name.to_string(), value.clone())).collect()
+    }
+
+    fn eventbrite_headers(secret: &str, body: &[u8]) -> HashMap<String, String> {
+        headers(&[("x-eventbrite-signature", hex::encode(sign(secret, body)))])
+    }
+
+    fn cvent_headers(secret: &str, timestamp: i64, body: &[u8]) -> HashMap<String, String> {
+        let mut signed = format!("{}.", timestamp).into_bytes();
+        signed.extend_from_slice(body);
+        headers(&[
+            ("cvent-timestamp", timestamp.to_string()),
+            ("cvent-signature", hex::encode(sign(secret, &signed))),
+        ])
+    }
+
+    fn rejected(result: Result<(), HKDError>) -> bool {
+        matches!(result, Err(HKDError::InvalidWebhookSignature(_)))
+    }
+
+    #[test]
+    fn eventbrite_signs_the_body_in_hex() {
+        let receiver = receiver();
+        let platform = EventPlatform::Eventbrite;
+
+        assert!(receiver.verify_signature(&platform, &eventbrite_headers(SECRET, EVENTBRITE_BODY), EVENTBRITE_BODY).is_ok());
+        assert!(rejected(receiver.verify_signature(&platform, &eventbrite_headers(SECRET, EVENTBRITE_BODY), b"{}")));
+        assert!(rejected(receiver.verify_signature(&platform, &eventbrite_headers("other", EVENTBRITE_BODY), EVENTBRITE_BODY)));
+    }
+
+    #[test]
+    fn ticketmaster_signs_the_body_in_base64() {
+        let receiver = receiver();
+        let platform = EventPlatform::Ticketmaster;
+        let signed = |secret: &str| {
+            let signature = base64::engine::general_purpose::STANDARD.encode(sign(secret, TICKETMASTER_BODY));
+            headers(&[("x-tm-signature", signature)])
+        };
+
+        assert!(receiver.verify_signature(&platform, &signed(SECRET), TICKETMASTER_BODY).is_ok());
+        assert!(rejected(receiver.verify_signature(&platform, &signed(SECRET), b"{}")));
+        assert!(rejected(receiver.verify_signature(&platform, &signed("other"), TICKETMASTER_BODY)));
+    }
+
+    #[test]
+    fn cvent_signs_the_timestamp_and_body() {
+        let receiver = receiver();
+        let platform = EventPlatform::Cvent;
+        let now = chrono::Utc::now().timestamp();
+
+        assert!(receiver.verify_signature(&platform, &cvent_headers(SECRET, now, CVENT_BODY), CVENT_BODY).is_ok());
+        assert!(rejected(receiver.verify_signature(&platform, &cvent_headers(SECRET, now, CVENT_BODY), b"{}")));
+        assert!(rejected(receiver.verify_signature(&platform, &cvent_headers("other", now, CVENT_BODY), CVENT_BODY)));
+
+        // The signature covers the timestamp, so it can't be swapped for a fresh one
+        let mut replayed = cvent_headers(SECRET, now - 10, CVENT_BODY);
+        replayed.insert("cvent-timestamp".to_string(), now.to_string());
+        assert!(rejected(receiver.verify_signature(&platform, &replayed, CVENT_BODY)));
+    }
+
+    #[test]
+    fn cvent_rejects_deliveries_outside_the_replay_window() {
+        let receiver = receiver();
+        let platform = EventPlatform::Cvent;
+        let now = chrono::Utc::now().timestamp();
+        let window = CVENT_SIGNATURE_TOLERANCE_SECS;
+
+        assert!(receiver.verify_signature(&platform, &cvent_headers(SECRET, now - window + 5, CVENT_BODY), CVENT_BODY).is_ok());
+        assert!(rejected(receiver.verify_signature(&platform, &cvent_headers(SECRET, now - window - 5, CVENT_BODY), CVENT_BODY)));
+        assert!(rejected(receiver.verify_signature(&platform, &cvent_headers(SECRET, now + window + 5, CVENT_BODY), CVENT_BODY)));
+    }
+
+    #[test]
+    fn missing_headers_and_secrets_are_rejected() {
+        let receiver = receiver();
+        let no_headers = HashMap::new();
+
+        assert!(rejected(receiver.verify_signature(&EventPlatform::Eventbrite, &no_headers, EVENTBRITE_BODY)));
+        assert!(rejected(receiver.verify_signature(&EventPlatform::Ticketmaster, &no_headers, TICKETMASTER_BODY)));
+        let mut unsigned = cvent_headers(SECRET, chrono::Utc::now().timestamp(), CVENT_BODY);
+        unsigned.remove("cvent-signature");
+        assert!(rejected(receiver.verify_signature(&EventPlatform::Cvent, &unsigned, CVENT_BODY)));
+
+        let headers = eventbrite_headers(SECRET, EVENTBRITE_BODY);
+        assert!(rejected(WebhookReceiver::new().verify_signature(&EventPlatform::Eventbrite, &headers, EVENTBRITE_BODY)));
+    }
+
+    #[tokio::test]
+    async fn duplicate_deliveries_are_applied_once() {
+        let mut service = TicketingService::new(Arc::new(HKDEngine::new()))
+            .with_platform(Box::new(FakePlatform::new(EventPlatform::Eventbrite)));
+        let mut receiver = receiver();
+        let mut headers = eventbrite_headers(SECRET, EVENTBRITE_BODY);
+        headers.insert("x-eventbrite-delivery".to_string(), "delivery-1".to_string());
+
+        assert!(receiver.handle(&mut service, EventPlatform::Eventbrite, &headers, EVENTBRITE_BODY).await.unwrap());
+        assert!(!receiver.handle(&mut service, EventPlatform::Eventbrite, &headers, EVENTBRITE_BODY).await.unwrap());
+
+        headers.insert("x-eventbrite-delivery".to_string(), "delivery-2".to_string());
+        assert!(receiver.handle(&mut service, EventPlatform::Eventbrite, &headers, EVENTBRITE_BODY).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn deliveries_without_an_id_are_always_applied() {
+        let mut service = TicketingService::new(Arc::new(HKDEngine::new()))
+            .with_platform(Box::new(FakePlatform::new(EventPlatform::Eventbrite)));
+        let mut receiver = receiver();
+        let headers = eventbrite_headers(SECRET, EVENTBRITE_BODY);
+
+        // The same body can be a new notification about the same resource
+        assert!(receiver.handle(&mut service, EventPlatform::Eventbrite, &headers, EVENTBRITE_BODY).await.unwrap());
+        assert!(receiver.handle(&mut service, EventPlatform::Eventbrite, &headers, EVENTBRITE_BODY).await.unwrap());
+    }
+
+    #[test]
+    fn ticketmaster_orders_carry_their_attendees() {
+        let body = br#"{"type":"order.placed","data":{"eventId":"tm-1","orderId":"o-1",
+            "tickets":[{"ticketId":"t-1","offerId":"off-1"},{"ticketId":"t-2","offerId":"off-1"}],
+            "customer":{"email":"fan@example.com"}}}"#;
+
+        match parse_webhook(&EventPlatform::Ticketmaster, body).unwrap() {
+            WebhookEvent::OrderPlaced(order) => {
+                assert_eq!(order.external_order_id, "o-1");
+                assert_eq!(order.attendees.len(), 2);
+                assert_eq!(order.attendees[1].buyer_reference, "fan@example.com");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
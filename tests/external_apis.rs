@@ -0,0 +1,553 @@
+// tests/external_apis.rs
+//! Contract tests for the `TicketingPlatform` clients, run against the
+//! in-process fakes in `support` so they need neither network nor API keys.
+mod support;
+
+use futures::StreamExt;
+use hkd_stablecoin::error::HKDError;
+use hkd_stablecoin::models::ticketing::*;
+use hkd_stablecoin::services::external_apis::*;
+use hkd_stablecoin::services::platform_http::{BackoffPolicy, CircuitBreakerConfig, RateLimit, ResilienceConfig};
+use rust_decimal::Decimal;
+use serde_json::json;
+use std::time::{Duration, Instant};
+use support::cvent::FakeCvent;
+use support::eventbrite::FakeEventbrite;
+use support::ticketmaster::FakeTicketmaster;
+use support::{sample_event, sample_ticket_type, StubResponse, StubServer};
+
+const EVENTBRITE_KEY: &str = "eventbrite-test-key";
+const TICKETMASTER_KEY: &str = "ticketmaster-test-key";
+
+/// No quotas and millisecond backoff so the suite stays fast.
+fn test_resilience(platform: EventPlatform) -> ResilienceConfig {
+    ResilienceConfig {
+        rate_limits: Vec::new(),
+        backoff: BackoffPolicy {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(50),
+        },
+        ..ResilienceConfig::for_platform(&platform)
+    }
+}
+
+async fn eventbrite(fake: FakeEventbrite) -> (StubServer<FakeEventbrite>, EventbriteClient) {
+    let server = StubServer::start(fake).await;
+    let client = EventbriteClient::new(EVENTBRITE_KEY.to_string())
+        .with_base_url(server.base_url.clone())
+        .with_resilience(test_resilience(EventPlatform::Eventbrite));
+    (server, client)
+}
+
+async fn ticketmaster(fake: FakeTicketmaster) -> (StubServer<FakeTicketmaster>, TicketmasterClient) {
+    let server = StubServer::start(fake).await;
+    let client = TicketmasterClient::new(TICKETMASTER_KEY.to_string())
+        .with_base_url(server.base_url.clone())
+        .with_resilience(test_resilience(EventPlatform::Ticketmaster));
+    (server, client)
+}
+
+async fn cvent(fake: FakeCvent) -> (StubServer<FakeCvent>, CventClient) {
+    let server = StubServer::start(fake).await;
+    let client = CventClient::new("cvent-client".to_string(), "cvent-secret".to_string(), "acct-1".to_string())
+        .with_base_url(server.base_url.clone())
+        .with_resilience(test_resilience(EventPlatform::Cvent));
+    (server, client)
+}
+
+fn fake_cvent() -> FakeCvent {
+    FakeCvent::new("cvent-client", "cvent-secret", "acct-1")
+}
+
+// Eventbrite
+
+#[tokio::test]
+async fn eventbrite_create_event_resolves_organizer_and_creates_venue() {
+    let (server, client) = eventbrite(FakeEventbrite::new(EVENTBRITE_KEY)).await;
+    let event = sample_event();
+
+    let response = client.create_event(&event).await.unwrap();
+
+    assert_eq!(response.platform, EventPlatform::Eventbrite);
+    assert_eq!(response.event_url, format!("https://www.eventbrite.com/e/{}", response.external_id));
+
+    let requests = server.requests();
+    let calls: Vec<(&str, &str)> = requests.iter().map(|r| (r.method.as_str(), r.path.as_str())).collect();
+    assert_eq!(calls, vec![
+        ("GET", "/users/me/organizations/"),
+        ("GET", "/organizations/org-1/organizers/"),
+        ("GET", "/organizations/org-1/venues/"),
+        ("POST", "/organizations/org-1/venues/"),
+        ("POST", "/organizations/org-1/events/"),
+    ]);
+
+    assert_eq!(requests[3].body["venue"]["address"]["country"], "HK");
+
+    let body = &requests[4].body["event"];
+    assert_eq!(body["organizer_id"], "organizer-1");
+    assert_eq!(body["start"]["timezone"], "Asia/Hong_Kong");
+    assert_eq!(body["name"]["html"], "Harbour Lights Festival");
+    assert_eq!(body["currency"], "HKD");
+    assert!(requests.iter().all(|r| r.header("authorization") == Some("Bearer eventbrite-test-key")));
+}
+
+#[tokio::test]
+async fn eventbrite_create_event_reuses_an_existing_venue() {
+    let (server, client) = eventbrite(FakeEventbrite::new(EVENTBRITE_KEY)).await;
+    let event = sample_event();
+
+    // A retry after the first attempt created the venue but not the event
+    client.create_event(&event).await.unwrap();
+    client.create_event(&event).await.unwrap();
+
+    let requests = server.requests();
+    let posts_to = |path: &str| requests.iter()
+        .filter(|r| r.method == "POST" && r.path == path)
+        .collect::<Vec<_>>();
+    assert_eq!(posts_to("/organizations/org-1/venues/").len(), 1);
+    let events = posts_to("/organizations/org-1/events/");
+    assert_eq!(events[0].body["event"]["venue_id"], events[1].body["event"]["venue_id"]);
+}
+
+#[tokio::test]
+async fn eventbrite_get_event_maps_the_expanded_event_back() {
+    let (_server, client) = eventbrite(FakeEventbrite::new(EVENTBRITE_KEY)).await;
+    let event = sample_event();
+    let created = client.create_event(&event).await.unwrap();
+
+    let fetched = client.get_event(&created.external_id).await.unwrap();
+
+    assert_eq!(fetched.title, event.title);
+    assert_eq!(fetched.description, event.description);
+    assert_eq!(fetched.event_date, event.event_date);
+    assert_eq!(fetched.event_type, EventType::Concert);
+    assert_eq!(fetched.venue.city, "Hong Kong");
+    assert_eq!(fetched.venue.coordinates, event.venue.coordinates);
+    assert_eq!(fetched.platform, EventPlatform::Eventbrite);
+    assert_eq!(fetched.external_id_on(&EventPlatform::Eventbrite), Some(created.external_id.as_str()));
+}
+
+#[tokio::test]
+async fn eventbrite_update_event_posts_to_the_event() {
+    let (server, client) = eventbrite(FakeEventbrite::new(EVENTBRITE_KEY)).await;
+    let mut event = sample_event();
+    let created = client.create_event(&event).await.unwrap();
+
+    event.title = "Harbour Lights <Encore>".to_string();
+    let updated = client.update_event(&created.external_id, &event).await.unwrap();
+
+    assert_eq!(updated.external_id, created.external_id);
+    let request = server.last_request();
+    assert_eq!((request.method.as_str(), request.path.as_str()), ("POST", format!("/events/{}/", created.external_id).as_str()));
+    assert_eq!(request.body["event"]["name"]["html"], "Harbour Lights &lt;Encore&gt;");
+    // Venues stay attached to the event once it exists
+    assert!(request.body["event"].get("venue_id").is_none());
+
+    let fetched = client.get_event(&created.external_id).await.unwrap();
+    assert_eq!(fetched.title, "Harbour Lights <Encore>");
+}
+
+#[tokio::test]
+async fn eventbrite_list_events_follows_continuation_tokens() {
+    let mut fake = FakeEventbrite::new(EVENTBRITE_KEY).with_page_size(2);
+    for n in 0..5 {
+        fake.seed_event(&format!("Seeded {}", n), "live");
+    }
+    let (server, client) = eventbrite(fake).await;
+
+    let events = client.list_events(EventFilters::default()).await.unwrap();
+
+    let titles: Vec<String> = events.iter().map(|event| event.title.clone()).collect();
+    assert_eq!(titles, vec!["Seeded 0", "Seeded 1", "Seeded 2", "Seeded 3", "Seeded 4"]);
+    let continuations: Vec<Option<String>> = server.requests().iter()
+        .filter(|r| r.path == "/organizations/org-1/events/")
+        .map(|r| r.query("continuation").map(str::to_string))
+        .collect();
+    assert_eq!(continuations, vec![None, Some("2".to_string()), Some("4".to_string())]);
+}
+
+#[tokio::test]
+async fn eventbrite_list_events_pushes_status_down() {
+    let mut fake = FakeEventbrite::new(EVENTBRITE_KEY);
+    fake.seed_event("Live", "live");
+    fake.seed_event("Draft", "draft");
+    let (server, client) = eventbrite(fake).await;
+
+    let filters = EventFilters { status: Some(EventStatus::OnSale), ..EventFilters::default() };
+    let events = client.list_events(filters).await.unwrap();
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].title, "Live");
+    assert_eq!(server.last_request().query("status"), Some("live"));
+}
+
+#[tokio::test]
+async fn eventbrite_event_stream_fetches_pages_on_demand() {
+    let mut fake = FakeEventbrite::new(EVENTBRITE_KEY).with_page_size(2);
+    for n in 0..5 {
+        fake.seed_event(&format!("Seeded {}", n), "live");
+    }
+    let (server, client) = eventbrite(fake).await;
+
+    let first_two: Vec<Event> = event_stream(&client, EventFilters::default())
+        .take(2)
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    assert_eq!(first_two.len(), 2);
+    let list_requests = server.requests().iter()
+        .filter(|r| r.path == "/organizations/org-1/events/")
+        .count();
+    assert_eq!(list_requests, 1);
+}
+
+#[tokio::test]
+async fn eventbrite_ticket_classes_report_and_accept_quantities() {
+    let (server, client) = eventbrite(FakeEventbrite::new(EVENTBRITE_KEY)).await;
+    let event = sample_event();
+    let created = client.create_event(&event).await.unwrap();
+    let ticket_types = vec![
+        sample_ticket_type("VIP", Decimal::new(88000, 2), 500),
+        sample_ticket_type("Community", Decimal::ZERO, 100),
+    ];
+
+    let responses = client.create_ticket_types(&created.external_id, &ticket_types).await.unwrap();
+
+    assert_eq!(responses.len(), 2);
+    assert_eq!(responses[0].ticket_type_id, ticket_types[0].id);
+    let requests = server.requests();
+    let ticket_class_bodies: Vec<_> = requests.iter()
+        .filter(|r| r.path.ends_with("/ticket_classes/"))
+        .map(|r| r.body["ticket_class"].clone())
+        .collect();
+    assert_eq!(ticket_class_bodies[0]["cost"], "HKD,88000");
+    assert_eq!(ticket_class_bodies[1]["free"], true);
+    assert!(ticket_class_bodies[1].get("cost").is_none());
+
+    let vip = &responses[0].external_id;
+    server.platform(|fake| fake.sell(vip, 7));
+    assert_eq!(client.get_quantity_sold(&created.external_id, vip).await.unwrap(), 7);
+
+    client.set_quantity_total(&created.external_id, vip, 420).await.unwrap();
+    assert_eq!(server.platform(|fake| fake.quantity_total(vip)), Some(420));
+
+    let fetched = client.get_event(&created.external_id).await.unwrap();
+    let fetched_vip = fetched.ticket_types.iter().find(|tt| tt.name == "VIP").unwrap();
+    assert_eq!(fetched_vip.price, Decimal::new(88000, 2));
+    assert_eq!(fetched_vip.quantity_sold, 7);
+    assert_eq!(fetched_vip.external_ids.get(&EventPlatform::Eventbrite), Some(vip));
+}
+
+#[tokio::test]
+async fn eventbrite_orders_and_attendees_map_to_external_types() {
+    let (server, client) = eventbrite(FakeEventbrite::new(EVENTBRITE_KEY)).await;
+    let (order_id, attendee_id) = server.platform(|fake| {
+        let event_id = fake.seed_event("Seeded", "live");
+        fake.place_order(&event_id, "tc-1", "fan@example.com")
+    });
+
+    let order = client.get_order(&order_id).await.unwrap();
+
+    assert_eq!(order.external_order_id, order_id);
+    assert_eq!(order.attendees.len(), 1);
+    assert_eq!(order.attendees[0].buyer_reference, "fan@example.com");
+    assert_eq!(order.attendees[0].external_ticket_type_id, "tc-1");
+    assert_eq!(server.last_request().query("expand"), Some("attendees"));
+
+    let attendee = client.get_attendee(&order.external_event_id, &attendee_id).await.unwrap();
+    assert_eq!(attendee.external_attendee_id, attendee_id);
+    assert_eq!(attendee.status, AttendeeStatus::Active);
+}
+
+#[tokio::test]
+async fn eventbrite_auth_failures_surface_status_and_body() {
+    let server = StubServer::start(FakeEventbrite::new(EVENTBRITE_KEY)).await;
+    let client = EventbriteClient::new("wrong-key".to_string())
+        .with_base_url(server.base_url.clone())
+        .with_resilience(test_resilience(EventPlatform::Eventbrite));
+
+    match client.get_event("1").await {
+        Err(HKDError::PlatformApiError { platform, status, body }) => {
+            assert_eq!(platform, EventPlatform::Eventbrite);
+            assert_eq!(status, 401);
+            assert!(body.contains("INVALID_AUTH"));
+        }
+        other => panic!("expected a platform error, got {:?}", other),
+    }
+    // Rejected requests are not retried
+    assert_eq!(server.requests().len(), 1);
+}
+
+// Ticketmaster
+
+#[tokio::test]
+async fn ticketmaster_published_events_are_readable_through_discovery() {
+    let (server, client) = ticketmaster(FakeTicketmaster::new(TICKETMASTER_KEY)).await;
+    let event = sample_event();
+
+    let created = client.create_event(&event).await.unwrap();
+    let publish = server.last_request();
+    assert_eq!((publish.method.as_str(), publish.path.as_str()), ("POST", "/publish/v2/events"));
+    assert_eq!(publish.query("apikey"), Some(TICKETMASTER_KEY));
+    assert_eq!(publish.body["venue"]["capacity"], 12_500);
+
+    let fetched = client.get_event(&created.external_id).await.unwrap();
+
+    assert_eq!(server.last_request().path, format!("/discovery/v2/events/{}.json", created.external_id));
+    assert_eq!(fetched.title, event.title);
+    assert_eq!(fetched.door_time, event.door_time);
+    assert_eq!(fetched.event_type, EventType::Concert);
+    assert_eq!(fetched.organizer, event.organizer);
+    assert_eq!(fetched.venue.coordinates, event.venue.coordinates);
+    assert_eq!(fetched.status, EventStatus::OnSale);
+}
+
+#[tokio::test]
+async fn ticketmaster_update_event_puts_the_publish_payload() {
+    let (server, client) = ticketmaster(FakeTicketmaster::new(TICKETMASTER_KEY)).await;
+    let mut event = sample_event();
+    let created = client.create_event(&event).await.unwrap();
+
+    event.event_type = EventType::Theater;
+    client.update_event(&created.external_id, &event).await.unwrap();
+
+    let request = server.last_request();
+    assert_eq!((request.method.as_str(), request.path.as_str()), ("PUT", format!("/publish/v2/events/{}", created.external_id).as_str()));
+    let fetched = client.get_event(&created.external_id).await.unwrap();
+    assert_eq!(fetched.event_type, EventType::Theater);
+}
+
+#[tokio::test]
+async fn ticketmaster_list_events_pages_by_number_with_filters_pushed_down() {
+    let mut fake = FakeTicketmaster::new(TICKETMASTER_KEY).with_page_size(2);
+    for n in 0..3 {
+        fake.seed_event(&format!("Jazz Night {}", n), "Hong Kong");
+    }
+    fake.seed_event("Rock Night", "Hong Kong");
+    fake.seed_event("Jazz Night Macau", "Macau");
+    let (server, client) = ticketmaster(fake).await;
+
+    let filters = EventFilters {
+        keyword: Some("jazz".to_string()),
+        venue_city: Some("Hong Kong".to_string()),
+        ..EventFilters::default()
+    };
+    let events = client.list_events(filters).await.unwrap();
+
+    assert_eq!(events.len(), 3);
+    let pages: Vec<Option<String>> = server.requests().iter()
+        .map(|r| r.query("page").map(str::to_string))
+        .collect();
+    assert_eq!(pages, vec![Some("0".to_string()), Some("1".to_string())]);
+    assert_eq!(server.last_request().query("keyword"), Some("jazz"));
+}
+
+#[tokio::test]
+async fn ticketmaster_offers_report_and_accept_quantities() {
+    let (server, client) = ticketmaster(FakeTicketmaster::new(TICKETMASTER_KEY)).await;
+    let created = client.create_event(&sample_event()).await.unwrap();
+    let ticket_types = vec![sample_ticket_type("Standard", Decimal::new(48000, 2), 2_000)];
+
+    let responses = client.create_ticket_types(&created.external_id, &ticket_types).await.unwrap();
+    let offer_id = &responses[0].external_id;
+    assert_eq!(server.last_request().body["price"], 480.0);
+
+    server.platform(|fake| fake.sell(offer_id, 12));
+    assert_eq!(client.get_quantity_sold(&created.external_id, offer_id).await.unwrap(), 12);
+
+    client.set_quantity_total(&created.external_id, offer_id, 1_500).await.unwrap();
+    assert_eq!(server.last_request().method, "PATCH");
+    assert_eq!(server.platform(|fake| fake.offer_quantity(offer_id)), Some(1_500));
+}
+
+#[tokio::test]
+async fn ticketmaster_does_not_support_order_lookup() {
+    let (server, client) = ticketmaster(FakeTicketmaster::new(TICKETMASTER_KEY)).await;
+
+    assert!(matches!(client.get_order("order-1").await, Err(HKDError::ExternalApiError(_))));
+    assert!(matches!(client.get_attendee("event-1", "attendee-1").await, Err(HKDError::ExternalApiError(_))));
+    assert!(server.requests().is_empty());
+}
+
+// Cvent
+
+#[tokio::test]
+async fn cvent_reuses_its_access_token_across_calls() {
+    let (server, client) = cvent(fake_cvent()).await;
+    let event = sample_event();
+
+    let created = client.create_event(&event).await.unwrap();
+    client.get_event(&created.external_id).await.unwrap();
+    client.update_event(&created.external_id, &event).await.unwrap();
+
+    assert_eq!(server.platform(|fake| fake.tokens_issued()), 1);
+    let token_request = &server.requests()[0];
+    assert_eq!(token_request.path, "/oauth2/token");
+    assert_eq!(token_request.body["grant_type"], "client_credentials");
+    assert_eq!(server.last_request().header("authorization"), Some("Bearer token-1"));
+    assert_eq!(created.event_url, format!("https://cvent.me/{}", created.external_id));
+}
+
+#[tokio::test]
+async fn cvent_get_event_maps_the_event_back() {
+    let (server, client) = cvent(fake_cvent()).await;
+    let event = sample_event();
+    let created = client.create_event(&event).await.unwrap();
+    assert_eq!(server.last_request().path, "/accounts/acct-1/events");
+
+    let fetched = client.get_event(&created.external_id).await.unwrap();
+
+    assert_eq!(fetched.title, event.title);
+    assert_eq!(fetched.organizer, event.organizer);
+    assert_eq!(fetched.venue.name, event.venue.name);
+    assert_eq!(fetched.venue.coordinates, event.venue.coordinates);
+    assert_eq!(fetched.platform, EventPlatform::Cvent);
+}
+
+#[tokio::test]
+async fn cvent_list_events_follows_next_tokens() {
+    let mut fake = fake_cvent().with_page_size(2);
+    for n in 0..3 {
+        fake.seed_event(&format!("Summit {}", n));
+    }
+    let (server, client) = cvent(fake).await;
+
+    let events = client.list_events(EventFilters::default()).await.unwrap();
+
+    assert_eq!(events.len(), 3);
+    let tokens: Vec<Option<String>> = server.requests().iter()
+        .filter(|r| r.path == "/accounts/acct-1/events")
+        .map(|r| r.query("token").map(str::to_string))
+        .collect();
+    assert_eq!(tokens, vec![None, Some("2".to_string())]);
+}
+
+#[tokio::test]
+async fn cvent_admission_items_report_and_accept_quantities() {
+    let (server, client) = cvent(fake_cvent()).await;
+    let created = client.create_event(&sample_event()).await.unwrap();
+    let ticket_types = vec![sample_ticket_type("Delegate", Decimal::new(150000, 2), 800)];
+
+    let responses = client.create_ticket_types(&created.external_id, &ticket_types).await.unwrap();
+    let item_id = &responses[0].external_id;
+
+    server.platform(|fake| fake.register(item_id, 40));
+    assert_eq!(client.get_quantity_sold(&created.external_id, item_id).await.unwrap(), 40);
+
+    client.set_quantity_total(&created.external_id, item_id, 600).await.unwrap();
+    assert_eq!(server.platform(|fake| fake.capacity(item_id)), Some(600));
+
+    let fetched = client.get_event(&created.external_id).await.unwrap();
+    assert_eq!(fetched.ticket_types[0].price, Decimal::new(150000, 2));
+    assert_eq!(fetched.ticket_types[0].quantity_sold, 40);
+}
+
+// Resilience
+
+#[tokio::test]
+async fn rate_limited_requests_are_retried_after_the_advertised_delay() {
+    let (server, client) = ticketmaster(FakeTicketmaster::new(TICKETMASTER_KEY)).await;
+    let created = client.create_event(&sample_event()).await.unwrap();
+    server.inject(StubResponse::error(429, json!({ "fault": { "faultstring": "Rate limit quota violation" } }))
+        .with_header("Retry-After", "0"));
+
+    let fetched = client.get_event(&created.external_id).await.unwrap();
+
+    assert_eq!(fetched.title, sample_event().title);
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn server_errors_are_retried_then_reported_with_the_platform_body() {
+    let (server, client) = ticketmaster(FakeTicketmaster::new(TICKETMASTER_KEY)).await;
+    for _ in 0..3 {
+        server.inject(StubResponse::error(503, json!({ "errors": [{ "code": "SERVICE_UNAVAILABLE" }] })));
+    }
+
+    match client.get_event("vv1A1").await {
+        Err(HKDError::PlatformApiError { status, body, .. }) => {
+            assert_eq!(status, 503);
+            assert!(body.contains("SERVICE_UNAVAILABLE"));
+        }
+        other => panic!("expected a platform error, got {:?}", other),
+    }
+    // The first attempt plus `max_retries`
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn retry_after_beyond_the_backoff_cap_is_not_waited_out() {
+    let (server, client) = ticketmaster(FakeTicketmaster::new(TICKETMASTER_KEY)).await;
+    server.inject(StubResponse::error(429, json!({})).with_header("Retry-After", "3600"));
+
+    let result = client.get_event("vv1A1").await;
+
+    assert!(matches!(result, Err(HKDError::PlatformApiError { status: 429, .. })));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn repeated_failures_open_the_circuit() {
+    let server = StubServer::start(FakeTicketmaster::new(TICKETMASTER_KEY)).await;
+    let client = TicketmasterClient::new(TICKETMASTER_KEY.to_string())
+        .with_base_url(server.base_url.clone())
+        .with_resilience(ResilienceConfig {
+            backoff: BackoffPolicy { max_retries: 0, ..BackoffPolicy::default() },
+            circuit_breaker: CircuitBreakerConfig { failure_threshold: 2, open_for: Duration::from_secs(60) },
+            ..test_resilience(EventPlatform::Ticketmaster)
+        });
+    server.inject(StubResponse::error(502, json!({})));
+    server.inject(StubResponse::error(502, json!({})));
+
+    assert!(client.get_event("vv1A1").await.is_err());
+    assert!(client.get_event("vv1A1").await.is_err());
+    let result = client.get_event("vv1A1").await;
+
+    assert!(matches!(result, Err(HKDError::PlatformUnavailable(EventPlatform::Ticketmaster))));
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn retries_and_throttling_count_once_towards_the_circuit() {
+    let server = StubServer::start(FakeTicketmaster::new(TICKETMASTER_KEY)).await;
+    let client = TicketmasterClient::new(TICKETMASTER_KEY.to_string())
+        .with_base_url(server.base_url.clone())
+        .with_resilience(ResilienceConfig {
+            circuit_breaker: CircuitBreakerConfig { failure_threshold: 2, open_for: Duration::from_secs(60) },
+            ..test_resilience(EventPlatform::Ticketmaster)
+        });
+    for _ in 0..3 {
+        server.inject(StubResponse::error(502, json!({})));
+    }
+    server.inject(StubResponse::error(429, json!({})).with_header("Retry-After", "3600"));
+
+    // Three attempts, but one failed call
+    assert!(client.get_event("vv1A1").await.is_err());
+    // Throttling says nothing about the platform's health
+    assert!(matches!(client.get_event("vv1A1").await, Err(HKDError::PlatformApiError { status: 429, .. })));
+    // So the circuit is still closed
+    let _ = client.get_event("vv1A1").await;
+    assert_eq!(server.requests().len(), 5);
+}
+
+#[tokio::test]
+async fn requests_are_spaced_to_the_rate_limit() {
+    let server = StubServer::start(FakeTicketmaster::new(TICKETMASTER_KEY)).await;
+    let client = TicketmasterClient::new(TICKETMASTER_KEY.to_string())
+        .with_base_url(server.base_url.clone())
+        .with_resilience(ResilienceConfig {
+            rate_limits: vec![RateLimit { requests: 2, period: Duration::from_millis(200) }],
+            ..test_resilience(EventPlatform::Ticketmaster)
+        });
+
+    let started = Instant::now();
+    for _ in 0..5 {
+        let _ = client.get_event("missing").await;
+    }
+
+    // Two requests fit the initial burst; the other three wait 100ms each
+    assert!(started.elapsed() >= Duration::from_millis(250));
+    assert_eq!(server.requests().len(), 5);
+}
@@ -0,0 +1,225 @@
+// tests/support/cvent.rs
+use super::{page, FakePlatform, IdSequence, RecordedRequest, StubResponse};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+
+/// Cvent REST API: client-credentials OAuth, then account-scoped events and
+/// admission items with token paging.
+pub struct FakeCvent {
+    pub client_id: String,
+    pub client_secret: String,
+    pub account_id: String,
+    pub page_size: usize,
+    tokens: Vec<String>,
+    events: Vec<Value>,
+    // (event id, admission item)
+    admission_items: Vec<(String, Value)>,
+    ids: IdSequence,
+}
+
+impl FakeCvent {
+    pub fn new(client_id: &str, client_secret: &str, account_id: &str) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            account_id: account_id.to_string(),
+            page_size: 100,
+            tokens: Vec::new(),
+            events: Vec::new(),
+            admission_items: Vec::new(),
+            ids: IdSequence::default(),
+        }
+    }
+
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    pub fn tokens_issued(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn seed_event(&mut self, title: &str) -> String {
+        let id = self.ids.next("evt-");
+        self.events.push(json!({
+            "id": id,
+            "title": title,
+            "description": "Seeded",
+            "start": "2030-03-01T11:00:00Z",
+            "end": "2030-03-01T14:00:00Z",
+            "status": "Active",
+            "type": "Conference",
+            "currency": "HKD",
+            "capacity": 300,
+            "planner": "HKD Events",
+            "venues": [],
+            "links": { "invitation": { "href": format!("https://cvent.me/{}", id) } },
+        }));
+        id
+    }
+
+    pub fn register(&mut self, admission_item_id: &str, count: u64) {
+        let item = self.admission_item_mut(admission_item_id).expect("unknown admission item");
+        let registered = item["registered"].as_u64().unwrap_or(0);
+        item["registered"] = json!(registered + count);
+    }
+
+    pub fn capacity(&self, admission_item_id: &str) -> Option<u64> {
+        self.admission_items.iter()
+            .find(|(_, item)| item["id"] == admission_item_id)
+            .and_then(|(_, item)| item["capacity"].as_u64())
+    }
+
+    fn admission_item_mut(&mut self, admission_item_id: &str) -> Option<&mut Value> {
+        self.admission_items.iter_mut()
+            .find(|(_, item)| item["id"] == admission_item_id)
+            .map(|(_, item)| item)
+    }
+
+    fn event_index(&self, event_id: &str) -> Option<usize> {
+        self.events.iter().position(|event| event["id"] == event_id)
+    }
+
+    fn event_view(&self, event: &Value) -> Value {
+        let mut view = event.clone();
+        let event_id = event["id"].as_str().unwrap_or_default();
+        view["admissionItems"] = Value::Array(
+            self.admission_items.iter()
+                .filter(|(id, _)| id == event_id)
+                .map(|(_, item)| item.clone())
+                .collect(),
+        );
+        view
+    }
+
+    fn event_from(id: &str, status: &str, body: &Value) -> Value {
+        json!({
+            "id": id,
+            "title": body["title"],
+            "description": body["description"],
+            "start": body["start"],
+            "end": body["end"],
+            "status": status,
+            "type": body["type"],
+            "currency": body["currency"],
+            "capacity": body["capacity"],
+            "planner": body["planner"],
+            "venues": body["venues"],
+            "links": { "invitation": { "href": format!("https://cvent.me/{}", id) } },
+        })
+    }
+
+    fn issue_token(&mut self, request: &RecordedRequest) -> StubResponse {
+        let expected = format!("Basic {}", BASE64.encode(format!("{}:{}", self.client_id, self.client_secret)));
+        if request.header("authorization") != Some(expected.as_str())
+            || request.body["grant_type"] != "client_credentials"
+        {
+            return StubResponse::error(401, json!({ "error": "invalid_client" }));
+        }
+
+        let token = self.ids.next("token-");
+        self.tokens.push(token.clone());
+        StubResponse::json(json!({
+            "access_token": token,
+            "expires_in": 3600,
+            "token_type": "Bearer",
+        }))
+    }
+
+    fn list_events(&self, request: &RecordedRequest) -> StubResponse {
+        let events: Vec<Value> = self.events.iter().map(|event| self.event_view(event)).collect();
+        let offset = request.query("token").and_then(|token| token.parse().ok()).unwrap_or(0);
+        let size = request.query("limit")
+            .and_then(|limit| limit.parse().ok())
+            .unwrap_or(self.page_size)
+            .min(self.page_size);
+        let (data, next) = page(&events, offset, size);
+
+        StubResponse::json(json!({
+            "data": data,
+            "paging": {
+                "limit": size,
+                "totalCount": events.len(),
+                "nextToken": next.map(|offset| offset.to_string()),
+            },
+        }))
+    }
+}
+
+impl FakePlatform for FakeCvent {
+    fn handle(&mut self, request: &RecordedRequest) -> StubResponse {
+        let segments = request.segments();
+        if let ("POST", ["oauth2", "token"]) = (request.method.as_str(), segments.as_slice()) {
+            return self.issue_token(request);
+        }
+
+        let authorized = request.header("authorization")
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|token| self.tokens.iter().any(|issued| issued == token));
+        if !authorized {
+            return StubResponse::error(401, json!({ "message": "Unauthorized" }));
+        }
+
+        let path = match segments.as_slice() {
+            ["accounts", account_id, path @ ..] if *account_id == self.account_id => path.to_vec(),
+            _ => return StubResponse::not_found(),
+        };
+
+        match (request.method.as_str(), path.as_slice()) {
+            ("POST", ["events"]) => {
+                let id = self.ids.next("evt-");
+                let event = Self::event_from(&id, "Pending", &request.body);
+                self.events.push(event.clone());
+                StubResponse::json(self.event_view(&event))
+            }
+            ("GET", ["events"]) => self.list_events(request),
+            ("GET", ["events", event_id]) => match self.event_index(event_id) {
+                Some(index) => StubResponse::json(self.event_view(&self.events[index])),
+                None => StubResponse::not_found(),
+            },
+            ("PUT", ["events", event_id]) => match self.event_index(event_id) {
+                Some(index) => {
+                    let status = self.events[index]["status"].as_str().unwrap_or("Pending").to_string();
+                    self.events[index] = Self::event_from(event_id, &status, &request.body);
+                    StubResponse::json(self.event_view(&self.events[index]))
+                }
+                None => StubResponse::not_found(),
+            },
+            ("POST", ["events", event_id, "admission-items"]) => {
+                if self.event_index(event_id).is_none() {
+                    return StubResponse::not_found();
+                }
+                let body = &request.body;
+                let item = json!({
+                    "id": self.ids.next("item-"),
+                    "name": body["name"],
+                    "amount": body["amount"],
+                    "currency": body["currency"],
+                    "capacity": body["capacity"],
+                    "registered": 0,
+                    "availableFrom": body["availableFrom"],
+                    "availableTo": body["availableTo"],
+                });
+                self.admission_items.push((event_id.to_string(), item.clone()));
+                StubResponse::json(json!({ "id": item["id"] }))
+            }
+            ("GET", ["events", _, "admission-items", item_id]) => match self.admission_item_mut(item_id) {
+                Some(item) => StubResponse::json(item.clone()),
+                None => StubResponse::not_found(),
+            },
+            ("PATCH", ["events", _, "admission-items", item_id]) => {
+                let capacity = request.body["capacity"].clone();
+                match self.admission_item_mut(item_id) {
+                    Some(item) => {
+                        item["capacity"] = capacity;
+                        StubResponse::json(item.clone())
+                    }
+                    None => StubResponse::not_found(),
+                }
+            }
+            _ => StubResponse::not_found(),
+        }
+    }
+}
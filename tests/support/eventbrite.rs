@@ -0,0 +1,314 @@
+// tests/support/eventbrite.rs
+use super::{page, FakePlatform, IdSequence, RecordedRequest, StubResponse};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+/// Eventbrite v3 API: one organization, organizer profiles, venues, events with
+/// continuation paging, ticket classes, orders and attendees.
+pub struct FakeEventbrite {
+    pub api_key: String,
+    pub organization_id: String,
+    pub organizers: Vec<Value>,
+    pub page_size: usize,
+    events: Vec<Value>,
+    venues: HashMap<String, Value>,
+    // (event id, ticket class)
+    ticket_classes: Vec<(String, Value)>,
+    orders: HashMap<String, Value>,
+    attendees: Vec<Value>,
+    ids: IdSequence,
+}
+
+impl FakeEventbrite {
+    pub fn new(api_key: &str) -> Self {
+        Self {
+            api_key: api_key.to_string(),
+            organization_id: "org-1".to_string(),
+            organizers: vec![json!({ "id": "organizer-1", "name": "HKD Events" })],
+            page_size: 50,
+            events: Vec::new(),
+            venues: HashMap::new(),
+            ticket_classes: Vec::new(),
+            orders: HashMap::new(),
+            attendees: Vec::new(),
+            ids: IdSequence::default(),
+        }
+    }
+
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Adds an event as if an organizer had created it on Eventbrite.
+    pub fn seed_event(&mut self, title: &str, status: &str) -> String {
+        let id = self.ids.next("");
+        self.events.push(json!({
+            "id": id,
+            "url": format!("https://www.eventbrite.com/e/{}", id),
+            "name": { "text": title, "html": title },
+            "description": { "text": null, "html": "<p>Seeded</p>" },
+            "start": { "timezone": "Asia/Hong_Kong", "utc": "2030-03-01T11:00:00Z" },
+            "end": { "timezone": "Asia/Hong_Kong", "utc": "2030-03-01T14:00:00Z" },
+            "status": status,
+            "organizer_id": "organizer-1",
+            "capacity": 300,
+            "format_id": "6",
+            "category_id": "103",
+        }));
+        id
+    }
+
+    pub fn sell(&mut self, ticket_class_id: &str, quantity: u64) {
+        let ticket_class = self.ticket_class_mut(ticket_class_id).expect("unknown ticket class");
+        let sold = ticket_class["quantity_sold"].as_u64().unwrap_or(0);
+        ticket_class["quantity_sold"] = json!(sold + quantity);
+    }
+
+    pub fn quantity_total(&self, ticket_class_id: &str) -> Option<u64> {
+        self.ticket_classes.iter()
+            .find(|(_, tc)| tc["id"] == ticket_class_id)
+            .and_then(|(_, tc)| tc["quantity_total"].as_u64())
+    }
+
+    /// Records a single-attendee order, returning (order id, attendee id).
+    pub fn place_order(&mut self, event_id: &str, ticket_class_id: &str, email: &str) -> (String, String) {
+        let order_id = self.ids.next("");
+        let attendee_id = self.ids.next("");
+        self.attendees.push(json!({
+            "id": attendee_id,
+            "event_id": event_id,
+            "order_id": order_id,
+            "ticket_class_id": ticket_class_id,
+            "profile": { "email": email },
+            "checked_in": false,
+            "cancelled": false,
+            "refunded": false,
+        }));
+        self.orders.insert(order_id.clone(), json!({ "id": order_id, "event_id": event_id }));
+        (order_id, attendee_id)
+    }
+
+    fn ticket_class_mut(&mut self, ticket_class_id: &str) -> Option<&mut Value> {
+        self.ticket_classes.iter_mut()
+            .find(|(_, tc)| tc["id"] == ticket_class_id)
+            .map(|(_, tc)| tc)
+    }
+
+    /// The event as returned with `expand=venue,ticket_classes`.
+    fn event_view(&self, event: &Value) -> Value {
+        let mut view = event.clone();
+        let event_id = event["id"].as_str().unwrap_or_default();
+        if let Some(venue) = event["venue_id"].as_str().and_then(|id| self.venues.get(id)) {
+            view["venue"] = venue.clone();
+        }
+        view["ticket_classes"] = Value::Array(
+            self.ticket_classes.iter()
+                .filter(|(id, _)| id == event_id)
+                .map(|(_, tc)| tc.clone())
+                .collect(),
+        );
+        view
+    }
+
+    fn event_index(&self, event_id: &str) -> Option<usize> {
+        self.events.iter().position(|event| event["id"] == event_id)
+    }
+
+    fn create_event(&mut self, request: &RecordedRequest) -> StubResponse {
+        let data = &request.body["event"];
+        let id = self.ids.next("");
+        let event = json!({
+            "id": id,
+            "url": format!("https://www.eventbrite.com/e/{}", id),
+            "name": { "text": unescape(data["name"]["html"].as_str().unwrap_or_default()), "html": data["name"]["html"] },
+            "description": { "text": null, "html": data["description"]["html"] },
+            "start": data["start"],
+            "end": data["end"],
+            "status": "draft",
+            "currency": data["currency"],
+            "organizer_id": data["organizer_id"],
+            "venue_id": data["venue_id"],
+            "capacity": data["capacity"],
+            "format_id": data["format_id"],
+            "category_id": data["category_id"],
+        });
+        self.events.push(event.clone());
+        StubResponse::json(self.event_view(&event))
+    }
+
+    fn update_event(&mut self, event_id: &str, request: &RecordedRequest) -> StubResponse {
+        let index = match self.event_index(event_id) {
+            Some(index) => index,
+            None => return StubResponse::not_found(),
+        };
+        let data = &request.body["event"];
+        let event = &mut self.events[index];
+        if let Some(html) = data["name"]["html"].as_str() {
+            event["name"] = json!({ "text": unescape(html), "html": html });
+        }
+        for field in ["description", "start", "end", "capacity", "format_id", "category_id", "organizer_id"] {
+            if !data[field].is_null() {
+                event[field] = data[field].clone();
+            }
+        }
+        if !data["venue_id"].is_null() {
+            event["venue_id"] = data["venue_id"].clone();
+        }
+        let event = event.clone();
+        StubResponse::json(self.event_view(&event))
+    }
+
+    fn list_events(&self, request: &RecordedRequest) -> StubResponse {
+        let status = request.query("status").filter(|status| *status != "all");
+        let matching: Vec<Value> = self.events.iter()
+            .filter(|event| status.is_none_or(|status| event["status"] == status))
+            .map(|event| self.event_view(event))
+            .collect();
+
+        let offset = request.query("continuation").and_then(|c| c.parse().ok()).unwrap_or(0);
+        let size = request.query("page_size")
+            .and_then(|size| size.parse().ok())
+            .unwrap_or(self.page_size)
+            .min(self.page_size);
+        let (events, next) = page(&matching, offset, size);
+
+        StubResponse::json(json!({
+            "events": events,
+            "pagination": {
+                "object_count": matching.len(),
+                "page_size": size,
+                "has_more_items": next.is_some(),
+                "continuation": next.map(|offset| offset.to_string()),
+            },
+        }))
+    }
+
+    fn list_venues(&self, request: &RecordedRequest) -> StubResponse {
+        let mut venues: Vec<Value> = self.venues.values().cloned().collect();
+        venues.sort_by(|a, b| a["id"].as_str().cmp(&b["id"].as_str()));
+
+        let offset = request.query("continuation").and_then(|c| c.parse().ok()).unwrap_or(0);
+        let (venues, next) = page(&venues, offset, self.page_size);
+        StubResponse::json(json!({
+            "venues": venues,
+            "pagination": {
+                "has_more_items": next.is_some(),
+                "continuation": next.map(|offset| offset.to_string()),
+            },
+        }))
+    }
+
+    fn create_ticket_class(&mut self, event_id: &str, request: &RecordedRequest) -> StubResponse {
+        if self.event_index(event_id).is_none() {
+            return StubResponse::not_found();
+        }
+        let data = &request.body["ticket_class"];
+        // Costs are sent as "CURRENCY,minor units"
+        let cost = data["cost"].as_str().and_then(|cost| cost.split_once(',')).map(|(currency, value)| {
+            json!({
+                "currency": currency,
+                "value": value.parse::<i64>().unwrap_or(0),
+                "display": format!("{} {}", currency, value),
+            })
+        });
+        let ticket_class = json!({
+            "id": self.ids.next(""),
+            "name": data["name"],
+            "free": data["free"],
+            "cost": cost,
+            "quantity_total": data["quantity_total"],
+            "quantity_sold": 0,
+            "sales_start": data["sales_start"],
+            "sales_end": data["sales_end"],
+        });
+        self.ticket_classes.push((event_id.to_string(), ticket_class.clone()));
+        StubResponse::json(ticket_class)
+    }
+}
+
+impl FakePlatform for FakeEventbrite {
+    fn handle(&mut self, request: &RecordedRequest) -> StubResponse {
+        if request.header("authorization") != Some(format!("Bearer {}", self.api_key).as_str()) {
+            return StubResponse::error(401, json!({
+                "status_code": 401,
+                "error": "INVALID_AUTH",
+                "error_description": "The OAuth token you provided was invalid.",
+            }));
+        }
+
+        let segments = request.segments();
+        match (request.method.as_str(), segments.as_slice()) {
+            ("GET", ["users", "me", "organizations"]) => StubResponse::json(json!({
+                "organizations": [{ "id": self.organization_id, "name": "HKD Events Ltd" }],
+            })),
+            ("GET", ["organizations", org, "organizers"]) if *org == self.organization_id => {
+                StubResponse::json(json!({ "organizers": self.organizers }))
+            }
+            ("GET", ["organizations", org, "venues"]) if *org == self.organization_id => self.list_venues(request),
+            ("POST", ["organizations", org, "venues"]) if *org == self.organization_id => {
+                let data = &request.body["venue"];
+                let venue = json!({
+                    "id": self.ids.next(""),
+                    "name": data["name"],
+                    "capacity": data["capacity"],
+                    "address": data["address"],
+                });
+                self.venues.insert(venue["id"].as_str().unwrap().to_string(), venue.clone());
+                StubResponse::json(venue)
+            }
+            ("POST", ["organizations", org, "events"]) if *org == self.organization_id => self.create_event(request),
+            ("GET", ["organizations", org, "events"]) if *org == self.organization_id => self.list_events(request),
+            ("GET", ["events", event_id]) => match self.event_index(event_id) {
+                Some(index) => StubResponse::json(self.event_view(&self.events[index])),
+                None => StubResponse::not_found(),
+            },
+            ("POST", ["events", event_id]) => self.update_event(event_id, request),
+            ("POST", ["events", event_id, "ticket_classes"]) => self.create_ticket_class(event_id, request),
+            ("GET", ["events", _, "ticket_classes", ticket_class_id]) => {
+                match self.ticket_class_mut(ticket_class_id) {
+                    Some(ticket_class) => StubResponse::json(ticket_class.clone()),
+                    None => StubResponse::not_found(),
+                }
+            }
+            ("POST", ["events", _, "ticket_classes", ticket_class_id]) => {
+                let quantity_total = request.body["ticket_class"]["quantity_total"].clone();
+                match self.ticket_class_mut(ticket_class_id) {
+                    Some(ticket_class) => {
+                        ticket_class["quantity_total"] = quantity_total;
+                        StubResponse::json(ticket_class.clone())
+                    }
+                    None => StubResponse::not_found(),
+                }
+            }
+            ("GET", ["orders", order_id]) => match self.orders.get(*order_id) {
+                Some(order) => {
+                    let mut order = order.clone();
+                    order["attendees"] = Value::Array(
+                        self.attendees.iter()
+                            .filter(|attendee| attendee["order_id"] == *order_id)
+                            .cloned()
+                            .collect(),
+                    );
+                    StubResponse::json(order)
+                }
+                None => StubResponse::not_found(),
+            },
+            ("GET", ["events", event_id, "attendees", attendee_id]) => {
+                match self.attendees.iter().find(|a| a["id"] == *attendee_id && a["event_id"] == *event_id) {
+                    Some(attendee) => StubResponse::json(attendee.clone()),
+                    None => StubResponse::not_found(),
+                }
+            }
+            _ => StubResponse::not_found(),
+        }
+    }
+}
+
+fn unescape(html: &str) -> String {
+    html.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
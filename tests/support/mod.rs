@@ -0,0 +1,280 @@
+// tests/support/mod.rs
+//! In-process fakes of the external ticketing APIs. Each fake keeps its own
+//! state, hands out sequential ids and records every request it receives.
+#![allow(dead_code)]
+
+pub mod cvent;
+pub mod eventbrite;
+pub mod ticketmaster;
+
+use chrono::{Duration, TimeZone, Utc};
+use hkd_stablecoin::models::ticketing::*;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server, StatusCode};
+use reqwest::Url;
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::convert::Infallible;
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub headers: HashMap<String, String>,
+    // JSON bodies as sent; form bodies as an object of their fields
+    pub body: Value,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_ascii_lowercase()).map(String::as_str)
+    }
+
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query.get(name).map(String::as_str)
+    }
+
+    /// Path segments, so handlers can match on slices.
+    pub fn segments(&self) -> Vec<&str> {
+        self.path.split('/').filter(|segment| !segment.is_empty()).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StubResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Value,
+}
+
+impl StubResponse {
+    pub fn json(body: Value) -> Self {
+        Self { status: 200, headers: Vec::new(), body }
+    }
+
+    pub fn error(status: u16, body: Value) -> Self {
+        Self { status, headers: Vec::new(), body }
+    }
+
+    pub fn not_found() -> Self {
+        Self::error(404, json!({ "error": "NOT_FOUND" }))
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+pub trait FakePlatform: Send + 'static {
+    fn handle(&mut self, request: &RecordedRequest) -> StubResponse;
+}
+
+struct StubState<P> {
+    platform: P,
+    requests: Vec<RecordedRequest>,
+    // Served, in order, instead of the fake's own responses
+    injected: VecDeque<StubResponse>,
+}
+
+pub struct StubServer<P: FakePlatform> {
+    pub base_url: String,
+    state: Arc<Mutex<StubState<P>>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl<P: FakePlatform> StubServer<P> {
+    pub async fn start(platform: P) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind stub server");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(StubState {
+            platform,
+            requests: Vec::new(),
+            injected: VecDeque::new(),
+        }));
+
+        let service_state = state.clone();
+        let make_service = make_service_fn(move |_| {
+            let state = service_state.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let state = state.clone();
+                    async move { Ok::<_, Infallible>(respond(state, request).await) }
+                }))
+            }
+        });
+
+        let (shutdown, shutdown_signal) = oneshot::channel::<()>();
+        let server = Server::from_tcp(listener)
+            .expect("serve stub")
+            .serve(make_service)
+            .with_graceful_shutdown(async {
+                shutdown_signal.await.ok();
+            });
+        tokio::spawn(server);
+
+        Self { base_url, state, shutdown: Some(shutdown) }
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    pub fn last_request(&self) -> RecordedRequest {
+        self.requests().pop().expect("no request recorded")
+    }
+
+    /// Serves `response` for the next request without touching the fake's state.
+    pub fn inject(&self, response: StubResponse) {
+        self.state.lock().unwrap().injected.push_back(response);
+    }
+
+    /// Seeds or inspects the fake's state.
+    pub fn platform<R>(&self, f: impl FnOnce(&mut P) -> R) -> R {
+        f(&mut self.state.lock().unwrap().platform)
+    }
+}
+
+impl<P: FakePlatform> Drop for StubServer<P> {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+async fn respond<P: FakePlatform>(state: Arc<Mutex<StubState<P>>>, request: Request<Body>) -> Response<Body> {
+    let (parts, body) = request.into_parts();
+    let bytes = hyper::body::to_bytes(body).await.unwrap_or_default();
+    let url = Url::parse(&format!("http://stub{}", parts.uri)).unwrap();
+
+    let content_type = parts.headers.get("content-type")
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    let body = if bytes.is_empty() {
+        Value::Null
+    } else if content_type.starts_with("application/x-www-form-urlencoded") {
+        let form = Url::parse(&format!("http://stub/?{}", String::from_utf8_lossy(&bytes))).unwrap();
+        Value::Object(form.query_pairs().map(|(k, v)| (k.into_owned(), Value::String(v.into_owned()))).collect())
+    } else {
+        serde_json::from_slice(&bytes).unwrap_or(Value::Null)
+    };
+
+    let recorded = RecordedRequest {
+        method: parts.method.to_string(),
+        path: url.path().to_string(),
+        query: url.query_pairs().map(|(k, v)| (k.into_owned(), v.into_owned())).collect(),
+        headers: parts.headers.iter()
+            .map(|(name, value)| (name.as_str().to_string(), value.to_str().unwrap_or_default().to_string()))
+            .collect(),
+        body,
+    };
+
+    let response = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(recorded.clone());
+        match state.injected.pop_front() {
+            Some(injected) => injected,
+            None => state.platform.handle(&recorded),
+        }
+    };
+
+    let mut builder = Response::builder()
+        .status(StatusCode::from_u16(response.status).unwrap())
+        .header("content-type", "application/json");
+    for (name, value) in &response.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    builder.body(Body::from(response.body.to_string())).unwrap()
+}
+
+/// Sequential ids so assertions don't depend on run order.
+#[derive(Debug, Default)]
+pub struct IdSequence {
+    next: u64,
+}
+
+impl IdSequence {
+    pub fn next(&mut self, prefix: &str) -> String {
+        self.next += 1;
+        format!("{}{}", prefix, self.next)
+    }
+}
+
+/// Slices `items` for an offset-style cursor, returning the next offset if more remain.
+pub fn page<T: Clone>(items: &[T], offset: usize, size: usize) -> (Vec<T>, Option<usize>) {
+    let end = (offset + size).min(items.len());
+    let slice = items.get(offset..end).map(<[T]>::to_vec).unwrap_or_default();
+    let next = if end < items.len() { Some(end) } else { None };
+    (slice, next)
+}
+
+pub fn sample_event() -> Event {
+    let event_date = Utc.with_ymd_and_hms(2030, 6, 14, 12, 0, 0).unwrap();
+    let now = Utc::now();
+
+    Event {
+        id: Uuid::new_v4(),
+        title: "Harbour Lights Festival".to_string(),
+        description: "An evening of music & light by the harbour.".to_string(),
+        organizer: "HKD Events".to_string(),
+        venue: Venue {
+            id: None,
+            name: "Hong Kong Coliseum".to_string(),
+            address: "9 Cheong Wan Road, Hung Hom".to_string(),
+            city: "Hong Kong".to_string(),
+            country: "Hong Kong".to_string(),
+            capacity: 12_500,
+            coordinates: Some(Coordinates { latitude: 22.3019, longitude: 114.1822 }),
+            timezone: Some(chrono_tz::Asia::Hong_Kong),
+            seat_map: None,
+            areas: Vec::new(),
+        },
+        event_date,
+        door_time: event_date - Duration::hours(1),
+        end_date: None,
+        timezone: None,
+        local_times: None,
+        event_type: EventType::Concert,
+        ticket_types: vec![sample_ticket_type("VIP", Decimal::new(88000, 2), 500)],
+        external_event_id: None,
+        platform: EventPlatform::Internal,
+        status: EventStatus::Published,
+        created_at: now,
+        updated_at: now,
+        publications: HashMap::new(),
+        sold_seats: HashSet::new(),
+        sessions: Vec::new(),
+        recurrence: None,
+        version: 0,
+        settlement: SettlementSchedule::default(),
+    }
+}
+
+pub fn sample_ticket_type(name: &str, price: Decimal, quantity: u32) -> TicketType {
+    TicketType {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        price,
+        currency: "HKD".to_string(),
+        quantity_available: quantity,
+        quantity_sold: 0,
+        perks: Vec::new(),
+        nft_metadata: None,
+        sales_start: Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap(),
+        sales_end: Utc.with_ymd_and_hms(2030, 6, 14, 10, 0, 0).unwrap(),
+        transfer_policy: TransferPolicy::default(),
+        inventory: InventoryAllocation::default(),
+        channel_sales: HashMap::new(),
+        external_ids: HashMap::new(),
+        price_zone: None,
+        area: None,
+        session_access: SessionAccess::default(),
+    }
+}
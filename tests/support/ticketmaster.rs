@@ -0,0 +1,212 @@
+// tests/support/ticketmaster.rs
+use super::{page, FakePlatform, IdSequence, RecordedRequest, StubResponse};
+use serde_json::{json, Value};
+
+/// Ticketmaster Publish and Discovery APIs: events written through Publish
+/// are readable through Discovery, which pages by number.
+pub struct FakeTicketmaster {
+    pub api_key: String,
+    pub page_size: usize,
+    events: Vec<Value>,
+    // (event id, offer)
+    offers: Vec<(String, Value)>,
+    ids: IdSequence,
+}
+
+impl FakeTicketmaster {
+    pub fn new(api_key: &str) -> Self {
+        Self {
+            api_key: api_key.to_string(),
+            page_size: 100,
+            events: Vec::new(),
+            offers: Vec::new(),
+            ids: IdSequence::default(),
+        }
+    }
+
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    pub fn seed_event(&mut self, name: &str, city: &str) -> String {
+        let id = self.ids.next("vv1A");
+        self.events.push(json!({
+            "id": id,
+            "name": name,
+            "url": format!("https://www.ticketmaster.com/event/{}", id),
+            "info": "Seeded",
+            "dates": { "start": { "dateTime": "2030-03-01T11:00:00Z" }, "timezone": "Asia/Hong_Kong", "status": { "code": "onsale" } },
+            "classifications": [{ "segment": { "id": "KZFzniwnSyZfZ7v7nJ", "name": "Music" } }],
+            "priceRanges": [],
+            "_embedded": { "venues": [{ "name": "AsiaWorld-Arena", "city": { "name": city } }] },
+        }));
+        id
+    }
+
+    pub fn sell(&mut self, offer_id: &str, quantity: u64) {
+        let offer = self.offer_mut(offer_id).expect("unknown offer");
+        let sold = offer["quantitySold"].as_u64().unwrap_or(0);
+        offer["quantitySold"] = json!(sold + quantity);
+    }
+
+    pub fn offer_quantity(&self, offer_id: &str) -> Option<u64> {
+        self.offers.iter()
+            .find(|(_, offer)| offer["id"] == offer_id)
+            .and_then(|(_, offer)| offer["quantity"].as_u64())
+    }
+
+    fn offer_mut(&mut self, offer_id: &str) -> Option<&mut Value> {
+        self.offers.iter_mut()
+            .find(|(_, offer)| offer["id"] == offer_id)
+            .map(|(_, offer)| offer)
+    }
+
+    fn event_index(&self, event_id: &str) -> Option<usize> {
+        self.events.iter().position(|event| event["id"] == event_id)
+    }
+
+    /// Discovery's shape for an event published with `body`.
+    fn discovery_event(id: &str, body: &Value, price_ranges: Value) -> Value {
+        let segment_id = body["classifications"][0]["segment"]["id"].clone();
+        let mut venue = body["venue"].clone();
+        if let Some(venue) = venue.as_object_mut() {
+            venue.remove("capacity");
+        }
+        json!({
+            "id": id,
+            "name": body["name"]["en"],
+            "url": format!("https://www.ticketmaster.com/event/{}", id),
+            "info": body["description"]["en"],
+            "dates": {
+                "start": body["dates"]["start"],
+                "timezone": body["dates"]["timezone"],
+                "doorsTimes": body["dates"]["doorsTimes"],
+                "status": { "code": "onsale" },
+            },
+            "classifications": [{ "segment": { "id": segment_id, "name": segment_name(segment_id.as_str()) } }],
+            "promoter": body["promoter"],
+            "priceRanges": price_ranges,
+            "_embedded": { "venues": [venue] },
+        })
+    }
+
+    fn list_events(&self, request: &RecordedRequest) -> StubResponse {
+        let keyword = request.query("keyword").map(str::to_lowercase);
+        let city = request.query("city");
+        let matching: Vec<Value> = self.events.iter()
+            .filter(|event| keyword.as_ref().is_none_or(|keyword| {
+                event["name"].as_str().unwrap_or_default().to_lowercase().contains(keyword)
+            }))
+            .filter(|event| city.is_none_or(|city| event["_embedded"]["venues"][0]["city"]["name"] == city))
+            .cloned()
+            .collect();
+
+        let size = request.query("size")
+            .and_then(|size| size.parse().ok())
+            .unwrap_or(self.page_size)
+            .min(self.page_size);
+        let number: usize = request.query("page").and_then(|page| page.parse().ok()).unwrap_or(0);
+        let (events, _) = page(&matching, number * size, size);
+        let total_pages = matching.len().div_ceil(size);
+
+        let mut body = json!({
+            "page": {
+                "size": size,
+                "totalElements": matching.len(),
+                "totalPages": total_pages,
+                "number": number,
+            },
+        });
+        // Discovery omits `_embedded` entirely when nothing matches
+        if !events.is_empty() {
+            body["_embedded"] = json!({ "events": events });
+        }
+        StubResponse::json(body)
+    }
+}
+
+impl FakePlatform for FakeTicketmaster {
+    fn handle(&mut self, request: &RecordedRequest) -> StubResponse {
+        if request.query("apikey") != Some(self.api_key.as_str()) {
+            return StubResponse::error(401, json!({
+                "fault": {
+                    "faultstring": "Invalid ApiKey",
+                    "detail": { "errorcode": "oauth.v2.InvalidApiKey" },
+                },
+            }));
+        }
+
+        let segments = request.segments();
+        match (request.method.as_str(), segments.as_slice()) {
+            ("POST", ["publish", "v2", "events"]) => {
+                let id = self.ids.next("vv1A");
+                let event = Self::discovery_event(&id, &request.body, json!([]));
+                self.events.push(event.clone());
+                StubResponse::json(event)
+            }
+            ("PUT", ["publish", "v2", "events", event_id]) => match self.event_index(event_id) {
+                Some(index) => {
+                    let price_ranges = self.events[index]["priceRanges"].clone();
+                    let event = Self::discovery_event(event_id, &request.body, price_ranges);
+                    self.events[index] = event.clone();
+                    StubResponse::json(event)
+                }
+                None => StubResponse::not_found(),
+            },
+            ("GET", ["discovery", "v2", "events.json"]) => self.list_events(request),
+            ("GET", ["discovery", "v2", "events", file]) => {
+                match file.strip_suffix(".json").and_then(|id| self.event_index(id)) {
+                    Some(index) => StubResponse::json(self.events[index].clone()),
+                    None => StubResponse::not_found(),
+                }
+            }
+            ("POST", ["publish", "v2", "events", event_id, "offers"]) => {
+                let index = match self.event_index(event_id) {
+                    Some(index) => index,
+                    None => return StubResponse::not_found(),
+                };
+                let body = &request.body;
+                let offer = json!({
+                    "id": self.ids.next("offer-"),
+                    "quantity": body["quantity"],
+                    "quantitySold": 0,
+                });
+                if let Some(ranges) = self.events[index]["priceRanges"].as_array_mut() {
+                    ranges.push(json!({
+                        "type": body["name"],
+                        "currency": body["currency"],
+                        "min": body["price"],
+                        "max": body["price"],
+                    }));
+                }
+                self.offers.push((event_id.to_string(), offer.clone()));
+                StubResponse::json(json!({ "id": offer["id"] }))
+            }
+            ("GET", ["publish", "v2", "events", _, "offers", offer_id]) => match self.offer_mut(offer_id) {
+                Some(offer) => StubResponse::json(offer.clone()),
+                None => StubResponse::not_found(),
+            },
+            ("PATCH", ["publish", "v2", "events", _, "offers", offer_id]) => {
+                let quantity = request.body["quantity"].clone();
+                match self.offer_mut(offer_id) {
+                    Some(offer) => {
+                        offer["quantity"] = quantity;
+                        StubResponse::json(offer.clone())
+                    }
+                    None => StubResponse::not_found(),
+                }
+            }
+            _ => StubResponse::not_found(),
+        }
+    }
+}
+
+fn segment_name(segment_id: Option<&str>) -> &'static str {
+    match segment_id {
+        Some("KZFzniwnSyZfZ7v7nJ") => "Music",
+        Some("KZFzniwnSyZfZ7v7nE") => "Sports",
+        Some("KZFzniwnSyZfZ7v7na") => "Arts & Theatre",
+        _ => "Miscellaneous",
+    }
+}